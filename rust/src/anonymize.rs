@@ -1,7 +1,18 @@
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 
+use crate::cardhash::cardhash_into;
 use crate::config::{AnonBase, AnonType};
+use crate::emailhash::emailhash_into;
+use crate::encrypt::encrypt_into;
+use crate::generalize::generalize_into;
+use crate::idhash::idhash_into;
+use crate::idmap::idmap_into;
+use crate::iphash::{iphash_into, CryptoPan};
+use crate::mask::mask_into;
+use crate::noise::{noise_into, NumericType};
+use crate::synthesize::synthesize_into;
+use crate::uuidhash::uuidhash_into;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -23,6 +34,11 @@ pub struct AnonContext<'a> {
     pub rowindex: i32,
     pub bfirstinsert: bool,
     pub tablename: &'a str,
+    /// Numeric type of the column, to keep `noise` results in range and as
+    /// the default `idmap` domain
+    pub column: Option<NumericType>,
+    /// Crypto-PAn state of the secret for `iphash`, built once per dump
+    pub crypto_pan: Option<&'a CryptoPan>,
 }
 
 /// Escape single quotes and backslashes for MySQL output (doubling style).
@@ -93,12 +109,7 @@ fn is_valid_utf8_sequence(src: &[u8]) -> bool {
     if expected_len == 0 || expected_len > src.len() {
         return false;
     }
-    for i in 1..expected_len {
-        if !is_utf8_continuation(src[i]) {
-            return false;
-        }
-    }
-    true
+    src[1..expected_len].iter().all(|&c| is_utf8_continuation(c))
 }
 
 /// Substring that respects UTF-8 boundaries and escape sequences (\x counts as 1 char).
@@ -108,42 +119,66 @@ pub fn mysubstr(src: &[u8], num_chars: usize) -> Vec<u8> {
     result
 }
 
-/// Same as `mysubstr` but appends into a caller-provided buffer.
-pub fn mysubstr_into(result: &mut Vec<u8>, src: &[u8], num_chars: usize) {
+/// Byte length of the character starting `src`, an escape sequence counting
+/// as one character; 0 if it is truncated or not valid UTF-8.
+pub(crate) fn mychar_len(src: &[u8]) -> usize {
+    if src.is_empty() {
+        0
+    } else if is_escape_char(src[0]) {
+        if src.len() > 1 {
+            2
+        } else {
+            0
+        }
+    } else if is_valid_utf8_sequence(src) {
+        utf8_char_length(src[0])
+    } else {
+        0
+    }
+}
+
+/// Same as `mysubstr` but appends into a caller-provided buffer. Returns the
+/// number of bytes of `src` copied.
+pub fn mysubstr_into(result: &mut Vec<u8>, src: &[u8], num_chars: usize) -> usize {
     let mut srccount = 0;
     let mut copied_chars = 0;
-    let src_len = src.len();
-
-    while srccount < src_len && copied_chars < num_chars {
-        if is_escape_char(src[srccount]) {
-            if srccount + 1 < src_len {
-                result.push(src[srccount]);
-                result.push(src[srccount + 1]);
-                srccount += 2;
-                copied_chars += 1;
-            } else {
-                break;
-            }
-        } else {
-            let char_length = utf8_char_length(src[srccount]);
-            if char_length == 0
-                || srccount + char_length > src_len
-                || !is_valid_utf8_sequence(&src[srccount..])
-            {
-                break;
-            }
-            for i in 0..char_length {
-                result.push(src[srccount + i]);
-            }
-            srccount += char_length;
-            copied_chars += 1;
+
+    while copied_chars < num_chars {
+        let char_length = mychar_len(&src[srccount..]);
+        if char_length == 0 {
+            break;
         }
+        result.extend_from_slice(&src[srccount..srccount + char_length]);
+        srccount += char_length;
+        copied_chars += 1;
     }
+    srccount
+}
+
+/// Number of characters of `src`, counted as `mysubstr` does.
+pub(crate) fn mychar_count(src: &[u8]) -> usize {
+    let mut pos = 0;
+    let mut count = 0;
+    loop {
+        let char_length = mychar_len(&src[pos..]);
+        if char_length == 0 {
+            return count;
+        }
+        pos += char_length;
+        count += 1;
+    }
+}
+
+/// HMAC-SHA256 of `token` keyed by the secret.
+pub(crate) fn hmac_digest(token: &[u8], secret: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(token);
+    mac.finalize().into_bytes().into()
 }
 
 /// Compute HMAC-SHA256 and map each byte to the range [begin, end], appending
 /// into the caller-provided buffer.
-fn make_readable_hash_into(
+pub(crate) fn make_readable_hash_into(
     out: &mut Vec<u8>,
     token: &[u8],
     secret: &[u8],
@@ -151,16 +186,10 @@ fn make_readable_hash_into(
     begin: u8,
     end: u8,
 ) {
-    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts any key length");
-    mac.update(token);
-    let result = mac.finalize();
-    let digest = result.into_bytes();
+    let digest = hmac_digest(token, secret);
 
-    let range = (end - begin + 1) as u8;
-    out.reserve(hash_len);
-    for i in 0..hash_len {
-        out.push((digest[i] % range) + begin);
-    }
+    let range = end - begin + 1;
+    out.extend(digest.iter().take(hash_len).map(|&b| (b % range) + begin));
 }
 
 pub fn anonymize_token(
@@ -272,9 +301,7 @@ pub fn anonymize_token_into(
 
         AnonType::EmailHash => {
             let hash_len = std::cmp::min(32, config.len as usize);
-            make_readable_hash_into(out, worktoken, secret, hash_len, b'a', b'z');
-            out.push(b'@');
-            out.extend_from_slice(config.domain.as_bytes());
+            emailhash_into(out, worktoken, secret, hash_len, &config.domain, &config.email);
             QuoteMode::AsInput
         }
 
@@ -289,17 +316,280 @@ pub fn anonymize_token_into(
             QuoteMode::AsInput
         }
 
-        AnonType::Json | AnonType::Py => {
-            // JSON handled at a higher level; Py not implemented
+        AnonType::Encrypt => {
+            let Some(rule) = &config.encrypt else {
+                out.extend_from_slice(worktoken);
+                return QuoteMode::AsInput;
+            };
+            let tablename = ctx.as_deref().map_or("", |c| c.tablename);
+            if !encrypt_into(out, worktoken, rule) {
+                eprintln!(
+                    "WARNING! Table {}: value not encrypted with key {}, left as it is",
+                    tablename, rule.key_name
+                );
+                out.extend_from_slice(worktoken);
+            }
+            QuoteMode::AsInput
+        }
+
+        // Moved between rows once the whole table has been read
+        AnonType::Shuffle => {
+            out.extend_from_slice(worktoken);
+            QuoteMode::AsInput
+        }
+
+        AnonType::Synthesize => {
+            let drawn = match (&config.synthesize, ctx.as_deref()) {
+                (Some(rule), Some(c)) => synthesize_into(out, worktoken, rule, secret, c.tablename, c.rowindex),
+                _ => false,
+            };
+            if !drawn {
+                out.extend_from_slice(worktoken);
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::Mask => {
+            match &config.mask {
+                Some(rule) => mask_into(out, worktoken, rule),
+                None => out.extend_from_slice(worktoken),
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::CardHash => {
+            if !cardhash_into(out, worktoken, secret, config.len as usize) {
+                // Not a card number: left as it is
+                out.extend_from_slice(worktoken);
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::IdHash => {
+            match config.id_kind {
+                Some(kind) => idhash_into(out, worktoken, secret, kind),
+                None => out.extend_from_slice(worktoken),
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::UuidHash => {
+            match uuidhash_into(out, worktoken, secret) {
+                Some(quoting) => quoting,
+                None => {
+                    // Not a UUID: left as it is
+                    out.extend_from_slice(worktoken);
+                    QuoteMode::AsInput
+                }
+            }
+        }
+
+        AnonType::IdMap => {
+            if !idmap_into(out, worktoken, secret, config.id_domain, ctx.and_then(|c| c.column)) {
+                // Not an integer of the domain: left as it is
+                out.extend_from_slice(worktoken);
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::IpHash => {
+            let owned;
+            let pan = match ctx.as_deref().and_then(|c| c.crypto_pan) {
+                Some(pan) => pan,
+                None => {
+                    owned = CryptoPan::new(secret);
+                    &owned
+                }
+            };
+            match iphash_into(out, worktoken, pan, config.keep_private) {
+                Some(quoting) => quoting,
+                None => {
+                    // Not an IP address: left as it is
+                    out.extend_from_slice(worktoken);
+                    QuoteMode::AsInput
+                }
+            }
+        }
+
+        AnonType::Noise => {
+            let Some(spec) = &config.noise else {
+                out.extend_from_slice(worktoken);
+                return QuoteMode::AsInput;
+            };
+            let ctx = ctx.as_deref();
+            let key = if spec.by_key {
+                if let Some(c) = ctx {
+                    if c.tablekey.is_empty() && c.bfirstinsert {
+                        eprintln!(
+                            "WARNING! Table {} fields order: for noise bykey mode, the key must be defined before the field to anonymize",
+                            c.tablename
+                        );
+                    }
+                }
+                ctx.map(|c| c.tablekey.as_str())
+            } else {
+                None
+            };
+            let column = ctx.and_then(|c| c.column);
+            if !noise_into(out, worktoken, spec, secret, key, column) {
+                // Not a number: left as it is
+                out.extend_from_slice(worktoken);
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::Generalize => {
+            match &config.generalize {
+                Some(rule) => generalize_into(out, worktoken, rule, ctx.and_then(|c| c.column)),
+                None => out.extend_from_slice(worktoken),
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::RegexReplace => {
+            match &config.regex_replace {
+                Some(rule) => rule.replace_into(out, worktoken, secret),
+                None => out.extend_from_slice(worktoken),
+            }
+            QuoteMode::AsInput
+        }
+
+        AnonType::Json
+        | AnonType::PhpSerialized
+        | AnonType::Xml
+        | AnonType::Yaml
+        | AnonType::Url
+        | AnonType::Scrub
+        | AnonType::Py
+        | AnonType::Remove => {
+            // JSON and Py handled at a higher level
             QuoteMode::AsInput
         }
     }
 }
 
+/// Remove MySQL backslash escaping, as written by mysqldump. Returns the unescaped text and, for each of
+/// its bytes, the offset of the escape sequence it comes from.
+pub fn mysql_unescape(text: &str) -> (String, Vec<usize>) {
+    let (out, offsets) = mysql_unescape_bytes(text.as_bytes());
+    // Only ASCII bytes were rewritten, so the text is still valid UTF-8
+    (String::from_utf8_lossy(&out).into_owned(), offsets)
+}
+
+/// Same as `mysql_unescape`, for binary strings.
+pub fn mysql_unescape_bytes(bytes: &[u8]) -> (Vec<u8>, Vec<usize>) {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut offsets = Vec::with_capacity(bytes.len() + 1);
+    let mut i = 0;
+    while i < bytes.len() {
+        offsets.push(i);
+        match (bytes[i], bytes.get(i + 1)) {
+            (b'\\', Some(&c)) => {
+                out.push(match c {
+                    b'0' => 0,
+                    b'b' => 0x08,
+                    b'n' => b'\n',
+                    b'r' => b'\r',
+                    b't' => b'\t',
+                    b'Z' => 0x1a,
+                    c => c,
+                });
+                i += 2;
+            }
+            (b'\'', Some(b'\'')) => {
+                out.push(b'\'');
+                i += 2;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    offsets.push(bytes.len());
+    (out, offsets)
+}
+
+/// Escape a string with backslashes, the way mysqldump does.
+pub fn mysql_backslash_escape(src: &str) -> String {
+    let mut out = Vec::with_capacity(src.len() + 8);
+    mysql_backslash_escape_into(&mut out, src.as_bytes());
+    // Only ASCII bytes were escaped, so the text is still valid UTF-8
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Append `src` escaped with backslashes, the way mysqldump does, to `out`.
+/// Works on binary strings too.
+pub fn mysql_backslash_escape_into(out: &mut Vec<u8>, src: &[u8]) {
+    for &b in src {
+        match b {
+            0 => out.extend_from_slice(b"\\0"),
+            b'\n' => out.extend_from_slice(b"\\n"),
+            b'\r' => out.extend_from_slice(b"\\r"),
+            0x1a => out.extend_from_slice(b"\\Z"),
+            b'\'' | b'"' | b'\\' => {
+                out.push(b'\\');
+                out.push(b);
+            }
+            b => out.push(b),
+        }
+    }
+}
+
+/// Anonymize each `separator`-separated part of `text` on its own, keeping
+/// the separators. Parts whose rule forces quoting are quoted individually.
+pub fn anonymize_separated_into(
+    out: &mut Vec<u8>,
+    config: &AnonBase,
+    text: &str,
+    secret: &[u8],
+    mut ctx: Option<&mut AnonContext<'_>>,
+) {
+    out.clear();
+    let separator = config.separator.unwrap_or(',');
+    let mut sep_buf = [0u8; 4];
+    let mut part_out = Vec::new();
+
+    for (i, part) in text.split(separator).enumerate() {
+        if i > 0 {
+            out.extend_from_slice(separator.encode_utf8(&mut sep_buf).as_bytes());
+        }
+        let quoting = anonymize_token_into(
+            &mut part_out,
+            false,
+            config,
+            part.as_bytes(),
+            secret,
+            ctx.as_deref_mut(),
+        );
+        if quoting == QuoteMode::ForceTrue {
+            out.push(b'\'');
+            out.extend_from_slice(&part_out);
+            out.push(b'\'');
+        } else {
+            out.extend_from_slice(&part_out);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_mysql_unescape() {
+        let (json, offsets) = mysql_unescape(r"a\nb\\c''d");
+        assert_eq!(json, "a\nb\\c'd");
+        assert_eq!(offsets, vec![0, 1, 3, 4, 6, 7, 9, 10]);
+    }
+
+    #[test]
+    fn test_mysql_backslash_escape() {
+        let text = "a'b\"c\\d\ne\0";
+        assert_eq!(mysql_backslash_escape(text), r#"a\'b\"c\\d\ne\0"#);
+        assert_eq!(mysql_unescape(&mysql_backslash_escape(text)).0, text);
+    }
+
     #[test]
     fn test_remove_quote() {
         assert_eq!(remove_quote(b"'hello'"), b"hello");
@@ -398,6 +688,8 @@ mod tests {
             rowindex: 0,
             bfirstinsert: true,
             tablename: "test",
+            column: None,
+            crypto_pan: None,
         };
         let result = anonymize_token(false, &config, b"42", b"secret", Some(&mut ctx));
         assert_eq!(result.data, b"42");
@@ -417,6 +709,8 @@ mod tests {
             rowindex: 0,
             bfirstinsert: false,
             tablename: "test",
+            column: None,
+            crypto_pan: None,
         };
         let result = anonymize_token(false, &config, b"Roger", b"secret", Some(&mut ctx));
         assert_eq!(result.data, b"player10");
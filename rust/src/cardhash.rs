@@ -0,0 +1,80 @@
+use crate::anonymize::make_readable_hash_into;
+
+/// Luhn check digit of `payload`, a string of ASCII digits.
+pub fn luhn_check_digit(payload: &[u8]) -> u8 {
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &b)| {
+            let d = (b - b'0') as u32;
+            match i % 2 {
+                0 if d > 4 => d * 2 - 9,
+                0 => d * 2,
+                _ => d,
+            }
+        })
+        .sum();
+    b'0' + ((10 - sum % 10) % 10) as u8
+}
+
+/// Replace the digits of a card number after the first `keep` ones with
+/// HMAC digits, and recompute the Luhn check digit. Separators (spaces and
+/// dashes) stay where they are. Returns false, writing nothing, for values
+/// that are not card-like numbers.
+pub fn cardhash_into(out: &mut Vec<u8>, token: &[u8], secret: &[u8], keep: usize) -> bool {
+    if !token.iter().all(|&b| b.is_ascii_digit() || b == b' ' || b == b'-') {
+        return false;
+    }
+    let digits: Vec<u8> = token.iter().copied().filter(u8::is_ascii_digit).collect();
+    if digits.len() < keep + 2 {
+        return false;
+    }
+    // Hash the digits only, so that every formatting of a number gives the same result
+    let mut new_digits = digits[..keep].to_vec();
+    make_readable_hash_into(&mut new_digits, &digits, secret, digits.len() - keep - 1, b'0', b'9');
+    new_digits.push(luhn_check_digit(&new_digits));
+
+    let mut next = new_digits.into_iter();
+    out.extend(token.iter().map(|&b| if b.is_ascii_digit() { next.next().unwrap() } else { b }));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cardhash(token: &[u8], keep: usize) -> Option<Vec<u8>> {
+        let mut out = Vec::new();
+        cardhash_into(&mut out, token, b"secret", keep).then_some(out)
+    }
+
+    #[test]
+    fn test_luhn_check_digit() {
+        assert_eq!(luhn_check_digit(b"411111111111111"), b'1');
+        assert_eq!(luhn_check_digit(b"7992739871"), b'3');
+        assert_eq!(luhn_check_digit(b"555555555555444"), b'4');
+    }
+
+    #[test]
+    fn test_cardhash() {
+        let plain = cardhash(b"4111111111111111", 6).unwrap();
+        assert_eq!(&plain[..6], b"411111");
+        assert_ne!(plain, b"4111111111111111");
+        assert_eq!(luhn_check_digit(&plain[..15]), plain[15]);
+
+        // Separators kept, same digits whatever the formatting
+        let spaced = cardhash(b"4111 1111-1111 1111", 6).unwrap();
+        assert_eq!(spaced.len(), 19);
+        assert_eq!((spaced[4], spaced[9], spaced[14]), (b' ', b'-', b' '));
+        let digits: Vec<u8> = spaced.into_iter().filter(u8::is_ascii_digit).collect();
+        assert_eq!(digits, plain);
+
+        let amex = cardhash(b"378282246310005", 8).unwrap();
+        assert_eq!(&amex[..8], b"37828224");
+        assert_eq!(luhn_check_digit(&amex[..14]), amex[14]);
+
+        assert_eq!(cardhash(b"N/A", 8), None);
+        assert_eq!(cardhash(b"123456789", 8), None);
+    }
+}
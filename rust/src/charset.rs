@@ -0,0 +1,354 @@
+/// Character set of the values found in a dump.
+///
+/// mysqldump writes values in the connection character set (`SET NAMES`).
+/// Rules work on UTF-8 text, so values in any other character set are
+/// decoded before anonymization and re-encoded afterwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Charset {
+    Utf8,
+    Latin1,
+    Latin2,
+    Latin5,
+    Cp1250,
+    Cp1251,
+    Koi8r,
+    Greek,
+    Hebrew,
+    Binary,
+}
+
+impl Charset {
+    /// Map a MySQL character set name (as used in `SET NAMES` or
+    /// `DEFAULT CHARSET=`) to a supported charset.
+    pub fn from_mysql_name(name: &str) -> Option<Charset> {
+        match name.to_ascii_lowercase().as_str() {
+            "utf8" | "utf8mb3" | "utf8mb4" | "utf-8" => Some(Charset::Utf8),
+            // MySQL latin1 is Windows-1252, ascii is a subset of it
+            "latin1" | "cp1252" | "ascii" => Some(Charset::Latin1),
+            "latin2" => Some(Charset::Latin2),
+            "latin5" => Some(Charset::Latin5),
+            "cp1250" => Some(Charset::Cp1250),
+            "cp1251" => Some(Charset::Cp1251),
+            "koi8r" => Some(Charset::Koi8r),
+            "greek" => Some(Charset::Greek),
+            "hebrew" => Some(Charset::Hebrew),
+            "binary" => Some(Charset::Binary),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Charset::Utf8 => "utf8mb4",
+            Charset::Latin1 => "latin1",
+            Charset::Latin2 => "latin2",
+            Charset::Latin5 => "latin5",
+            Charset::Cp1250 => "cp1250",
+            Charset::Cp1251 => "cp1251",
+            Charset::Koi8r => "koi8r",
+            Charset::Greek => "greek",
+            Charset::Hebrew => "hebrew",
+            Charset::Binary => "binary",
+        }
+    }
+
+    pub fn is_utf8(&self) -> bool {
+        *self == Charset::Utf8
+    }
+
+    fn upper_half(&self) -> Option<&'static [u16; 128]> {
+        match self {
+            Charset::Latin1 => Some(&CP1252),
+            Charset::Latin2 => Some(&LATIN2),
+            Charset::Latin5 => Some(&LATIN5),
+            Charset::Cp1250 => Some(&CP1250),
+            Charset::Cp1251 => Some(&CP1251),
+            Charset::Koi8r => Some(&KOI8R),
+            Charset::Greek => Some(&GREEK),
+            Charset::Hebrew => Some(&HEBREW),
+            Charset::Utf8 | Charset::Binary => None,
+        }
+    }
+
+    /// Decode bytes in this charset to a UTF-8 string.
+    /// Every single-byte charset decodes each byte to a distinct character
+    /// (bytes a code page leaves undefined go to the private use area U+F780..),
+    /// so `encode(decode(b)) == b` for any input.
+    pub fn decode(&self, src: &[u8]) -> String {
+        match self {
+            Charset::Utf8 => String::from_utf8_lossy(src).into_owned(),
+            Charset::Binary => src.iter().map(|&b| b as char).collect(),
+            _ => {
+                let table = self.upper_half().unwrap();
+                let mut out = String::with_capacity(src.len() + src.len() / 4);
+                for &b in src {
+                    if b < 0x80 {
+                        out.push(b as char);
+                    } else {
+                        let cp = table[(b - 0x80) as usize] as u32;
+                        out.push(char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                }
+                out
+            }
+        }
+    }
+
+    /// Encode a UTF-8 string into this charset.
+    /// Characters with no representation are replaced by `?`.
+    pub fn encode(&self, src: &str) -> Vec<u8> {
+        match self {
+            Charset::Utf8 => src.as_bytes().to_vec(),
+            Charset::Binary => src
+                .chars()
+                .map(|c| if (c as u32) < 0x100 { c as u8 } else { b'?' })
+                .collect(),
+            _ => {
+                let table = self.upper_half().unwrap();
+                let mut out = Vec::with_capacity(src.len());
+                for c in src.chars() {
+                    let cp = c as u32;
+                    if cp < 0x80 {
+                        out.push(cp as u8);
+                    } else {
+                        match table.iter().position(|&t| t as u32 == cp) {
+                            Some(i) => out.push(0x80 + i as u8),
+                            None => out.push(b'?'),
+                        }
+                    }
+                }
+                out
+            }
+        }
+    }
+
+    /// Encode the UTF-8 text of `src` into this charset, copying the bytes
+    /// that are not UTF-8 (written by rules producing bytes) as they are.
+    pub fn encode_bytes(&self, src: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(src.len());
+        for chunk in src.utf8_chunks() {
+            out.extend_from_slice(&self.encode(chunk.valid()));
+            out.extend_from_slice(chunk.invalid());
+        }
+        out
+    }
+}
+
+/// Upper half (0x80-0xFF) of latin1 (MySQL latin1 is Windows-1252).
+const CP1252: [u16; 128] = [
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008D, 0x017D, 0x008F,
+    0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+    0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF,
+    0x00C0, 0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7,
+    0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF,
+    0x00D0, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6, 0x00D7,
+    0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF,
+    0x00E0, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7,
+    0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC, 0x00ED, 0x00EE, 0x00EF,
+    0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+    0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF,
+];
+
+/// Upper half (0x80-0xFF) of latin2 (ISO-8859-2).
+const LATIN2: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x008D, 0x008E, 0x008F,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F,
+    0x00A0, 0x0104, 0x02D8, 0x0141, 0x00A4, 0x013D, 0x015A, 0x00A7,
+    0x00A8, 0x0160, 0x015E, 0x0164, 0x0179, 0x00AD, 0x017D, 0x017B,
+    0x00B0, 0x0105, 0x02DB, 0x0142, 0x00B4, 0x013E, 0x015B, 0x02C7,
+    0x00B8, 0x0161, 0x015F, 0x0165, 0x017A, 0x02DD, 0x017E, 0x017C,
+    0x0154, 0x00C1, 0x00C2, 0x0102, 0x00C4, 0x0139, 0x0106, 0x00C7,
+    0x010C, 0x00C9, 0x0118, 0x00CB, 0x011A, 0x00CD, 0x00CE, 0x010E,
+    0x0110, 0x0143, 0x0147, 0x00D3, 0x00D4, 0x0150, 0x00D6, 0x00D7,
+    0x0158, 0x016E, 0x00DA, 0x0170, 0x00DC, 0x00DD, 0x0162, 0x00DF,
+    0x0155, 0x00E1, 0x00E2, 0x0103, 0x00E4, 0x013A, 0x0107, 0x00E7,
+    0x010D, 0x00E9, 0x0119, 0x00EB, 0x011B, 0x00ED, 0x00EE, 0x010F,
+    0x0111, 0x0144, 0x0148, 0x00F3, 0x00F4, 0x0151, 0x00F6, 0x00F7,
+    0x0159, 0x016F, 0x00FA, 0x0171, 0x00FC, 0x00FD, 0x0163, 0x02D9,
+];
+
+/// Upper half (0x80-0xFF) of cp1250.
+const CP1250: [u16; 128] = [
+    0x20AC, 0xF781, 0x201A, 0xF783, 0x201E, 0x2026, 0x2020, 0x2021,
+    0xF788, 0x2030, 0x0160, 0x2039, 0x015A, 0x0164, 0x017D, 0x0179,
+    0xF790, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0xF798, 0x2122, 0x0161, 0x203A, 0x015B, 0x0165, 0x017E, 0x017A,
+    0x00A0, 0x02C7, 0x02D8, 0x0141, 0x00A4, 0x0104, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x015E, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x017B,
+    0x00B0, 0x00B1, 0x02DB, 0x0142, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x0105, 0x015F, 0x00BB, 0x013D, 0x02DD, 0x013E, 0x017C,
+    0x0154, 0x00C1, 0x00C2, 0x0102, 0x00C4, 0x0139, 0x0106, 0x00C7,
+    0x010C, 0x00C9, 0x0118, 0x00CB, 0x011A, 0x00CD, 0x00CE, 0x010E,
+    0x0110, 0x0143, 0x0147, 0x00D3, 0x00D4, 0x0150, 0x00D6, 0x00D7,
+    0x0158, 0x016E, 0x00DA, 0x0170, 0x00DC, 0x00DD, 0x0162, 0x00DF,
+    0x0155, 0x00E1, 0x00E2, 0x0103, 0x00E4, 0x013A, 0x0107, 0x00E7,
+    0x010D, 0x00E9, 0x0119, 0x00EB, 0x011B, 0x00ED, 0x00EE, 0x010F,
+    0x0111, 0x0144, 0x0148, 0x00F3, 0x00F4, 0x0151, 0x00F6, 0x00F7,
+    0x0159, 0x016F, 0x00FA, 0x0171, 0x00FC, 0x00FD, 0x0163, 0x02D9,
+];
+
+/// Upper half (0x80-0xFF) of cp1251.
+const CP1251: [u16; 128] = [
+    0x0402, 0x0403, 0x201A, 0x0453, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x20AC, 0x2030, 0x0409, 0x2039, 0x040A, 0x040C, 0x040B, 0x040F,
+    0x0452, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0xF798, 0x2122, 0x0459, 0x203A, 0x045A, 0x045C, 0x045B, 0x045F,
+    0x00A0, 0x040E, 0x045E, 0x0408, 0x00A4, 0x0490, 0x00A6, 0x00A7,
+    0x0401, 0x00A9, 0x0404, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x0407,
+    0x00B0, 0x00B1, 0x0406, 0x0456, 0x0491, 0x00B5, 0x00B6, 0x00B7,
+    0x0451, 0x2116, 0x0454, 0x00BB, 0x0458, 0x0405, 0x0455, 0x0457,
+    0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0416, 0x0417,
+    0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F,
+    0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427,
+    0x0428, 0x0429, 0x042A, 0x042B, 0x042C, 0x042D, 0x042E, 0x042F,
+    0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0436, 0x0437,
+    0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E, 0x043F,
+    0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447,
+    0x0448, 0x0449, 0x044A, 0x044B, 0x044C, 0x044D, 0x044E, 0x044F,
+];
+
+/// Upper half (0x80-0xFF) of koi8r.
+const KOI8R: [u16; 128] = [
+    0x2500, 0x2502, 0x250C, 0x2510, 0x2514, 0x2518, 0x251C, 0x2524,
+    0x252C, 0x2534, 0x253C, 0x2580, 0x2584, 0x2588, 0x258C, 0x2590,
+    0x2591, 0x2592, 0x2593, 0x2320, 0x25A0, 0x2219, 0x221A, 0x2248,
+    0x2264, 0x2265, 0x00A0, 0x2321, 0x00B0, 0x00B2, 0x00B7, 0x00F7,
+    0x2550, 0x2551, 0x2552, 0x0451, 0x2553, 0x2554, 0x2555, 0x2556,
+    0x2557, 0x2558, 0x2559, 0x255A, 0x255B, 0x255C, 0x255D, 0x255E,
+    0x255F, 0x2560, 0x2561, 0x0401, 0x2562, 0x2563, 0x2564, 0x2565,
+    0x2566, 0x2567, 0x2568, 0x2569, 0x256A, 0x256B, 0x256C, 0x00A9,
+    0x044E, 0x0430, 0x0431, 0x0446, 0x0434, 0x0435, 0x0444, 0x0433,
+    0x0445, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E,
+    0x043F, 0x044F, 0x0440, 0x0441, 0x0442, 0x0443, 0x0436, 0x0432,
+    0x044C, 0x044B, 0x0437, 0x0448, 0x044D, 0x0449, 0x0447, 0x044A,
+    0x042E, 0x0410, 0x0411, 0x0426, 0x0414, 0x0415, 0x0424, 0x0413,
+    0x0425, 0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E,
+    0x041F, 0x042F, 0x0420, 0x0421, 0x0422, 0x0423, 0x0416, 0x0412,
+    0x042C, 0x042B, 0x0417, 0x0428, 0x042D, 0x0429, 0x0427, 0x042A,
+];
+
+/// Upper half (0x80-0xFF) of greek (ISO-8859-7).
+const GREEK: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x008D, 0x008E, 0x008F,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F,
+    0x00A0, 0x2018, 0x2019, 0x00A3, 0x20AC, 0x20AF, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x037A, 0x00AB, 0x00AC, 0x00AD, 0xF7AE, 0x2015,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x0384, 0x0385, 0x0386, 0x00B7,
+    0x0388, 0x0389, 0x038A, 0x00BB, 0x038C, 0x00BD, 0x038E, 0x038F,
+    0x0390, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397,
+    0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F,
+    0x03A0, 0x03A1, 0xF7D2, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7,
+    0x03A8, 0x03A9, 0x03AA, 0x03AB, 0x03AC, 0x03AD, 0x03AE, 0x03AF,
+    0x03B0, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7,
+    0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF,
+    0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7,
+    0x03C8, 0x03C9, 0x03CA, 0x03CB, 0x03CC, 0x03CD, 0x03CE, 0xF7FF,
+];
+
+/// Upper half (0x80-0xFF) of hebrew (ISO-8859-8).
+const HEBREW: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x008D, 0x008E, 0x008F,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F,
+    0x00A0, 0xF7A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00D7, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00F7, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0xF7BF,
+    0xF7C0, 0xF7C1, 0xF7C2, 0xF7C3, 0xF7C4, 0xF7C5, 0xF7C6, 0xF7C7,
+    0xF7C8, 0xF7C9, 0xF7CA, 0xF7CB, 0xF7CC, 0xF7CD, 0xF7CE, 0xF7CF,
+    0xF7D0, 0xF7D1, 0xF7D2, 0xF7D3, 0xF7D4, 0xF7D5, 0xF7D6, 0xF7D7,
+    0xF7D8, 0xF7D9, 0xF7DA, 0xF7DB, 0xF7DC, 0xF7DD, 0xF7DE, 0x2017,
+    0x05D0, 0x05D1, 0x05D2, 0x05D3, 0x05D4, 0x05D5, 0x05D6, 0x05D7,
+    0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC, 0x05DD, 0x05DE, 0x05DF,
+    0x05E0, 0x05E1, 0x05E2, 0x05E3, 0x05E4, 0x05E5, 0x05E6, 0x05E7,
+    0x05E8, 0x05E9, 0x05EA, 0xF7FB, 0xF7FC, 0x200E, 0x200F, 0xF7FF,
+];
+
+/// Upper half (0x80-0xFF) of latin5 (ISO-8859-9).
+const LATIN5: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x008D, 0x008E, 0x008F,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F,
+    0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF,
+    0x00C0, 0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7,
+    0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF,
+    0x011E, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6, 0x00D7,
+    0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x0130, 0x015E, 0x00DF,
+    0x00E0, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7,
+    0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC, 0x00ED, 0x00EE, 0x00EF,
+    0x011F, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+    0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x0131, 0x015F, 0x00FF,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_mysql_name() {
+        assert_eq!(Charset::from_mysql_name("utf8mb4"), Some(Charset::Utf8));
+        assert_eq!(Charset::from_mysql_name("UTF8"), Some(Charset::Utf8));
+        assert_eq!(Charset::from_mysql_name("latin1"), Some(Charset::Latin1));
+        assert_eq!(Charset::from_mysql_name("cp1251"), Some(Charset::Cp1251));
+        assert_eq!(Charset::from_mysql_name("sjis"), None);
+    }
+
+    #[test]
+    fn test_latin1_decode_encode() {
+        // "café €5" in Windows-1252
+        let src = b"caf\xe9 \x805";
+        let decoded = Charset::Latin1.decode(src);
+        assert_eq!(decoded, "café €5");
+        assert_eq!(Charset::Latin1.encode(&decoded), src);
+    }
+
+    #[test]
+    fn test_unmappable_char() {
+        assert_eq!(Charset::Latin1.encode("a\u{4e2d}b"), b"a?b");
+    }
+
+    #[test]
+    fn test_roundtrip_all_bytes() {
+        let all: Vec<u8> = (0..=255u8).collect();
+        for cs in [
+            Charset::Latin1,
+            Charset::Latin2,
+            Charset::Latin5,
+            Charset::Cp1250,
+            Charset::Cp1251,
+            Charset::Koi8r,
+            Charset::Greek,
+            Charset::Hebrew,
+            Charset::Binary,
+        ] {
+            assert_eq!(cs.encode(&cs.decode(&all)), all, "{}", cs.name());
+        }
+    }
+
+    #[test]
+    fn test_encode_bytes() {
+        assert_eq!(Charset::Latin1.encode_bytes(b"\xc3\xa9\xffa"), b"\xe9\xffa");
+        assert_eq!(Charset::Latin1.encode_bytes(b"\x00\xa0\xe9"), b"\x00\xa0\xe9");
+    }
+
+    #[test]
+    fn test_koi8r() {
+        // "Привет" in KOI8-R
+        let src = b"\xf0\xd2\xc9\xd7\xc5\xd4";
+        assert_eq!(Charset::Koi8r.decode(src), "Привет");
+    }
+}
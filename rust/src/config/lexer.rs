@@ -9,7 +9,12 @@ pub enum Token {
     Stats,
     PyPath,
     PyScript,
+    JsonPreserve,
     Tables,
+    Report,
+    Keys,
+    Quasi,
+    Sensitive,
     Yes,
     No,
     Fixed,
@@ -18,6 +23,11 @@ pub enum Token {
     FixedUnquoted,
     TextHash,
     EmailHash,
+    Normalize,
+    StripTags,
+    KeepDomain,
+    HashDomain,
+    KeepShape,
     IntHash,
     Key,
     AppendKey,
@@ -25,10 +35,57 @@ pub enum Token {
     AppendIndex,
     PrependIndex,
     Substring,
+    RegexReplace,
+    CardHash,
+    IdHash,
+    UuidHash,
+    IdMap,
+    Mask,
+    Encrypt,
+    Shuffle,
+    Synthesize,
+    Categorical,
+    Bins,
+    Base64,
+    Hex,
+    Width,
+    First,
+    Last,
+    Skip,
+    IpHash,
+    KeepPrivate,
+    Noise,
+    ByKey,
+    Generalize,
+    Bucket,
+    Round,
+    Year,
+    Quarter,
+    Month,
+    Prefix,
     Truncate,
     PyDef,
     Json,
+    PhpSerialized,
+    Xml,
+    Yaml,
+    Url,
+    Param,
+    Host,
+    UserInfo,
+    Segment,
+    Fragment,
+    Fallback,
+    Scrub,
+    Email,
+    Phone,
+    Ipv4,
+    Ipv6,
+    Card,
+    Iban,
     Path,
+    Remove,
+    Null,
     SeparatedBy,
     Regex,
     // Values
@@ -50,7 +107,12 @@ impl fmt::Display for Token {
             Token::Stats => write!(f, "stats"),
             Token::PyPath => write!(f, "pypath"),
             Token::PyScript => write!(f, "pyscript"),
+            Token::JsonPreserve => write!(f, "jsonpreserve"),
             Token::Tables => write!(f, "tables"),
+            Token::Report => write!(f, "report"),
+            Token::Keys => write!(f, "keys"),
+            Token::Quasi => write!(f, "quasi"),
+            Token::Sensitive => write!(f, "sensitive"),
             Token::Yes => write!(f, "'yes'"),
             Token::No => write!(f, "'no'"),
             Token::Fixed => write!(f, "fixed"),
@@ -59,6 +121,11 @@ impl fmt::Display for Token {
             Token::FixedUnquoted => write!(f, "fixed unquoted"),
             Token::TextHash => write!(f, "texthash"),
             Token::EmailHash => write!(f, "emailhash"),
+            Token::Normalize => write!(f, "normalize"),
+            Token::StripTags => write!(f, "striptags"),
+            Token::KeepDomain => write!(f, "keepdomain"),
+            Token::HashDomain => write!(f, "hashdomain"),
+            Token::KeepShape => write!(f, "keepshape"),
             Token::IntHash => write!(f, "inthash"),
             Token::Key => write!(f, "key"),
             Token::AppendKey => write!(f, "appendkey"),
@@ -66,10 +133,57 @@ impl fmt::Display for Token {
             Token::AppendIndex => write!(f, "appendindex"),
             Token::PrependIndex => write!(f, "prependindex"),
             Token::Substring => write!(f, "substring"),
+            Token::RegexReplace => write!(f, "regexreplace"),
+            Token::CardHash => write!(f, "cardhash"),
+            Token::IdHash => write!(f, "idhash"),
+            Token::UuidHash => write!(f, "uuidhash"),
+            Token::IdMap => write!(f, "idmap"),
+            Token::Mask => write!(f, "mask"),
+            Token::Encrypt => write!(f, "encrypt"),
+            Token::Shuffle => write!(f, "shuffle"),
+            Token::Synthesize => write!(f, "synthesize"),
+            Token::Categorical => write!(f, "categorical"),
+            Token::Bins => write!(f, "bins"),
+            Token::Base64 => write!(f, "base64"),
+            Token::Hex => write!(f, "hex"),
+            Token::Width => write!(f, "width"),
+            Token::First => write!(f, "first"),
+            Token::Last => write!(f, "last"),
+            Token::Skip => write!(f, "skip"),
+            Token::IpHash => write!(f, "iphash"),
+            Token::KeepPrivate => write!(f, "keepprivate"),
+            Token::Noise => write!(f, "noise"),
+            Token::ByKey => write!(f, "bykey"),
+            Token::Generalize => write!(f, "generalize"),
+            Token::Bucket => write!(f, "bucket"),
+            Token::Round => write!(f, "round"),
+            Token::Year => write!(f, "year"),
+            Token::Quarter => write!(f, "quarter"),
+            Token::Month => write!(f, "month"),
+            Token::Prefix => write!(f, "prefix"),
             Token::Truncate => write!(f, "truncate"),
             Token::PyDef => write!(f, "pydef"),
             Token::Json => write!(f, "json"),
+            Token::PhpSerialized => write!(f, "phpserialized"),
+            Token::Xml => write!(f, "xml"),
+            Token::Yaml => write!(f, "yaml"),
+            Token::Url => write!(f, "url"),
+            Token::Param => write!(f, "param"),
+            Token::Host => write!(f, "host"),
+            Token::UserInfo => write!(f, "userinfo"),
+            Token::Segment => write!(f, "segment"),
+            Token::Fragment => write!(f, "fragment"),
+            Token::Fallback => write!(f, "fallback"),
+            Token::Scrub => write!(f, "scrub"),
+            Token::Email => write!(f, "email"),
+            Token::Phone => write!(f, "phone"),
+            Token::Ipv4 => write!(f, "ipv4"),
+            Token::Ipv6 => write!(f, "ipv6"),
+            Token::Card => write!(f, "card"),
+            Token::Iban => write!(f, "iban"),
             Token::Path => write!(f, "path"),
+            Token::Remove => write!(f, "remove"),
+            Token::Null => write!(f, "null"),
             Token::SeparatedBy => write!(f, "separated by"),
             Token::Regex => write!(f, "regex"),
             Token::Str(s) => write!(f, "'{}'", s),
@@ -275,9 +389,19 @@ impl Lexer {
             "stats" => Ok(Token::Stats),
             "pypath" => Ok(Token::PyPath),
             "pyscript" => Ok(Token::PyScript),
+            "jsonpreserve" => Ok(Token::JsonPreserve),
             "tables" => Ok(Token::Tables),
+            "report" => Ok(Token::Report),
+            "keys" => Ok(Token::Keys),
+            "quasi" => Ok(Token::Quasi),
+            "sensitive" => Ok(Token::Sensitive),
             "texthash" => Ok(Token::TextHash),
             "emailhash" => Ok(Token::EmailHash),
+            "normalize" => Ok(Token::Normalize),
+            "striptags" => Ok(Token::StripTags),
+            "keepdomain" => Ok(Token::KeepDomain),
+            "hashdomain" => Ok(Token::HashDomain),
+            "keepshape" => Ok(Token::KeepShape),
             "inthash" => Ok(Token::IntHash),
             "key" => Ok(Token::Key),
             "appendkey" => Ok(Token::AppendKey),
@@ -285,10 +409,57 @@ impl Lexer {
             "appendindex" => Ok(Token::AppendIndex),
             "prependindex" => Ok(Token::PrependIndex),
             "substring" => Ok(Token::Substring),
+            "regexreplace" => Ok(Token::RegexReplace),
+            "cardhash" => Ok(Token::CardHash),
+            "idhash" => Ok(Token::IdHash),
+            "uuidhash" => Ok(Token::UuidHash),
+            "idmap" => Ok(Token::IdMap),
+            "mask" => Ok(Token::Mask),
+            "encrypt" => Ok(Token::Encrypt),
+            "shuffle" => Ok(Token::Shuffle),
+            "synthesize" => Ok(Token::Synthesize),
+            "categorical" => Ok(Token::Categorical),
+            "bins" => Ok(Token::Bins),
+            "base64" => Ok(Token::Base64),
+            "hex" => Ok(Token::Hex),
+            "width" => Ok(Token::Width),
+            "first" => Ok(Token::First),
+            "last" => Ok(Token::Last),
+            "skip" => Ok(Token::Skip),
+            "iphash" => Ok(Token::IpHash),
+            "keepprivate" => Ok(Token::KeepPrivate),
+            "noise" => Ok(Token::Noise),
+            "bykey" => Ok(Token::ByKey),
+            "generalize" => Ok(Token::Generalize),
+            "bucket" => Ok(Token::Bucket),
+            "round" => Ok(Token::Round),
+            "year" => Ok(Token::Year),
+            "quarter" => Ok(Token::Quarter),
+            "month" => Ok(Token::Month),
+            "prefix" => Ok(Token::Prefix),
             "truncate" => Ok(Token::Truncate),
             "pydef" => Ok(Token::PyDef),
             "json" => Ok(Token::Json),
+            "phpserialized" => Ok(Token::PhpSerialized),
+            "xml" => Ok(Token::Xml),
+            "yaml" => Ok(Token::Yaml),
+            "url" => Ok(Token::Url),
+            "param" => Ok(Token::Param),
+            "host" => Ok(Token::Host),
+            "userinfo" => Ok(Token::UserInfo),
+            "segment" => Ok(Token::Segment),
+            "fragment" => Ok(Token::Fragment),
+            "fallback" => Ok(Token::Fallback),
+            "scrub" => Ok(Token::Scrub),
+            "email" => Ok(Token::Email),
+            "phone" => Ok(Token::Phone),
+            "ipv4" => Ok(Token::Ipv4),
+            "ipv6" => Ok(Token::Ipv6),
+            "card" => Ok(Token::Card),
+            "iban" => Ok(Token::Iban),
             "path" => Ok(Token::Path),
+            "remove" => Ok(Token::Remove),
+            "null" => Ok(Token::Null),
             "regex" => Ok(Token::Regex),
             "fixed" => {
                 // Check for two-word variants: "fixed null", "fixed quoted", "fixed unquoted"
@@ -411,6 +582,162 @@ mod tests {
         assert_eq!(lex.next_token().unwrap(), Token::Fixed);
     }
 
+    #[test]
+    fn test_json_only_actions() {
+        let mut lex = Lexer::new("remove null fixed null");
+        assert_eq!(lex.next_token().unwrap(), Token::Remove);
+        assert_eq!(lex.next_token().unwrap(), Token::Null);
+        assert_eq!(lex.next_token().unwrap(), Token::FixedNull);
+    }
+
+    #[test]
+    fn test_path_blocks() {
+        let mut lex = Lexer::new("phpserialized xml yaml { path");
+        assert_eq!(lex.next_token().unwrap(), Token::PhpSerialized);
+        assert_eq!(lex.next_token().unwrap(), Token::Xml);
+        assert_eq!(lex.next_token().unwrap(), Token::Yaml);
+        assert_eq!(lex.next_token().unwrap(), Token::LBrace);
+        assert_eq!(lex.next_token().unwrap(), Token::Path);
+    }
+
+    #[test]
+    fn test_url_block() {
+        let mut lex = Lexer::new("url { param host userinfo segment 2 fragment fallback");
+        assert_eq!(lex.next_token().unwrap(), Token::Url);
+        assert_eq!(lex.next_token().unwrap(), Token::LBrace);
+        assert_eq!(lex.next_token().unwrap(), Token::Param);
+        assert_eq!(lex.next_token().unwrap(), Token::Host);
+        assert_eq!(lex.next_token().unwrap(), Token::UserInfo);
+        assert_eq!(lex.next_token().unwrap(), Token::Segment);
+        assert_eq!(lex.next_token().unwrap(), Token::Length(2));
+        assert_eq!(lex.next_token().unwrap(), Token::Fragment);
+        assert_eq!(lex.next_token().unwrap(), Token::Fallback);
+    }
+
+    #[test]
+    fn test_scrub_block() {
+        let mut lex = Lexer::new("scrub { email phone ipv4 ipv6 card iban url regex");
+        assert_eq!(lex.next_token().unwrap(), Token::Scrub);
+        assert_eq!(lex.next_token().unwrap(), Token::LBrace);
+        assert_eq!(lex.next_token().unwrap(), Token::Email);
+        assert_eq!(lex.next_token().unwrap(), Token::Phone);
+        assert_eq!(lex.next_token().unwrap(), Token::Ipv4);
+        assert_eq!(lex.next_token().unwrap(), Token::Ipv6);
+        assert_eq!(lex.next_token().unwrap(), Token::Card);
+        assert_eq!(lex.next_token().unwrap(), Token::Iban);
+        assert_eq!(lex.next_token().unwrap(), Token::Url);
+        assert_eq!(lex.next_token().unwrap(), Token::Regex);
+    }
+
+    #[test]
+    fn test_regexreplace_keyword() {
+        let mut lex = Lexer::new("regexreplace 'a(b)' '${1}'");
+        assert_eq!(lex.next_token().unwrap(), Token::RegexReplace);
+        assert_eq!(lex.next_token().unwrap(), Token::Str("a(b)".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::Str("${1}".into()));
+    }
+
+    #[test]
+    fn test_iphash_keywords() {
+        let mut lex = Lexer::new("cardhash idhash uuidhash idmap iphash keepprivate");
+        assert_eq!(lex.next_token().unwrap(), Token::CardHash);
+        assert_eq!(lex.next_token().unwrap(), Token::IdHash);
+        assert_eq!(lex.next_token().unwrap(), Token::UuidHash);
+        assert_eq!(lex.next_token().unwrap(), Token::IdMap);
+        assert_eq!(lex.next_token().unwrap(), Token::IpHash);
+        assert_eq!(lex.next_token().unwrap(), Token::KeepPrivate);
+    }
+
+    #[test]
+    fn test_emailhash_options() {
+        let mut lex = Lexer::new("normalize striptags keepdomain 'example.org' hashdomain keepshape");
+        assert_eq!(lex.next_token().unwrap(), Token::Normalize);
+        assert_eq!(lex.next_token().unwrap(), Token::StripTags);
+        assert_eq!(lex.next_token().unwrap(), Token::KeepDomain);
+        assert_eq!(lex.next_token().unwrap(), Token::Str("example.org".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::HashDomain);
+        assert_eq!(lex.next_token().unwrap(), Token::KeepShape);
+    }
+
+    #[test]
+    fn test_mask_keywords() {
+        let mut lex = Lexer::new("mask first 1 last 4 '#' skip ' -'");
+        assert_eq!(lex.next_token().unwrap(), Token::Mask);
+        assert_eq!(lex.next_token().unwrap(), Token::First);
+        assert_eq!(lex.next_token().unwrap(), Token::Length(1));
+        assert_eq!(lex.next_token().unwrap(), Token::Last);
+        assert_eq!(lex.next_token().unwrap(), Token::Length(4));
+        assert_eq!(lex.next_token().unwrap(), Token::Str("#".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::Skip);
+        assert_eq!(lex.next_token().unwrap(), Token::Str(" -".into()));
+    }
+
+    #[test]
+    fn test_encrypt_keywords() {
+        let mut lex = Lexer::new("keys encrypt `billing` base64 hex width '64'");
+        assert_eq!(lex.next_token().unwrap(), Token::Keys);
+        assert_eq!(lex.next_token().unwrap(), Token::Encrypt);
+        assert_eq!(lex.next_token().unwrap(), Token::Ident("billing".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::Base64);
+        assert_eq!(lex.next_token().unwrap(), Token::Hex);
+        assert_eq!(lex.next_token().unwrap(), Token::Width);
+        assert_eq!(lex.next_token().unwrap(), Token::Str("64".into()));
+    }
+
+    #[test]
+    fn test_shuffle_keyword() {
+        let mut lex = Lexer::new("shuffle 'address'");
+        assert_eq!(lex.next_token().unwrap(), Token::Shuffle);
+        assert_eq!(lex.next_token().unwrap(), Token::Str("address".into()));
+    }
+
+    #[test]
+    fn test_synthesize_keywords() {
+        let mut lex = Lexer::new("synthesize categorical bins '50'");
+        assert_eq!(lex.next_token().unwrap(), Token::Synthesize);
+        assert_eq!(lex.next_token().unwrap(), Token::Categorical);
+        assert_eq!(lex.next_token().unwrap(), Token::Bins);
+        assert_eq!(lex.next_token().unwrap(), Token::Str("50".into()));
+    }
+
+    #[test]
+    fn test_noise_keywords() {
+        let mut lex = Lexer::new("noise '10%' bykey");
+        assert_eq!(lex.next_token().unwrap(), Token::Noise);
+        assert_eq!(lex.next_token().unwrap(), Token::Str("10%".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::ByKey);
+    }
+
+    #[test]
+    fn test_report_keywords() {
+        let mut lex = Lexer::new("report = { `t` = { `zip` = quasi `diagnosis` = sensitive } }");
+        assert_eq!(lex.next_token().unwrap(), Token::Report);
+        assert_eq!(lex.next_token().unwrap(), Token::Eq);
+        assert_eq!(lex.next_token().unwrap(), Token::LBrace);
+        assert_eq!(lex.next_token().unwrap(), Token::Ident("t".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::Eq);
+        assert_eq!(lex.next_token().unwrap(), Token::LBrace);
+        assert_eq!(lex.next_token().unwrap(), Token::Ident("zip".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::Eq);
+        assert_eq!(lex.next_token().unwrap(), Token::Quasi);
+        assert_eq!(lex.next_token().unwrap(), Token::Ident("diagnosis".into()));
+        assert_eq!(lex.next_token().unwrap(), Token::Eq);
+        assert_eq!(lex.next_token().unwrap(), Token::Sensitive);
+    }
+
+    #[test]
+    fn test_generalize_keywords() {
+        let mut lex = Lexer::new("generalize bucket round year quarter month prefix 3");
+        assert_eq!(lex.next_token().unwrap(), Token::Generalize);
+        assert_eq!(lex.next_token().unwrap(), Token::Bucket);
+        assert_eq!(lex.next_token().unwrap(), Token::Round);
+        assert_eq!(lex.next_token().unwrap(), Token::Year);
+        assert_eq!(lex.next_token().unwrap(), Token::Quarter);
+        assert_eq!(lex.next_token().unwrap(), Token::Month);
+        assert_eq!(lex.next_token().unwrap(), Token::Prefix);
+        assert_eq!(lex.next_token().unwrap(), Token::Length(3));
+    }
+
     #[test]
     fn test_separated_by() {
         let mut lex = Lexer::new("separated by ','");
@@ -1,14 +1,29 @@
 use crate::config::lexer::{Lexer, Token};
 use crate::config::types::*;
+use crate::encrypt::{Encoding, EncryptRule, SivKey};
+use crate::generalize::Generalization;
+use crate::idhash::IdKind;
+use crate::idmap::IdDomain;
+use crate::json::JsonPath;
+use crate::mask::MaskRule;
+use crate::noise::NoiseSpec;
+use crate::regexreplace::RegexReplace;
+use crate::scrub::Detector;
+use crate::synthesize::SynthesizeRule;
+use crate::url::UrlComponent;
+use crate::xml::XmlPath;
 
 pub struct Parser {
     lexer: Lexer,
+    /// Keys defined so far, for `encrypt` rules
+    keys: Vec<EncryptionKey>,
 }
 
 impl Parser {
     pub fn new(input: &str) -> Self {
         Parser {
             lexer: Lexer::new(input),
+            keys: Vec::new(),
         }
     }
 
@@ -70,11 +85,15 @@ impl Parser {
                 Token::Stats => self.parse_stats(&mut config)?,
                 Token::PyPath => self.parse_pypath(&mut config)?,
                 Token::PyScript => self.parse_pyscript(&mut config)?,
+                Token::JsonPreserve => self.parse_json_preserve(&mut config)?,
                 Token::Tables => self.parse_tables(&mut config)?,
+                Token::Report => self.parse_report(&mut config)?,
+                Token::Keys => self.parse_keys()?,
                 _ => return Err(self.error(&format!("unexpected token {}", tok))),
             }
         }
 
+        config.keys = self.keys.clone();
         Ok(config)
     }
 
@@ -95,6 +114,17 @@ impl Parser {
         Ok(())
     }
 
+    fn parse_json_preserve(&mut self, config: &mut Config) -> Result<(), String> {
+        self.expect_eq()?;
+        let tok = self.lexer.next_token()?;
+        match tok {
+            Token::Yes => config.json_preserve = true,
+            Token::No => config.json_preserve = false,
+            _ => return Err(self.error(&format!("expected 'yes' or 'no', got {}", tok))),
+        }
+        Ok(())
+    }
+
     fn parse_pypath(&mut self, config: &mut Config) -> Result<(), String> {
         self.expect_eq()?;
         config.pypath = self.expect_string()?;
@@ -143,6 +173,90 @@ impl Parser {
         Ok(())
     }
 
+    fn parse_keys(&mut self) -> Result<(), String> {
+        self.expect_eq()?;
+        self.expect_lbrace()?;
+
+        loop {
+            let tok = self.lexer.next_token()?;
+            let name = match tok {
+                Token::RBrace => break,
+                Token::Ident(name) => name,
+                _ => return Err(self.error(&format!("expected key name or '}}', got {}", tok))),
+            };
+            if self.keys.iter().any(|k| k.name == name) {
+                return Err(self.error(&format!("key {} is defined more than once", name)));
+            }
+            self.expect_eq()?;
+            let secret = self.expect_string()?;
+            if secret.is_empty() {
+                return Err(self.error(&format!("key {} is empty", name)));
+            }
+            self.keys.push(EncryptionKey {
+                name,
+                key: SivKey::derive(&secret),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn parse_report(&mut self, config: &mut Config) -> Result<(), String> {
+        self.expect_eq()?;
+        self.expect_lbrace()?;
+
+        loop {
+            let tok = self.lexer.next_token()?;
+            let name = match tok {
+                Token::RBrace => break,
+                Token::Ident(name) => name,
+                _ => return Err(self.error(&format!("expected table name or '}}', got {}", tok))),
+            };
+            if config.report.iter().any(|t| t.name == name) {
+                return Err(self.error(&format!("table {} is defined more than once in report", name)));
+            }
+            self.expect_eq()?;
+            self.expect_lbrace()?;
+
+            let mut table = ReportTable {
+                name,
+                quasi: Vec::new(),
+                sensitive: Vec::new(),
+            };
+            loop {
+                let tok = self.lexer.next_token()?;
+                let field = match tok {
+                    Token::RBrace => break,
+                    Token::Ident(field) => field,
+                    _ => return Err(self.error(&format!("expected field name or '}}', got {}", tok))),
+                };
+                if table.quasi.contains(&field) || table.sensitive.contains(&field) {
+                    return Err(self.error(&format!(
+                        "field {} in report table {} is defined more than once",
+                        field, table.name
+                    )));
+                }
+                self.expect_eq()?;
+                match self.lexer.next_token()? {
+                    Token::Quasi => table.quasi.push(field),
+                    Token::Sensitive => table.sensitive.push(field),
+                    tok => {
+                        return Err(self.error(&format!("expected quasi or sensitive, got {}", tok)))
+                    }
+                }
+            }
+            if table.quasi.is_empty() {
+                return Err(self.error(&format!(
+                    "report table {} has no quasi-identifier field",
+                    table.name
+                )));
+            }
+            config.report.push(table);
+        }
+
+        Ok(())
+    }
+
     fn check_duplicate_table(
         &self,
         config: &Config,
@@ -222,8 +336,84 @@ impl Parser {
         table_name: &str,
     ) -> Result<AnonField, String> {
         let tok = self.lexer.next_token()?;
-        let mut infos = AnonBase::default();
         let mut json_list = Vec::new();
+        let mut url_list = Vec::new();
+        let mut scrub_list = Vec::new();
+
+        let infos = match tok {
+            Token::Json => {
+                json_list = self.parse_path_block(field_name, table_name, PathBlock::Json)?;
+                AnonBase {
+                    anon_type: AnonType::Json,
+                    ..Default::default()
+                }
+            }
+            Token::PhpSerialized => {
+                json_list =
+                    self.parse_path_block(field_name, table_name, PathBlock::PhpSerialized)?;
+                AnonBase {
+                    anon_type: AnonType::PhpSerialized,
+                    ..Default::default()
+                }
+            }
+            Token::Xml => {
+                json_list = self.parse_path_block(field_name, table_name, PathBlock::Xml)?;
+                AnonBase {
+                    anon_type: AnonType::Xml,
+                    ..Default::default()
+                }
+            }
+            Token::Yaml => {
+                json_list = self.parse_path_block(field_name, table_name, PathBlock::Yaml)?;
+                AnonBase {
+                    anon_type: AnonType::Yaml,
+                    ..Default::default()
+                }
+            }
+            Token::Url => {
+                url_list = self.parse_url_block(field_name, table_name)?;
+                AnonBase {
+                    anon_type: AnonType::Url,
+                    ..Default::default()
+                }
+            }
+            Token::Scrub => {
+                scrub_list = self.parse_scrub_block(field_name, table_name)?;
+                AnonBase {
+                    anon_type: AnonType::Scrub,
+                    ..Default::default()
+                }
+            }
+            _ => self.parse_rule(tok)?,
+        };
+
+        Ok(AnonField {
+            name: field_name.to_string(),
+            pos: -1,
+            quoted: false,
+            numeric: None,
+            infos,
+            json: json_list,
+            url: url_list,
+            scrub: scrub_list,
+        })
+    }
+
+    /// Parse the rule of a part of a value: a document value, a URL
+    /// component or a scrubbed match. The `width` of `encrypt` is the width
+    /// of the column, which only whole values can be checked against.
+    fn parse_part_rule(&mut self, tok: Token) -> Result<AnonBase, String> {
+        let infos = self.parse_rule(tok)?;
+        if infos.encrypt.as_ref().is_some_and(|r| r.width.is_some()) {
+            return Err(self.error("width is only supported on encrypt rules of whole columns"));
+        }
+        Ok(infos)
+    }
+
+    /// Parse an anonymization rule starting with `tok`, including its optional
+    /// "separated by" clause. Shared by fields and JSON paths.
+    fn parse_rule(&mut self, tok: Token) -> Result<AnonBase, String> {
+        let mut infos = AnonBase::default();
 
         match tok {
             Token::FixedNull => {
@@ -253,6 +443,22 @@ impl Parser {
                 if infos.len + domain_len + 1 > MAX_LEN {
                     return Err(self.error("Requested length is too long"));
                 }
+                loop {
+                    match self.lexer.peek_token()? {
+                        Token::Normalize => infos.email.normalize = true,
+                        Token::StripTags => infos.email.strip_tags = true,
+                        Token::HashDomain => infos.email.hash_domain = true,
+                        Token::KeepShape => infos.email.keep_shape = true,
+                        Token::KeepDomain => {
+                            self.lexer.next_token()?;
+                            let domain = self.expect_string()?;
+                            infos.email.keep_domains.push(domain);
+                            continue;
+                        }
+                        _ => break,
+                    }
+                    self.lexer.next_token()?;
+                }
             }
             Token::IntHash => {
                 infos.anon_type = AnonType::IntHash;
@@ -262,6 +468,198 @@ impl Parser {
                 infos.anon_type = AnonType::Substring;
                 infos.len = self.expect_length()?;
             }
+            Token::CardHash => {
+                infos.anon_type = AnonType::CardHash;
+                // Number of leading digits kept (the issuer identification number)
+                infos.len = 6;
+                if let Token::Length(keep) = self.lexer.peek_token()? {
+                    self.lexer.next_token()?;
+                    infos.len = keep;
+                }
+            }
+            Token::IdHash => {
+                infos.anon_type = AnonType::IdHash;
+                let kind = self.expect_string()?;
+                infos.id_kind = Some(IdKind::parse(&kind).map_err(|e| self.error(&e))?);
+            }
+            Token::UuidHash => {
+                infos.anon_type = AnonType::UuidHash;
+            }
+            Token::IdMap => {
+                infos.anon_type = AnonType::IdMap;
+                if matches!(self.lexer.peek_token()?, Token::Str(_)) {
+                    let range = self.expect_string()?;
+                    infos.id_domain = Some(IdDomain::parse(&range).map_err(|e| self.error(&e))?);
+                }
+            }
+            Token::Mask => {
+                infos.anon_type = AnonType::Mask;
+                let mut rule = MaskRule::default();
+                loop {
+                    match self.lexer.peek_token()? {
+                        Token::First => {
+                            self.lexer.next_token()?;
+                            rule.first = self.expect_length()? as usize;
+                        }
+                        Token::Last => {
+                            self.lexer.next_token()?;
+                            rule.last = self.expect_length()? as usize;
+                        }
+                        Token::Skip => {
+                            self.lexer.next_token()?;
+                            let skip = self.expect_string()?;
+                            if skip.is_empty() {
+                                return Err(self.error("skip string is empty"));
+                            }
+                            rule.skip = skip.chars().collect();
+                        }
+                        Token::Str(_) => {
+                            let mask = self.expect_string()?;
+                            let mut chars = mask.chars();
+                            match (chars.next(), chars.next()) {
+                                (Some(c), None) => rule.mask = c,
+                                _ => return Err(self.error("mask must be a single character")),
+                            }
+                        }
+                        _ => break,
+                    }
+                }
+                infos.mask = Some(rule);
+            }
+            Token::Encrypt => {
+                infos.anon_type = AnonType::Encrypt;
+                let key_name = self.expect_ident()?;
+                let Some(key) = self.keys.iter().find(|k| k.name == key_name) else {
+                    return Err(self.error(&format!(
+                        "unknown key {}, keys must be defined before the tables using them",
+                        key_name
+                    )));
+                };
+                let mut rule = EncryptRule {
+                    key_name,
+                    key: key.key.clone(),
+                    encoding: Encoding::Base64,
+                    width: None,
+                    max_len: None,
+                    decrypt: false,
+                };
+                loop {
+                    match self.lexer.peek_token()? {
+                        Token::Base64 => rule.encoding = Encoding::Base64,
+                        Token::Hex => rule.encoding = Encoding::Hex,
+                        Token::Width => {
+                            self.lexer.next_token()?;
+                            let width = self.expect_string()?;
+                            match width.trim().parse::<usize>() {
+                                Ok(w) if w > 0 => rule.width = Some(w),
+                                _ => return Err(self.error(&format!("invalid width '{}'", width))),
+                            }
+                            continue;
+                        }
+                        _ => break,
+                    }
+                    self.lexer.next_token()?;
+                }
+                if let Some(width) = rule.width {
+                    rule.max_len = rule.encoding.max_plaintext_len(width);
+                    if rule.max_len.is_none() {
+                        return Err(self.error(&format!(
+                            "width {} is too small for encrypt, results have at least {} characters",
+                            width,
+                            rule.encoding.encoded_len(0)
+                        )));
+                    }
+                }
+                infos.encrypt = Some(rule);
+            }
+            Token::Shuffle => {
+                infos.anon_type = AnonType::Shuffle;
+                if matches!(self.lexer.peek_token()?, Token::Str(_)) {
+                    let group = self.expect_string()?;
+                    if group.is_empty() {
+                        return Err(self.error("shuffle group is empty"));
+                    }
+                    infos.shuffle_group = Some(group);
+                }
+            }
+            Token::Synthesize => {
+                infos.anon_type = AnonType::Synthesize;
+                let mut rule = SynthesizeRule::default();
+                loop {
+                    match self.lexer.peek_token()? {
+                        Token::Categorical => rule.categorical = true,
+                        Token::Bins => {
+                            self.lexer.next_token()?;
+                            let bins = self.expect_string()?;
+                            match bins.trim().parse::<usize>() {
+                                Ok(b) if (1..=10000).contains(&b) => rule.bins = b,
+                                _ => return Err(self.error(&format!("invalid number of bins '{}'", bins))),
+                            }
+                            continue;
+                        }
+                        _ => break,
+                    }
+                    self.lexer.next_token()?;
+                }
+                infos.synthesize = Some(rule);
+            }
+            Token::IpHash => {
+                infos.anon_type = AnonType::IpHash;
+                if self.lexer.peek_token()? == Token::KeepPrivate {
+                    self.lexer.next_token()?;
+                    infos.keep_private = true;
+                }
+            }
+            Token::Noise => {
+                infos.anon_type = AnonType::Noise;
+                let amount = self.expect_string()?;
+                let mut spec = NoiseSpec::parse(&amount).map_err(|e| self.error(&e))?;
+                if self.lexer.peek_token()? == Token::ByKey {
+                    self.lexer.next_token()?;
+                    spec.by_key = true;
+                }
+                infos.noise = Some(spec);
+            }
+            Token::Generalize => {
+                infos.anon_type = AnonType::Generalize;
+                let rule = match self.lexer.next_token()? {
+                    kind @ (Token::Bucket | Token::Round) => {
+                        let width = self.expect_string()?;
+                        Generalization::numeric(kind == Token::Round, &width)
+                            .map_err(|e| self.error(&e))?
+                    }
+                    Token::Year => Generalization::Year,
+                    Token::Quarter => Generalization::Quarter,
+                    Token::Month => Generalization::Month,
+                    Token::Prefix => {
+                        let len = self.expect_length()? as usize;
+                        let mut pad = None;
+                        if matches!(self.lexer.peek_token()?, Token::Str(_)) {
+                            let pad_str = self.expect_string()?;
+                            let mut chars = pad_str.chars();
+                            match (chars.next(), chars.next()) {
+                                (Some(c), None) => pad = Some(c),
+                                _ => return Err(self.error("prefix padding must be a single character")),
+                            }
+                        }
+                        Generalization::Prefix { len, pad }
+                    }
+                    tok => {
+                        return Err(self.error(&format!(
+                            "expected bucket, round, year, quarter, month or prefix, got {}",
+                            tok
+                        )))
+                    }
+                };
+                infos.generalize = Some(rule);
+            }
+            Token::RegexReplace => {
+                infos.anon_type = AnonType::RegexReplace;
+                let pattern = self.expect_string()?;
+                let template = self.expect_string()?;
+                let rule = RegexReplace::new(&pattern, &template).map_err(|e| self.error(&e))?;
+                infos.regex_replace = Some(rule);
+            }
             Token::Key => {
                 infos.anon_type = AnonType::Key;
             }
@@ -288,10 +686,6 @@ impl Parser {
                     infos.pyargs = self.expect_string()?;
                 }
             }
-            Token::Json => {
-                infos.anon_type = AnonType::Json;
-                json_list = self.parse_json_block(field_name, table_name)?;
-            }
             _ => {
                 return Err(self.error(&format!(
                     "expected anonymization type, got {}",
@@ -311,6 +705,16 @@ impl Parser {
                 | AnonType::EmailHash
                 | AnonType::IntHash
                 | AnonType::Substring
+                | AnonType::RegexReplace
+                | AnonType::IpHash
+                | AnonType::CardHash
+                | AnonType::IdHash
+                | AnonType::UuidHash
+                | AnonType::IdMap
+                | AnonType::Mask
+                | AnonType::Encrypt
+                | AnonType::Noise
+                | AnonType::Generalize
         ) {
             let peek = self.lexer.peek_token()?;
             if peek == Token::SeparatedBy {
@@ -326,19 +730,16 @@ impl Parser {
             }
         }
 
-        Ok(AnonField {
-            name: field_name.to_string(),
-            pos: -1,
-            quoted: false,
-            infos,
-            json: json_list,
-        })
+        Ok(infos)
     }
 
-    fn parse_json_block(
+    /// Parse a block of path rules: `json { ... }`, `phpserialized { ... }`,
+    /// `xml { ... }` or `yaml { ... }`.
+    fn parse_path_block(
         &mut self,
         field_name: &str,
         table_name: &str,
+        kind: PathBlock,
     ) -> Result<Vec<AnonJson>, String> {
         self.expect_lbrace()?;
         let mut json_entries = Vec::new();
@@ -351,35 +752,52 @@ impl Parser {
                     let raw_path = self.expect_string()?;
                     let line = self.lexer.line;
                     // Add leading dot if not present
-                    let filter = if !raw_path.starts_with('.') && !raw_path.starts_with('[') {
-                        format!(".{}", raw_path)
-                    } else {
+                    let filter = if kind == PathBlock::Xml
+                        || raw_path.starts_with('.')
+                        || raw_path.starts_with('[')
+                        || raw_path.starts_with('$')
+                    {
                         raw_path.clone()
+                    } else {
+                        format!(".{}", raw_path)
                     };
 
-                    // Validate JSON path
-                    if !is_valid_json_path(&filter) {
-                        eprintln!("Warning: Invalid json path '{}', ignoring it", filter);
-                        // Skip: = jsonaction
-                        self.expect_eq()?;
-                        self.skip_json_action()?;
-                        continue;
-                    }
+                    // Validate the path
+                    let parsed = if kind == PathBlock::Xml {
+                        XmlPath::parse(&filter).map(RulePath::Xml)
+                    } else {
+                        JsonPath::parse(&filter).map(RulePath::Json)
+                    };
+                    let path = match parsed {
+                        Ok(parsed) => parsed,
+                        Err(e) => {
+                            eprintln!(
+                                "Warning: Invalid {} path '{}' ({}), ignoring it",
+                                kind, filter, e
+                            );
+                            // Skip: = jsonaction
+                            self.expect_eq()?;
+                            self.parse_path_action(field_name, table_name, kind)?;
+                            continue;
+                        }
+                    };
 
                     // Check duplicate
                     if json_entries.iter().any(|j: &AnonJson| j.filter == filter) {
                         return Err(format!(
-                            "Error: JSON path '{}' in field {} of table {} is defined more than once in config file at line {}",
-                            filter, field_name, table_name, line
+                            "Error: {} path '{}' in field {} of table {} is defined more than once in config file at line {}",
+                            kind.path_label(), filter, field_name, table_name, line
                         ));
                     }
 
                     self.expect_eq()?;
-                    let json_infos = self.parse_json_action()?;
+                    let (json_infos, nested) = self.parse_path_action(field_name, table_name, kind)?;
 
                     json_entries.push(AnonJson {
                         filter,
+                        path,
                         infos: json_infos,
+                        json: nested,
                     });
                 }
                 _ => {
@@ -391,79 +809,199 @@ impl Parser {
         Ok(json_entries)
     }
 
-    fn parse_json_action(&mut self) -> Result<AnonBase, String> {
+    /// Parse the action of a path: any field rule, plus `remove`, `null`
+    /// and, in JSON, `json { ... }` for a string holding JSON itself. Also
+    /// returns the rules of the nested `json` block.
+    fn parse_path_action(
+        &mut self,
+        field_name: &str,
+        table_name: &str,
+        kind: PathBlock,
+    ) -> Result<(AnonBase, Vec<AnonJson>), String> {
         let tok = self.lexer.next_token()?;
-        let mut infos = AnonBase::default();
-
-        match tok {
-            Token::Fixed => {
-                infos.anon_type = AnonType::Fixed;
-                infos.fixed_value = self.expect_string()?;
-            }
-            Token::TextHash => {
-                infos.anon_type = AnonType::TextHash;
-                infos.len = self.expect_length()?;
-            }
-            Token::EmailHash => {
-                infos.anon_type = AnonType::EmailHash;
-                infos.domain = self.expect_string()?;
-                infos.len = self.expect_length()?;
-                let domain_len = infos.domain.len() as u16;
-                if infos.len + domain_len + 1 > MAX_LEN {
-                    return Err(self.error("Requested length is too long"));
-                }
-            }
-            Token::IntHash => {
-                infos.anon_type = AnonType::IntHash;
-                infos.len = self.expect_length()?;
+        let mut nested = Vec::new();
+        let infos = match tok {
+            Token::Remove => AnonBase {
+                anon_type: AnonType::Remove,
+                ..Default::default()
+            },
+            Token::Null | Token::FixedNull if kind == PathBlock::Xml => {
+                return Err(self.error(&format!("{} is not supported in an xml block", tok)))
             }
-            Token::PyDef => {
-                infos.anon_type = AnonType::Py;
-                infos.pydef = self.expect_string()?;
-                if matches!(self.lexer.peek_token()?, Token::Str(_)) {
-                    infos.pyargs = self.expect_string()?;
+            Token::Null => AnonBase {
+                anon_type: AnonType::FixedNull,
+                ..Default::default()
+            },
+            Token::Json if kind == PathBlock::Json => {
+                nested = self.parse_path_block(field_name, table_name, PathBlock::Json)?;
+                AnonBase {
+                    anon_type: AnonType::Json,
+                    ..Default::default()
                 }
             }
-            _ => {
+            Token::Key | Token::Shuffle | Token::Synthesize => {
                 return Err(self.error(&format!(
-                    "expected JSON anonymization type (fixed/texthash/emailhash/inthash/pydef), got {}",
-                    tok
+                    "{} is not supported on a {} path",
+                    tok,
+                    kind.path_label()
                 )))
             }
+            Token::Json => {
+                let article = if kind == PathBlock::Xml { "an" } else { "a" };
+                return Err(self.error(&format!(
+                    "{} is not supported in {} {} block",
+                    tok, article, kind
+                )));
+            }
+            _ => self.parse_part_rule(tok)?,
+        };
+        Ok((infos, nested))
+    }
+
+    /// Parse a `url { ... }` block: rules on query parameters, host,
+    /// userinfo, path segments and fragment, plus a `fallback` rule for
+    /// values that are not URLs.
+    fn parse_url_block(&mut self, field_name: &str, table_name: &str) -> Result<Vec<AnonUrl>, String> {
+        self.expect_lbrace()?;
+        let mut url_entries = Vec::new();
+
+        loop {
+            let tok = self.lexer.next_token()?;
+            let component = match tok {
+                Token::RBrace => break,
+                Token::Param => UrlComponent::Param(self.expect_string()?),
+                Token::Host => UrlComponent::Host,
+                Token::UserInfo => UrlComponent::UserInfo,
+                Token::Segment => UrlComponent::Segment(self.expect_length()? as usize),
+                Token::Fragment => UrlComponent::Fragment,
+                Token::Fallback => UrlComponent::Fallback,
+                _ => {
+                    return Err(self.error(&format!(
+                        "expected 'param', 'host', 'userinfo', 'segment', 'fragment', 'fallback' or '}}', got {}",
+                        tok
+                    )))
+                }
+            };
+            let line = self.lexer.line;
+            if url_entries.iter().any(|u: &AnonUrl| u.component == component) {
+                return Err(format!(
+                    "Error: URL {} in field {} of table {} is defined more than once in config file at line {}",
+                    component, field_name, table_name, line
+                ));
+            }
+            self.expect_eq()?;
+
+            let tok = self.lexer.next_token()?;
+            let infos = match tok {
+                // Values move between rows, or are drawn from the column, as a whole
+                Token::Shuffle | Token::Synthesize => {
+                    return Err(self.error(&format!("{} is not supported on URL {}", tok, component)))
+                }
+                // The fallback is a plain field rule on the whole value
+                _ if component == UrlComponent::Fallback => self.parse_rule(tok)?,
+                Token::Remove if component != UrlComponent::Host => AnonBase {
+                    anon_type: AnonType::Remove,
+                    ..Default::default()
+                },
+                Token::Key | Token::FixedNull | Token::Remove => {
+                    return Err(self.error(&format!("{} is not supported on URL {}", tok, component)))
+                }
+                _ => self.parse_part_rule(tok)?,
+            };
+            url_entries.push(AnonUrl { component, infos });
         }
 
-        Ok(infos)
+        Ok(url_entries)
     }
 
-    /// Skip a JSON action (used when the path is invalid and we want to skip it)
-    fn skip_json_action(&mut self) -> Result<(), String> {
-        let tok = self.lexer.next_token()?;
-        match tok {
-            Token::Fixed => {
-                self.expect_string()?;
-            }
-            Token::TextHash => {
-                self.expect_length()?;
-            }
-            Token::EmailHash => {
-                self.expect_string()?;
-                self.expect_length()?;
-            }
-            Token::IntHash => {
-                self.expect_length()?;
-            }
-            Token::PyDef => {
-                self.expect_string()?;
+    /// Parse a `scrub { ... }` block: the rule replacing each match of a
+    /// built-in detector or of a `regex '...'`.
+    fn parse_scrub_block(&mut self, field_name: &str, table_name: &str) -> Result<Vec<AnonScrub>, String> {
+        self.expect_lbrace()?;
+        let mut scrub_entries = Vec::new();
+
+        loop {
+            let tok = self.lexer.next_token()?;
+            let detector = match tok {
+                Token::RBrace => break,
+                Token::Email => Detector::Email,
+                Token::Phone => Detector::Phone,
+                Token::Ipv4 => Detector::Ipv4,
+                Token::Ipv6 => Detector::Ipv6,
+                Token::Card => Detector::Card,
+                Token::Iban => Detector::Iban,
+                Token::Url => Detector::Url,
+                Token::Regex => {
+                    let pattern = self.expect_string()?;
+                    let compiled = regex::Regex::new(&pattern)
+                        .map_err(|e| self.error(&format!("Unable to compile regex '{}': {}", pattern, e)))?;
+                    Detector::Regex(compiled)
+                }
+                _ => {
+                    return Err(self.error(&format!(
+                        "expected 'email', 'phone', 'ipv4', 'ipv6', 'card', 'iban', 'url', 'regex' or '}}', got {}",
+                        tok
+                    )))
+                }
+            };
+            let line = self.lexer.line;
+            if scrub_entries.iter().any(|s: &AnonScrub| s.detector == detector) {
+                return Err(format!(
+                    "Error: scrub {} in field {} of table {} is defined more than once in config file at line {}",
+                    detector, field_name, table_name, line
+                ));
             }
-            _ => return Err(self.error(&format!("expected JSON anonymization type, got {}", tok))),
+            self.expect_eq()?;
+
+            let tok = self.lexer.next_token()?;
+            let infos = match tok {
+                Token::Key | Token::FixedNull | Token::Shuffle | Token::Synthesize => {
+                    return Err(self.error(&format!("{} is not supported on scrub {}", tok, detector)))
+                }
+                _ => self.parse_part_rule(tok)?,
+            };
+            scrub_entries.push(AnonScrub { detector, infos });
+        }
+
+        Ok(scrub_entries)
+    }
+}
+
+/// Kind of a block of path rules
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PathBlock {
+    Json,
+    PhpSerialized,
+    Xml,
+    Yaml,
+}
+
+impl PathBlock {
+    /// Name of the path syntax, for messages
+    fn path_label(&self) -> &'static str {
+        match self {
+            PathBlock::Json | PathBlock::PhpSerialized => "JSON",
+            PathBlock::Xml => "XML",
+            PathBlock::Yaml => "YAML",
+        }
+    }
+}
+
+impl std::fmt::Display for PathBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PathBlock::Json => write!(f, "json"),
+            PathBlock::PhpSerialized => write!(f, "phpserialized"),
+            PathBlock::Xml => write!(f, "xml"),
+            PathBlock::Yaml => write!(f, "yaml"),
         }
-        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::emailhash::EmailOptions;
 
     #[test]
     fn test_minimal_config() {
@@ -494,6 +1032,18 @@ mod tests {
         assert!(!config.stats);
     }
 
+    #[test]
+    fn test_json_preserve() {
+        let mut parser = Parser::new("jsonpreserve = 'yes' tables = { `t` = truncate }");
+        assert!(parser.parse().unwrap().json_preserve);
+        let mut parser = Parser::new("tables = { `t` = truncate }");
+        assert!(!parser.parse().unwrap().json_preserve);
+        let mut parser = Parser::new("jsonpreserve = 'no' tables = { `t` = truncate }");
+        assert!(!parser.parse().unwrap().json_preserve);
+        let mut parser = Parser::new("jsonpreserve = 'maybe'");
+        assert!(parser.parse().is_err());
+    }
+
     #[test]
     fn test_field_types() {
         let input = r#"
@@ -556,61 +1106,458 @@ mod tests {
     }
 
     #[test]
-    fn test_json_field() {
+    fn test_regexreplace() {
         let input = r#"
-            secret = 'test'
             tables = {
                 `t` = {
-                    `data` = json {
-                        path 'name' = texthash 5
-                        path 'email' = emailhash 'example.com' 10
-                    }
+                    `ref` = regexreplace '^CUST-(\d+)-(\d+)-(\w+)$' 'CUST-${1}-${hash:2:5}-${3}'
+                    `tags` = regexreplace '(\d+)' '${inthash:1}' separated by ','
                 }
             }
         "#;
-        let mut parser = Parser::new(input);
-        let config = parser.parse().unwrap();
-        let field = &config.tables[0].fields[0];
-        assert_eq!(field.infos.anon_type, AnonType::Json);
-        assert_eq!(field.json.len(), 2);
-        assert_eq!(field.json[0].filter, ".name");
-        assert_eq!(field.json[1].filter, ".email");
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::RegexReplace);
+        assert!(fields[0].infos.regex_replace.is_some());
+        assert_eq!(fields[1].infos.separator, Some(','));
+
+        let err = Parser::new(&input.replace("(\\d+)-(\\w+)$", "(\\d+-(\\w+)$"))
+            .parse()
+            .unwrap_err();
+        assert!(
+            err.starts_with("Config parsing error at line 4: Unable to compile regex"),
+            "{}",
+            err
+        );
+        let err = Parser::new(&input.replace("${3}", "${4}")).parse().unwrap_err();
+        assert!(
+            err.contains("line 4: template references a group not defined"),
+            "{}",
+            err
+        );
     }
 
     #[test]
-    fn test_regex_table() {
+    fn test_cardhash() {
         let input = r#"
-            secret = 'test'
             tables = {
-                regex `test_.*` = {
-                    `data` = texthash 10
+                `t` = {
+                    `card` = cardhash
+                    `pan` = cardhash 8
                 }
             }
         "#;
-        let mut parser = Parser::new(input);
-        let config = parser.parse().unwrap();
-        assert!(config.tables[0].regex.is_some());
-        assert_eq!(config.tables[0].name, "test_.*");
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::CardHash);
+        assert_eq!(fields[0].infos.len, 6);
+        assert_eq!(fields[1].infos.len, 8);
     }
 
     #[test]
-    fn test_duplicate_table_error() {
+    fn test_idhash() {
         let input = r#"
             tables = {
-                `t1` = truncate
-                `t1` = truncate
+                `t` = {
+                    `ssn` = idhash 'ssn'
+                    `nir` = idhash 'nir'
+                }
             }
         "#;
-        let mut parser = Parser::new(input);
-        let err = parser.parse().unwrap_err();
-        assert!(err.contains("defined more than once"));
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::IdHash);
+        assert_eq!(fields[0].infos.id_kind, Some(IdKind::Ssn));
+        assert_eq!(fields[1].infos.id_kind, Some(IdKind::Nir));
+
+        let err = Parser::new(&input.replace("'nir'", "'siret'")).parse().unwrap_err();
+        assert!(err.contains("line 5: unknown idhash kind 'siret'"), "{}", err);
     }
 
     #[test]
-    fn test_duplicate_field_error() {
+    fn test_emailhash_options() {
         let input = r#"
             tables = {
-                `t1` = {
+                `t` = {
+                    `email` = emailhash 'example.com' 10
+                    `login` = emailhash 'example.com' 10 normalize striptags keepdomain 'example.org' keepdomain 'corp.example' hashdomain keepshape separated by ','
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.email, EmailOptions::default());
+        assert_eq!(
+            fields[1].infos.email,
+            EmailOptions {
+                normalize: true,
+                strip_tags: true,
+                keep_domains: vec!["example.org".to_string(), "corp.example".to_string()],
+                hash_domain: true,
+                keep_shape: true,
+            }
+        );
+        assert_eq!(fields[1].infos.separator, Some(','));
+
+        let err = Parser::new(&input.replace("keepdomain 'example.org'", "keepdomain")).parse().unwrap_err();
+        assert!(err.contains("line 5"), "{}", err);
+    }
+
+    #[test]
+    fn test_uuidhash() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `uuid` = uuidhash
+                    `uuids` = uuidhash separated by ','
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::UuidHash);
+        assert_eq!(fields[1].infos.separator, Some(','));
+    }
+
+    #[test]
+    fn test_idmap() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `id` = idmap
+                    `user_id` = idmap '1-4294967295'
+                    `ids` = idmap '1-999' separated by ','
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::IdMap);
+        assert_eq!(fields[0].infos.id_domain, None);
+        assert_eq!(fields[1].infos.id_domain, Some(IdDomain { min: 1, max: 4294967295 }));
+        assert_eq!(fields[2].infos.separator, Some(','));
+
+        let err = Parser::new(&input.replace("'1-999'", "'999-1'")).parse().unwrap_err();
+        assert!(err.contains("line 6: invalid idmap range '999-1'"), "{}", err);
+    }
+
+    #[test]
+    fn test_mask() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `card` = mask last 4 skip '- '
+                    `email` = mask first 1 '#'
+                    `code` = mask
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::Mask);
+        assert_eq!(
+            fields[0].infos.mask,
+            Some(MaskRule {
+                first: 0,
+                last: 4,
+                mask: '*',
+                skip: vec!['-', ' '],
+            })
+        );
+        assert_eq!(
+            fields[1].infos.mask,
+            Some(MaskRule {
+                first: 1,
+                mask: '#',
+                ..Default::default()
+            })
+        );
+        assert_eq!(fields[2].infos.mask, Some(MaskRule::default()));
+
+        let err = Parser::new(&input.replace("'#'", "'##'")).parse().unwrap_err();
+        assert!(err.contains("line 5: mask must be a single character"), "{}", err);
+        let err = Parser::new(&input.replace("last 4", "last")).parse().unwrap_err();
+        assert!(err.contains("line 4: expected length, got skip"), "{}", err);
+    }
+
+    #[test]
+    fn test_encrypt() {
+        let input = r#"
+            keys = {
+                `billing` = 'billing passphrase'
+                `hr` = 'hr passphrase'
+            }
+            tables = {
+                `t` = {
+                    `iban` = encrypt `billing`
+                    `salary` = encrypt `hr` hex width '128'
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        assert_eq!(config.keys.len(), 2);
+        assert_eq!(config.keys[1].key, SivKey::derive("hr passphrase"));
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::Encrypt);
+        let rule = fields[0].infos.encrypt.as_ref().unwrap();
+        assert_eq!((rule.key_name.as_str(), rule.encoding, rule.width), ("billing", Encoding::Base64, None));
+        assert_eq!(rule.key, SivKey::derive("billing passphrase"));
+        let rule = fields[1].infos.encrypt.as_ref().unwrap();
+        assert_eq!((rule.key_name.as_str(), rule.encoding, rule.width), ("hr", Encoding::Hex, Some(128)));
+        assert_eq!(rule.max_len, Some(48));
+        assert!(!rule.decrypt);
+
+        let err = Parser::new(&input.replace("encrypt `hr`", "encrypt `payroll`")).parse().unwrap_err();
+        assert!(err.contains("line 9: unknown key payroll"), "{}", err);
+        let err = Parser::new(&input.replace("`hr` =", "`billing` =")).parse().unwrap_err();
+        assert!(err.contains("line 4: key billing is defined more than once"), "{}", err);
+        let err = Parser::new(&input.replace("'128'", "'0'")).parse().unwrap_err();
+        assert!(err.contains("line 9: invalid width '0'"), "{}", err);
+        let err = Parser::new(&input.replace("'128'", "'31'")).parse().unwrap_err();
+        assert!(err.contains("line 9: width 31 is too small for encrypt, results have at least 32 characters"), "{}", err);
+        let nested = input.replace("encrypt `hr` hex width '128'", "json { path 'a' = encrypt `hr` width '64' }");
+        let err = Parser::new(&nested).parse().unwrap_err();
+        assert!(err.contains("line 9: width is only supported on encrypt rules of whole columns"), "{}", err);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let input = r#"
+            tables = {
+                `people` = {
+                    `job` = shuffle
+                    `city` = shuffle 'address'
+                    `zip` = shuffle 'address'
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::Shuffle);
+        assert_eq!(fields[0].infos.shuffle_group, None);
+        assert_eq!(fields[1].infos.shuffle_group.as_deref(), Some("address"));
+        assert_eq!(fields[2].infos.shuffle_group.as_deref(), Some("address"));
+
+        let err = Parser::new(&input.replace("'address'\n                    `zip`", "''\n                    `zip`"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("line 5: shuffle group is empty"), "{}", err);
+        let err = Parser::new(&input.replace("`job` = shuffle", "`job` = json { path 'a' = shuffle }"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("shuffle is not supported on a JSON path"), "{}", err);
+        let err = Parser::new(&input.replace("`job` = shuffle", "`job` = url { fallback = shuffle }"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("shuffle is not supported on URL fallback"), "{}", err);
+        let err = Parser::new(&input.replace("`job` = shuffle", "`job` = scrub { email = shuffle }"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("shuffle is not supported on scrub email"), "{}", err);
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let input = r#"
+            tables = {
+                `users` = {
+                    `status` = synthesize
+                    `age` = synthesize bins '10'
+                    `zip` = synthesize categorical
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::Synthesize);
+        assert_eq!(fields[0].infos.synthesize, Some(SynthesizeRule::default()));
+        let rule = fields[1].infos.synthesize.as_ref().unwrap();
+        assert_eq!((rule.categorical, rule.bins), (false, 10));
+        let rule = fields[2].infos.synthesize.as_ref().unwrap();
+        assert!(rule.categorical);
+        assert!(rule.profile.is_none());
+
+        let err = Parser::new(&input.replace("'10'", "'0'")).parse().unwrap_err();
+        assert!(err.contains("line 5: invalid number of bins '0'"), "{}", err);
+        let err = Parser::new(&input.replace("`zip` = synthesize categorical", "`zip` = json { path 'a' = synthesize }"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("synthesize is not supported on a JSON path"), "{}", err);
+    }
+
+    #[test]
+    fn test_iphash() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `ip` = iphash
+                    `last_ip` = iphash keepprivate
+                    `ips` = iphash keepprivate separated by ','
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::IpHash);
+        assert!(!fields[0].infos.keep_private);
+        assert!(fields[1].infos.keep_private);
+        assert!(fields[2].infos.keep_private);
+        assert_eq!(fields[2].infos.separator, Some(','));
+    }
+
+    #[test]
+    fn test_noise() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `salary` = noise '10%'
+                    `balance` = noise '500' bykey
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::Noise);
+        let noise = fields[0].infos.noise.as_ref().unwrap();
+        assert!(noise.relative && !noise.by_key);
+        assert_eq!(noise.amount, 10.0);
+        let noise = fields[1].infos.noise.as_ref().unwrap();
+        assert!(!noise.relative && noise.by_key);
+
+        let err = Parser::new(&input.replace("'500'", "'-5'")).parse().unwrap_err();
+        assert!(err.contains("line 5: invalid noise amount '-5'"), "{}", err);
+    }
+
+    #[test]
+    fn test_report() {
+        let input = r#"
+            tables = {
+                `patients` = {
+                    `zip` = generalize prefix 2 '0'
+                }
+            }
+            report = {
+                `patients` = {
+                    `zip` = quasi
+                    `birth_year` = quasi
+                    `diagnosis` = sensitive
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        assert_eq!(
+            config.report,
+            [ReportTable {
+                name: "patients".to_string(),
+                quasi: vec!["zip".to_string(), "birth_year".to_string()],
+                sensitive: vec!["diagnosis".to_string()],
+            }]
+        );
+
+        let err = Parser::new(&input.replace("`birth_year` = quasi", "`zip` = sensitive"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("line 10: field zip in report table patients is defined more than once"), "{}", err);
+        let err = Parser::new(&input.replace("= quasi", "= sensitive")).parse().unwrap_err();
+        assert!(err.contains("report table patients has no quasi-identifier field"), "{}", err);
+    }
+
+    #[test]
+    fn test_generalize() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `age` = generalize bucket '10'
+                    `salary` = generalize round '5000'
+                    `birth_date` = generalize year
+                    `zip` = generalize prefix 2 '0'
+                    `city` = generalize prefix 3
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let fields = &config.tables[0].fields;
+        assert_eq!(fields[0].infos.anon_type, AnonType::Generalize);
+        assert_eq!(
+            fields[0].infos.generalize,
+            Some(Generalization::Bucket { size: 10, scale: 0 })
+        );
+        assert_eq!(
+            fields[1].infos.generalize,
+            Some(Generalization::Round { step: 5000, scale: 0 })
+        );
+        assert_eq!(fields[2].infos.generalize, Some(Generalization::Year));
+        assert_eq!(
+            fields[3].infos.generalize,
+            Some(Generalization::Prefix { len: 2, pad: Some('0') })
+        );
+        assert_eq!(
+            fields[4].infos.generalize,
+            Some(Generalization::Prefix { len: 3, pad: None })
+        );
+
+        let err = Parser::new(&input.replace("'0'", "'00'")).parse().unwrap_err();
+        assert!(err.contains("line 7: prefix padding must be a single character"), "{}", err);
+        let err = Parser::new(&input.replace("year", "'year'")).parse().unwrap_err();
+        assert!(err.contains("line 6: expected bucket, round"), "{}", err);
+    }
+
+    #[test]
+    fn test_json_field() {
+        let input = r#"
+            secret = 'test'
+            tables = {
+                `t` = {
+                    `data` = json {
+                        path 'name' = texthash 5
+                        path 'email' = emailhash 'example.com' 10
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let field = &config.tables[0].fields[0];
+        assert_eq!(field.infos.anon_type, AnonType::Json);
+        assert_eq!(field.json.len(), 2);
+        assert_eq!(field.json[0].filter, ".name");
+        assert_eq!(field.json[1].filter, ".email");
+    }
+
+    #[test]
+    fn test_regex_table() {
+        let input = r#"
+            secret = 'test'
+            tables = {
+                regex `test_.*` = {
+                    `data` = texthash 10
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        assert!(config.tables[0].regex.is_some());
+        assert_eq!(config.tables[0].name, "test_.*");
+    }
+
+    #[test]
+    fn test_duplicate_table_error() {
+        let input = r#"
+            tables = {
+                `t1` = truncate
+                `t1` = truncate
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let err = parser.parse().unwrap_err();
+        assert!(err.contains("defined more than once"));
+    }
+
+    #[test]
+    fn test_duplicate_field_error() {
+        let input = r#"
+            tables = {
+                `t1` = {
                     `f1` = key
                     `f1` = key
                 }
@@ -704,4 +1651,298 @@ mod tests {
         assert_eq!(config.tables[0].fields[0].json[1].filter, ".nested[][]");
         assert_eq!(config.tables[0].fields[0].json[2].filter, "[]");
     }
+
+    #[test]
+    fn test_json_path_extended_syntax() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = json {
+                        path '["first-name"]' = texthash 5
+                        path '..email' = emailhash 'example.com' 10
+                        path '$.settings.*' = fixed 'x'
+                        path 'items[0]' = texthash 5
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let json = &config.tables[0].fields[0].json;
+        assert_eq!(json.len(), 4);
+        assert_eq!(json[0].filter, "[\"first-name\"]");
+        assert_eq!(json[1].filter, "..email");
+        assert_eq!(json[2].filter, "$.settings.*");
+        assert_eq!(json[3].filter, ".items[0]");
+    }
+
+    #[test]
+    fn test_json_full_rule_grammar() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = json {
+                        path 'token' = remove
+                        path 'reset' = null
+                        path 'name' = substring 3
+                        path 'ref' = appendindex 'ref-'
+                        path 'tags' = texthash 4 separated by ','
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let json = &config.tables[0].fields[0].json;
+        assert_eq!(json[0].infos.anon_type, AnonType::Remove);
+        assert_eq!(json[1].infos.anon_type, AnonType::FixedNull);
+        assert_eq!(json[2].infos.anon_type, AnonType::Substring);
+        assert_eq!(json[2].infos.len, 3);
+        assert_eq!(json[3].infos.anon_type, AnonType::AppendIndex);
+        assert_eq!(json[3].infos.fixed_value, "ref-");
+        assert_eq!(json[4].infos.separator, Some(','));
+    }
+
+    #[test]
+    fn test_json_nested_block() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = json {
+                        path 'payload' = json {
+                            path 'email' = emailhash 'example.com' 10
+                            path 'inner' = json { path 'name' = texthash 5 }
+                        }
+                        path 'id' = inthash 4
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let json = &config.tables[0].fields[0].json;
+        assert_eq!(json.len(), 2);
+        assert_eq!(json[0].infos.anon_type, AnonType::Json);
+        assert_eq!(json[0].json.len(), 2);
+        assert_eq!(json[0].json[0].filter, ".email");
+        assert_eq!(json[0].json[1].json[0].filter, ".name");
+        assert!(json[1].json.is_empty());
+    }
+
+    #[test]
+    fn test_phpserialized_block() {
+        let input = r#"
+            tables = {
+                `wp_usermeta` = {
+                    `meta_value` = phpserialized {
+                        path '..email' = emailhash 'example.com' 10
+                        path 'session_tokens' = remove
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let field = &config.tables[0].fields[0];
+        assert_eq!(field.infos.anon_type, AnonType::PhpSerialized);
+        assert_eq!(field.json.len(), 2);
+        assert_eq!(field.json[0].filter, "..email");
+        assert_eq!(field.json[1].infos.anon_type, AnonType::Remove);
+
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = phpserialized {
+                        path 'data' = json { path 'name' = texthash 5 }
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let err = parser.parse().unwrap_err();
+        assert!(err.contains("json is not supported in a phpserialized block"), "{}", err);
+    }
+
+    #[test]
+    fn test_xml_block() {
+        let input = r#"
+            tables = {
+                `profiles` = {
+                    `body` = xml {
+                        path '/user/email' = emailhash 'example.com' 10
+                        path '//phone' = fixed '000'
+                        path '/user/@token' = remove
+                        path 'user/name' = texthash 5
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let field = &config.tables[0].fields[0];
+        assert_eq!(field.infos.anon_type, AnonType::Xml);
+        // The relative path is ignored with a warning
+        assert_eq!(field.json.len(), 3);
+        assert_eq!(field.json[0].filter, "/user/email");
+        assert!(matches!(field.json[0].path, RulePath::Xml(_)));
+        assert_eq!(field.json[2].infos.anon_type, AnonType::Remove);
+
+        for action in ["null", "fixed null", "json { path 'a' = remove }"] {
+            let input = format!(
+                "tables = {{ `t` = {{ `f` = xml {{ path '/a' = {} }} }} }}",
+                action
+            );
+            let err = Parser::new(&input).parse().unwrap_err();
+            assert!(err.contains("is not supported in an xml block"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_yaml_block() {
+        let input = r#"
+            tables = {
+                `audit_events` = {
+                    `details` = yaml {
+                        path '..author_name' = texthash 8
+                        path 'ip_address' = null
+                        path 'target_details' = json { path 'a' = remove }
+                    }
+                }
+            }
+        "#;
+        let err = Parser::new(input).parse().unwrap_err();
+        assert!(err.contains("json is not supported in a yaml block"), "{}", err);
+
+        let input = input.replace("path 'target_details' = json { path 'a' = remove }", "");
+        let config = Parser::new(&input).parse().unwrap();
+        let field = &config.tables[0].fields[0];
+        assert_eq!(field.infos.anon_type, AnonType::Yaml);
+        assert_eq!(field.json[0].filter, "..author_name");
+        assert_eq!(field.json[1].filter, ".ip_address");
+        assert_eq!(field.json[1].infos.anon_type, AnonType::FixedNull);
+    }
+
+    #[test]
+    fn test_url_block() {
+        let input = r#"
+            tables = {
+                `oauth_clients` = {
+                    `redirect_uri` = url {
+                        param 'email' = emailhash 'example.com' 8
+                        param 'token' = fixed ''
+                        host = fixed 'example.com'
+                        userinfo = remove
+                        segment 2 = inthash 6
+                        fallback = texthash 10
+                    }
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let field = &config.tables[0].fields[0];
+        assert_eq!(field.infos.anon_type, AnonType::Url);
+        assert_eq!(field.url.len(), 6);
+        assert_eq!(field.url[0].component, UrlComponent::Param("email".into()));
+        assert_eq!(field.url[0].infos.anon_type, AnonType::EmailHash);
+        assert_eq!(field.url[3].infos.anon_type, AnonType::Remove);
+        assert_eq!(field.url[4].component, UrlComponent::Segment(2));
+        assert_eq!(field.url[5].component, UrlComponent::Fallback);
+
+        let err = Parser::new(&input.replace("userinfo = remove", "host = remove"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("URL host in field redirect_uri"), "{}", err);
+        let err = Parser::new(&input.replace("host = fixed 'example.com'", "host = remove"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("remove is not supported on URL host"), "{}", err);
+        let err = Parser::new(&input.replace("segment 2 = inthash 6", "segment 2 = key"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("key is not supported on URL segment 2"), "{}", err);
+    }
+
+    #[test]
+    fn test_scrub_block() {
+        let input = r#"
+            tables = {
+                `comments` = {
+                    `body` = scrub {
+                        email = emailhash 'example.com' 10
+                        phone = inthash 10
+                        card = fixed '4111111111111111'
+                        regex 'EMP-[0-9]{6}' = texthash 6
+                    }
+                }
+            }
+        "#;
+        let config = Parser::new(input).parse().unwrap();
+        let field = &config.tables[0].fields[0];
+        assert_eq!(field.infos.anon_type, AnonType::Scrub);
+        assert_eq!(field.scrub.len(), 4);
+        assert_eq!(field.scrub[0].detector, Detector::Email);
+        assert_eq!(field.scrub[0].infos.anon_type, AnonType::EmailHash);
+        assert_eq!(field.scrub[3].detector.to_string(), "regex 'EMP-[0-9]{6}'");
+
+        let err = Parser::new(&input.replace("phone = inthash 10", "email = inthash 10"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("scrub email in field body"), "{}", err);
+        let err = Parser::new(&input.replace("phone = inthash 10", "phone = key"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("key is not supported on scrub phone"), "{}", err);
+        let err = Parser::new(&input.replace("EMP-[0-9]{6}", "EMP-[0-9"))
+            .parse()
+            .unwrap_err();
+        assert!(err.contains("Unable to compile regex"), "{}", err);
+    }
+
+    #[test]
+    fn test_json_key_rule_rejected() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = json {
+                        path 'id' = key
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let err = parser.parse().unwrap_err();
+        assert!(err.contains("key is not supported on a JSON path"), "{}", err);
+    }
+
+    #[test]
+    fn test_remove_not_a_field_rule() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = remove
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn test_json_fixed_null() {
+        let input = r#"
+            tables = {
+                `t` = {
+                    `f` = json {
+                        path 'token' = fixed null
+                        path 'id' = inthash 8
+                    }
+                }
+            }
+        "#;
+        let mut parser = Parser::new(input);
+        let config = parser.parse().unwrap();
+        let json = &config.tables[0].fields[0].json;
+        assert_eq!(json[0].infos.anon_type, AnonType::FixedNull);
+        assert_eq!(json[1].infos.anon_type, AnonType::IntHash);
+    }
 }
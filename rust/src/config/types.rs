@@ -1,5 +1,20 @@
 use regex::Regex;
 
+use crate::charset::Charset;
+use crate::emailhash::EmailOptions;
+use crate::encrypt::{EncryptRule, SivKey};
+use crate::synthesize::SynthesizeRule;
+use crate::generalize::Generalization;
+use crate::idhash::IdKind;
+use crate::idmap::IdDomain;
+use crate::json::JsonPath;
+use crate::mask::MaskRule;
+use crate::noise::{NoiseSpec, NumericType};
+use crate::regexreplace::RegexReplace;
+use crate::scrub::Detector;
+use crate::url::UrlComponent;
+use crate::xml::XmlPath;
+
 /// Maximum anonymization output length (matches C MAX_LEN)
 pub const MAX_LEN: u16 = 32;
 
@@ -19,8 +34,45 @@ pub enum AnonType {
     AppendIndex,
     PrependIndex,
     Substring,
+    /// Card number keeping its first `len` digits, with a valid Luhn check digit
+    CardHash,
+    /// National identifier with a valid structure and checksum
+    IdHash,
+    /// Number moved by a deterministic amount within a range (`noise`)
+    Noise,
+    /// Value coarsened: bucketed, rounded, truncated date or prefix
+    Generalize,
+    /// Version 4 UUID, written in the form of the original
+    UuidHash,
+    /// Integer replaced by its image through a keyed permutation of its domain
+    IdMap,
+    /// Characters replaced by a mask character, the first and last ones kept
+    Mask,
+    /// Deterministic AES-SIV encryption, reversible with the key
+    Encrypt,
+    /// Value moved to another row of the table, the multiset of values kept
+    Shuffle,
+    /// Value drawn from the distribution of the column, profiled beforehand
+    Synthesize,
+    /// Prefix-preserving IP address pseudonymization (Crypto-PAn)
+    IpHash,
+    /// Matches of a regex replaced by a template (`regexreplace`)
+    RegexReplace,
     Json,
+    /// PHP `serialize()` data, anonymized with path rules like JSON
+    PhpSerialized,
+    /// XML document, anonymized with XML path rules
+    Xml,
+    /// YAML document, anonymized with JSON path rules
+    Yaml,
+    /// URL, anonymized with rules on its components
+    Url,
+    /// Free text, with personal data found by detectors replaced
+    Scrub,
     Py,
+    /// Path and URL rules only: drop the matched member, element, XML node
+    /// or URL component
+    Remove,
 }
 
 /// Table action — matches C table_action_st enum
@@ -36,10 +88,31 @@ pub struct AnonBase {
     pub anon_type: AnonType,
     pub len: u16,
     pub domain: String,
+    /// Options of `emailhash`
+    pub email: EmailOptions,
     pub separator: Option<char>,
     pub fixed_value: String,
     pub pydef: String,
     pub pyargs: String,
+    pub regex_replace: Option<RegexReplace>,
+    /// `iphash keepprivate`: private and reserved addresses are kept
+    pub keep_private: bool,
+    /// Kind of identifier generated by `idhash`
+    pub id_kind: Option<IdKind>,
+    /// Domain of an `idmap` rule, the column type range when not given
+    pub id_domain: Option<IdDomain>,
+    /// Kept characters and mask character of a `mask` rule
+    pub mask: Option<MaskRule>,
+    /// Key and output form of an `encrypt` rule
+    pub encrypt: Option<EncryptRule>,
+    /// Group of a `shuffle` rule: columns of a same group move together
+    pub shuffle_group: Option<String>,
+    /// Settings and profiled distribution of a `synthesize` rule
+    pub synthesize: Option<SynthesizeRule>,
+    /// Range of a `noise` rule
+    pub noise: Option<NoiseSpec>,
+    /// Coarsening of a `generalize` rule
+    pub generalize: Option<Generalization>,
     pub nbhits: u64,
 }
 
@@ -49,19 +122,56 @@ impl Default for AnonBase {
             anon_type: AnonType::FixedNull,
             len: 0,
             domain: String::new(),
+            email: EmailOptions::default(),
             separator: None,
             fixed_value: String::new(),
             pydef: String::new(),
             pyargs: String::new(),
+            regex_replace: None,
+            keep_private: false,
+            id_kind: None,
+            id_domain: None,
+            mask: None,
+            encrypt: None,
+            shuffle_group: None,
+            synthesize: None,
+            noise: None,
+            generalize: None,
             nbhits: 0,
         }
     }
 }
 
+/// Compiled path of a rule in a `json`, `phpserialized`, `yaml` or `xml` block
+#[derive(Debug, Clone, PartialEq)]
+pub enum RulePath {
+    /// JSON path syntax, in `json`, `phpserialized` and `yaml` blocks
+    Json(JsonPath),
+    Xml(XmlPath),
+}
+
 /// JSON path anonymization — matches C anon_json_st
 #[derive(Debug, Clone)]
 pub struct AnonJson {
     pub filter: String,
+    pub path: RulePath,
+    pub infos: AnonBase,
+    /// Rules applied to the JSON document held in a string value
+    /// (`path '...' = json { ... }`)
+    pub json: Vec<AnonJson>,
+}
+
+/// Rule of a `url { ... }` block
+#[derive(Debug, Clone)]
+pub struct AnonUrl {
+    pub component: UrlComponent,
+    pub infos: AnonBase,
+}
+
+/// Rule of a `scrub { ... }` block, applied to each match of its detector
+#[derive(Debug, Clone)]
+pub struct AnonScrub {
+    pub detector: Detector,
     pub infos: AnonBase,
 }
 
@@ -71,8 +181,12 @@ pub struct AnonField {
     pub name: String,
     pub pos: i32,
     pub quoted: bool,
+    /// Numeric type of the column, from `CREATE TABLE`
+    pub numeric: Option<NumericType>,
     pub infos: AnonBase,
     pub json: Vec<AnonJson>,
+    pub url: Vec<AnonUrl>,
+    pub scrub: Vec<AnonScrub>,
 }
 
 /// Table config — matches C anon_table_st
@@ -84,44 +198,46 @@ pub struct AnonTable {
     pub fields: Vec<AnonField>,
 }
 
+/// Columns of a table analyzed by `myanon report` (`report = { ... }`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportTable {
+    pub name: String,
+    /// Quasi-identifier columns, grouping rows into equivalence classes
+    pub quasi: Vec<String>,
+    /// Sensitive columns, whose l-diversity is measured in each class
+    pub sensitive: Vec<String>,
+}
+
+/// Named secret of the `keys` block, used by `encrypt` rules
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncryptionKey {
+    pub name: String,
+    pub key: SivKey,
+}
+
 /// Top-level config
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Config {
     pub secret: String,
     pub stats: bool,
     pub pypath: String,
     pub pyscript: String,
     pub tables: Vec<AnonTable>,
+    /// Rewrite only the targeted values of JSON fields, keeping the rest of
+    /// the document byte-for-byte (`jsonpreserve = 'yes'`)
+    pub json_preserve: bool,
+    /// Dump charset forced from the command line (overrides `SET NAMES`
+    /// and table charsets found in the dump)
+    pub charset: Option<Charset>,
+    /// Tables analyzed by `myanon report`
+    pub report: Vec<ReportTable>,
+    /// Keys of the `encrypt` rules, also used by `myanon decrypt`
+    pub keys: Vec<EncryptionKey>,
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Config {
-            secret: String::new(),
-            stats: false,
-            pypath: String::new(),
-            pyscript: String::new(),
-            tables: Vec::new(),
-        }
-    }
-}
-
-/// Validates a JSON path string.
-/// Valid characters: alphanumeric, underscore, dot, brackets `[` and `]`.
+/// Validates a JSON path string (see `JsonPath` for the supported syntax).
 pub fn is_valid_json_path(path: &str) -> bool {
-    let mut chars = path.chars().peekable();
-    while let Some(c) = chars.next() {
-        match c {
-            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '.' => {}
-            '[' => {
-                if chars.next() != Some(']') {
-                    return false;
-                }
-            }
-            _ => return false,
-        }
-    }
-    true
+    JsonPath::parse(path).is_ok()
 }
 
 #[cfg(test)]
@@ -138,6 +254,11 @@ mod tests {
         assert!(is_valid_json_path("email_changes[][]"));
         assert!(is_valid_json_path("[]"));
         assert!(is_valid_json_path(".full_name"));
+        assert!(is_valid_json_path("items[0].name"));
+        assert!(is_valid_json_path("..email"));
+        assert!(is_valid_json_path("settings.*"));
+        assert!(is_valid_json_path("$['first-name']"));
+        assert!(is_valid_json_path(".[\"user name\"].email"));
     }
 
     #[test]
@@ -145,5 +266,8 @@ mod tests {
         assert!(!is_valid_json_path("foo[bar]"));
         assert!(!is_valid_json_path("foo bar"));
         assert!(!is_valid_json_path("foo@bar"));
+        assert!(!is_valid_json_path("foo.."));
+        assert!(!is_valid_json_path("foo['bar"));
+        assert!(!is_valid_json_path("foo[1"));
     }
 }
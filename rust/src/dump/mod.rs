@@ -1,3 +1,9 @@
 pub mod processor;
+pub mod profile;
+pub mod report;
+mod shuffle;
+mod tuples;
 
 pub use processor::DumpProcessor;
+pub use profile::profile_dump;
+pub use report::Reporter;
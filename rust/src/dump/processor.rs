@@ -1,10 +1,21 @@
 use std::io::{BufRead, BufReader, Read, Write};
 
 use crate::anonymize::{
-    anonymize_token, anonymize_token_into, remove_quote, AnonContext, AnonResult, QuoteMode,
+    anonymize_separated_into, anonymize_token_into, mysql_backslash_escape, mysql_backslash_escape_into,
+    mysql_unescape, mysql_unescape_bytes, remove_quote, AnonContext, AnonResult, QuoteMode,
 };
-use crate::config::{AnonType, Config, TableAction};
+use crate::charset::Charset;
+use crate::config::{AnonBase, AnonJson, AnonType, Config, RulePath, TableAction};
+use crate::dump::shuffle::{ShuffleColumn, ShuffleSpool};
+use crate::dump::tuples::{DumpCharsets, TuplePart, TupleScanner};
+use crate::iphash::CryptoPan;
 use crate::json;
+use crate::noise::NumericType;
+use crate::phpserialized::{php_anonymize_with, PhpValue};
+use crate::scrub::find_matches;
+use crate::url::{Url, UrlComponent};
+use crate::xml::XmlDocument;
+use crate::yaml::YamlDocument;
 #[cfg(feature = "python")]
 use crate::python::PythonRunner;
 
@@ -19,10 +30,43 @@ enum State {
     TruncateInValues,
 }
 
+fn json_rules_use_py(rules: &[AnonJson]) -> bool {
+    rules
+        .iter()
+        .any(|r| r.infos.anon_type == AnonType::Py || json_rules_use_py(&r.json))
+}
+
+/// Rule applied to a whole field value: its own rule, or the `fallback`
+/// of a `url { ... }` block for values that are not URLs.
+fn field_rule(config: &Config, table_idx: usize, field_idx: usize, fallback: bool) -> &AnonBase {
+    let field = &config.tables[table_idx].fields[field_idx];
+    if fallback {
+        if let Some(rule) = field.url.iter().find(|u| u.component == UrlComponent::Fallback) {
+            return &rule.infos;
+        }
+    }
+    &field.infos
+}
+
+/// A JSON, PHP serialized, XML or YAML field value being anonymized by path rules
+enum StructuredValue {
+    /// Parsed and re-serialized in compact form
+    Tree(json::JsonValue),
+    /// Edited in place (`jsonpreserve = 'yes'`)
+    Document(json::JsonDocument),
+    /// PHP serialized data (`phpserialized { ... }`)
+    Php(PhpValue),
+    /// XML, edited in place (`xml { ... }`)
+    Xml(XmlDocument),
+    /// YAML, edited in place (`yaml { ... }`)
+    Yaml(YamlDocument),
+}
+
 /// Field info captured during CREATE TABLE parsing
 struct FieldInfo {
     name: String,
     quoted: bool,
+    numeric: Option<NumericType>,
 }
 
 pub struct DumpProcessor<'a> {
@@ -36,18 +80,34 @@ pub struct DumpProcessor<'a> {
     row_index: i32,
     bfirstinsert: bool,
     line_nb: usize,
-    values_field_pos: usize,
-    values_in_tuple: bool,
+    values: TupleScanner,
     table_needs_row_buffer: Vec<bool>,
     tuple_buffer: Vec<u8>,
     secret_bytes: Vec<u8>,
+    /// Key schedule of `iphash` rules, derived from the secret
+    crypto_pan: CryptoPan,
     anon_scratch: Vec<u8>,
+    charsets: DumpCharsets,
+    /// Output of the current table while it has `shuffle` rules
+    shuffle: Option<ShuffleSpool>,
     #[cfg(feature = "python")]
     python_runner: Option<PythonRunner>,
 }
 
 impl<'a> DumpProcessor<'a> {
     pub fn new(config: &'a mut Config) -> Result<Self, String> {
+        // `synthesize` draws from distributions set by a first pass over the dump
+        for table in &config.tables {
+            for field in &table.fields {
+                if field.infos.synthesize.as_ref().is_some_and(|r| r.profile.is_none()) {
+                    return Err(format!(
+                        "Field {}:{} - synthesize rule without profile, the dump must be profiled first",
+                        table.name, field.name
+                    ));
+                }
+            }
+        }
+
         #[cfg(feature = "python")]
         let python_runner = if !config.pyscript.is_empty() {
             Some(PythonRunner::new(
@@ -62,9 +122,20 @@ impl<'a> DumpProcessor<'a> {
         let table_needs_row_buffer: Vec<bool> = config
             .tables
             .iter()
-            .map(|t| t.fields.iter().any(|f| f.infos.anon_type == AnonType::Py))
+            .map(|t| {
+                t.fields.iter().any(|f| {
+                    f.infos.anon_type == AnonType::Py
+                        || json_rules_use_py(&f.json)
+                        || f.url.iter().any(|u| u.infos.anon_type == AnonType::Py)
+                        || f.scrub.iter().any(|r| r.infos.anon_type == AnonType::Py)
+                })
+            })
             .collect();
         let secret_bytes = config.secret.as_bytes().to_vec();
+        let charsets = DumpCharsets {
+            forced: config.charset,
+            ..DumpCharsets::default()
+        };
 
         Ok(DumpProcessor {
             config,
@@ -77,12 +148,14 @@ impl<'a> DumpProcessor<'a> {
             row_index: 0,
             bfirstinsert: true,
             line_nb: 1,
-            values_field_pos: 0,
-            values_in_tuple: false,
+            values: TupleScanner::default(),
             table_needs_row_buffer,
             tuple_buffer: Vec::new(),
+            crypto_pan: CryptoPan::new(&secret_bytes),
             secret_bytes,
             anon_scratch: Vec::with_capacity(64),
+            charsets,
+            shuffle: None,
             #[cfg(feature = "python")]
             python_runner,
         })
@@ -108,10 +181,58 @@ impl<'a> DumpProcessor<'a> {
             self.process_line(&line_buf, writer)?;
         }
 
-        Ok(())
+        self.finish_shuffle(writer)
     }
 
     fn process_line<W: Write>(&mut self, line: &[u8], writer: &mut W) -> Result<(), String> {
+        let create_table = line.starts_with(b"CREATE TABLE `");
+        // A table with shuffled columns is spooled until the next one starts
+        if create_table {
+            self.finish_shuffle(writer)?;
+        }
+        if let Some(mut spool) = self.shuffle.take() {
+            let result = self.dispatch_line(line, &mut spool);
+            self.shuffle = Some(spool);
+            return result;
+        }
+
+        self.dispatch_line(line, writer)?;
+
+        if let Some(idx) = self.current_table_config_idx.filter(|_| create_table) {
+            let table = &self.config.tables[idx];
+            if table.action == TableAction::Anon
+                && table.fields.iter().any(|f| f.infos.anon_type == AnonType::Shuffle)
+            {
+                self.shuffle = Some(ShuffleSpool::new(idx)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the spooled table, if any, with its shuffled columns permuted.
+    fn finish_shuffle<W: Write>(&mut self, writer: &mut W) -> Result<(), String> {
+        let Some(spool) = self.shuffle.take() else {
+            return Ok(());
+        };
+        let columns: Vec<ShuffleColumn> = self.config.tables[spool.table_idx]
+            .fields
+            .iter()
+            .filter(|f| f.infos.anon_type == AnonType::Shuffle && f.pos >= 0)
+            .map(|f| ShuffleColumn {
+                pos: f.pos as usize,
+                group: f.infos.shuffle_group.clone().unwrap_or_else(|| f.name.clone()),
+            })
+            .collect();
+        spool.finish(
+            &self.current_table,
+            self.charsets.identifier(),
+            &columns,
+            &self.secret_bytes,
+            writer,
+        )
+    }
+
+    fn dispatch_line<W: Write>(&mut self, line: &[u8], writer: &mut W) -> Result<(), String> {
         match self.state {
             State::Initial => self.process_initial(line, writer),
             State::InTable => self.process_in_table(line, writer),
@@ -121,8 +242,10 @@ impl<'a> DumpProcessor<'a> {
         }
     }
 
-    fn find_table_config(&self, table_name: &str) -> Option<usize> {
-        for (i, table) in self.config.tables.iter().enumerate() {
+    /// Config table of a dump table: the first one with its name or a
+    /// regex matching it.
+    pub(crate) fn find_table_config(config: &Config, table_name: &str) -> Option<usize> {
+        for (i, table) in config.tables.iter().enumerate() {
             if let Some(ref regex) = table.regex {
                 let with_backticks = format!("`{}`", table_name);
                 if regex.is_match(&with_backticks) {
@@ -135,15 +258,56 @@ impl<'a> DumpProcessor<'a> {
         None
     }
 
-    fn extract_table_name_bytes(line: &[u8]) -> Option<String> {
+    pub(crate) fn extract_table_name_bytes(line: &[u8], charset: Charset) -> Option<String> {
         let start = line.iter().position(|&b| b == b'`')?;
         let end = line[start + 1..].iter().position(|&b| b == b'`')?;
-        String::from_utf8(line[start + 1..start + 1 + end].to_vec()).ok()
+        let name = &line[start + 1..start + 1 + end];
+        if charset.is_utf8() {
+            String::from_utf8(name.to_vec()).ok()
+        } else {
+            Some(charset.decode(name))
+        }
     }
 
     fn set_working_table(&mut self, line: &[u8]) {
-        self.current_table = Self::extract_table_name_bytes(line).unwrap_or_default();
-        self.current_table_config_idx = self.find_table_config(&self.current_table);
+        self.current_table =
+            Self::extract_table_name_bytes(line, self.charsets.identifier()).unwrap_or_default();
+        self.current_table_config_idx = Self::find_table_config(self.config, &self.current_table);
+        self.charsets.table = None;
+    }
+
+    /// Extract the charset name following `marker` (e.g. `SET NAMES `, `CHARSET=`).
+    pub(crate) fn parse_charset_name<'l>(line: &'l str, marker: &str) -> Option<&'l str> {
+        let start = line.find(marker)? + marker.len();
+        let rest = &line[start..];
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        if end == 0 {
+            None
+        } else {
+            Some(&rest[..end])
+        }
+    }
+
+    fn lookup_charset(&self, name: &str) -> Option<Charset> {
+        let charset = Charset::from_mysql_name(name);
+        if charset.is_none() {
+            eprintln!(
+                "WARNING! Unsupported charset '{}' at line {}, values will be handled as UTF-8",
+                name, self.line_nb
+            );
+        }
+        charset
+    }
+
+    /// Track `SET NAMES` / `/*!40101 SET NAMES ... */` statements.
+    fn detect_set_names(&mut self, line: &[u8]) {
+        if let Some(name) = DumpCharsets::set_names(line) {
+            if let Some(charset) = self.lookup_charset(&name) {
+                self.charsets.dump = Some(charset);
+            }
+        }
     }
 
     fn is_insert_replace_line(line: &[u8]) -> bool {
@@ -174,17 +338,16 @@ impl<'a> DumpProcessor<'a> {
             return Ok(());
         }
 
-        if Self::is_insert_replace_line(line) {
-            if self.current_table_config_idx.is_some() {
-                let terminated = self.process_insert_line(line, writer)?;
-                self.count_newlines(line);
-                if !terminated {
-                    self.state = State::InValues;
-                }
-                return Ok(());
+        if Self::is_insert_replace_line(line) && self.current_table_config_idx.is_some() {
+            let terminated = self.process_insert_line(line, writer)?;
+            self.count_newlines(line);
+            if !terminated {
+                self.state = State::InValues;
             }
+            return Ok(());
         }
 
+        self.detect_set_names(line);
         writer.write_all(line).map_err(|e| e.to_string())?;
         self.count_newlines(line);
         Ok(())
@@ -193,12 +356,15 @@ impl<'a> DumpProcessor<'a> {
     fn process_in_table<W: Write>(&mut self, line: &[u8], writer: &mut W) -> Result<(), String> {
         writer.write_all(line).map_err(|e| e.to_string())?;
 
-        // Line may be binary, but keywords are ASCII. Use lossy conversion for matching.
-        let line_str = String::from_utf8_lossy(line);
+        // Keywords are ASCII; identifiers are decoded with the dump charset.
+        let line_str = self.charsets.identifier().decode(line);
         let trimmed = line_str.trim_start();
 
         // Check for ENGINE line or ) ENGINE (end of CREATE TABLE)
         if trimmed.starts_with("ENGINE") || trimmed.starts_with(") ENGINE") {
+            if let Some(name) = Self::parse_charset_name(trimmed, "CHARSET=") {
+                self.charsets.table = self.lookup_charset(name);
+            }
             self.resolve_field_positions();
             self.state = State::Initial;
             self.count_newlines(line);
@@ -218,10 +384,10 @@ impl<'a> DumpProcessor<'a> {
         }
 
         // Try to parse a field definition: `fieldname` type...
-        if trimmed.starts_with('`') {
-            if let Some(end) = trimmed[1..].find('`') {
-                let field_name = &trimmed[1..1 + end];
-                let rest = &trimmed[2 + end..];
+        if let Some(stripped) = trimmed.strip_prefix('`') {
+            if let Some(end) = stripped.find('`') {
+                let field_name = &stripped[..end];
+                let rest = &stripped[end + 1..];
                 let rest_trimmed = rest.trim_start();
 
                 let quoted = Self::is_quoted_type(rest_trimmed);
@@ -229,6 +395,7 @@ impl<'a> DumpProcessor<'a> {
                 self.fields.push(FieldInfo {
                     name: field_name.to_string(),
                     quoted,
+                    numeric: NumericType::parse(rest_trimmed),
                 });
             }
         }
@@ -271,6 +438,7 @@ impl<'a> DumpProcessor<'a> {
                     if config_field.name == field_info.name {
                         config_field.pos = pos as i32;
                         config_field.quoted = field_info.quoted;
+                        config_field.numeric = field_info.numeric;
                         break;
                     }
                 }
@@ -319,6 +487,7 @@ impl<'a> DumpProcessor<'a> {
         }
 
         // Other lines pass through
+        self.detect_set_names(line);
         writer.write_all(line).map_err(|e| e.to_string())?;
         self.count_newlines(line);
         Ok(())
@@ -362,8 +531,7 @@ impl<'a> DumpProcessor<'a> {
             .map_err(|e| e.to_string())?;
 
         // Reset per-statement parser state
-        self.values_field_pos = 0;
-        self.values_in_tuple = false;
+        self.values = TupleScanner::default();
 
         // Parse values portion
         self.parse_values(&line[prefix_end..], table_idx, writer)
@@ -378,7 +546,6 @@ impl<'a> DumpProcessor<'a> {
         table_idx: usize,
         writer: &mut W,
     ) -> Result<bool, String> {
-        let len = bytes.len();
         let mut pos = 0;
         let mut terminated = false;
         let buffered = self
@@ -387,90 +554,46 @@ impl<'a> DumpProcessor<'a> {
             .copied()
             .unwrap_or(false);
 
-        while pos < len {
-            let b = bytes[pos];
-
-            // Buffered-tuple fast path: collect every byte until ')'.
-            if buffered && self.values_in_tuple {
-                if b == b')' {
-                    self.tuple_buffer.push(b')');
-                    pos += 1;
-                    self.values_in_tuple = false;
-                    self.flush_buffered_tuple(table_idx, writer)?;
-                    self.bfirstinsert = false;
-                } else {
-                    if b == b'\n' {
-                        self.line_nb += 1;
-                    }
-                    self.tuple_buffer.push(b);
-                    pos += 1;
-                }
-                continue;
+        while pos < bytes.len() {
+            let (part, end) = self.values.next(bytes, pos, self.line_nb)?;
+            let text = &bytes[pos..end];
+            if text == b"\n" {
+                self.line_nb += 1;
             }
-
-            match b {
-                b'(' => {
+            match part {
+                TuplePart::Open => {
                     if buffered {
                         self.tuple_buffer.clear();
                         self.tuple_buffer.push(b'(');
                     } else {
                         writer.write_all(b"(").map_err(|e| e.to_string())?;
                     }
-                    pos += 1;
-                    self.values_field_pos = 0;
                     self.row_index += 1;
                     self.tablekey.clear();
-                    self.values_in_tuple = true;
-                }
-                b')' => {
-                    writer.write_all(b")").map_err(|e| e.to_string())?;
-                    pos += 1;
-                    self.bfirstinsert = false;
-                    self.values_in_tuple = false;
                 }
-                b',' => {
-                    writer.write_all(b",").map_err(|e| e.to_string())?;
-                    pos += 1;
-                    if self.values_in_tuple {
-                        // Comma between fields in a tuple
-                        self.values_field_pos += 1;
+                TuplePart::Close => {
+                    if buffered {
+                        self.tuple_buffer.push(b')');
+                        self.flush_buffered_tuple(table_idx, writer)?;
+                    } else {
+                        writer.write_all(b")").map_err(|e| e.to_string())?;
                     }
-                    // If not in tuple, comma between tuples
+                    self.bfirstinsert = false;
                 }
-                b';' => {
+                TuplePart::End => {
                     writer.write_all(b";").map_err(|e| e.to_string())?;
-                    pos += 1;
                     terminated = true;
                 }
-                b'\n' => {
-                    writer.write_all(b"\n").map_err(|e| e.to_string())?;
-                    pos += 1;
-                    self.line_nb += 1;
-                }
-                b' ' => {
-                    writer.write_all(b" ").map_err(|e| e.to_string())?;
-                    pos += 1;
-                }
-                _ if self.values_in_tuple => {
-                    // Parse a value token
-                    let (token_type, end_pos) = self.scan_value(bytes, pos)?;
-                    let raw = &bytes[pos..end_pos];
-                    let field_pos = self.values_field_pos;
-                    self.handle_value(
-                        &token_type,
-                        raw,
-                        field_pos,
-                        table_idx,
-                        writer,
-                    )?;
-                    pos = end_pos;
+                // Buffered tuples are anonymized once complete
+                TuplePart::Value { .. } | TuplePart::Other if buffered && self.values.in_tuple() => {
+                    self.tuple_buffer.extend_from_slice(text);
                 }
-                _ => {
-                    // Outside of a tuple, pass through
-                    writer.write_all(&[b]).map_err(|e| e.to_string())?;
-                    pos += 1;
+                TuplePart::Value { field_pos, token } => {
+                    self.handle_value(&token, text, field_pos, table_idx, writer)?;
                 }
+                TuplePart::Other => writer.write_all(text).map_err(|e| e.to_string())?,
             }
+            pos = end;
         }
 
         Ok(terminated)
@@ -496,45 +619,17 @@ impl<'a> DumpProcessor<'a> {
         }
 
         // Pass 2 — re-walk the buffer to write the tuple, anonymizing per field.
+        let mut scanner = TupleScanner::default();
         let mut pos = 0;
-        let mut field_pos: usize = 0;
-        let mut in_tuple = false;
         while pos < buf.len() {
-            let b = buf[pos];
-            match b {
-                b'(' => {
-                    writer.write_all(b"(").map_err(|e| e.to_string())?;
-                    pos += 1;
-                    in_tuple = true;
-                    field_pos = 0;
-                }
-                b')' => {
-                    writer.write_all(b")").map_err(|e| e.to_string())?;
-                    pos += 1;
-                    in_tuple = false;
-                }
-                b',' => {
-                    writer.write_all(b",").map_err(|e| e.to_string())?;
-                    pos += 1;
-                    if in_tuple {
-                        field_pos += 1;
-                    }
-                }
-                b' ' | b'\n' => {
-                    writer.write_all(&[b]).map_err(|e| e.to_string())?;
-                    pos += 1;
-                }
-                _ if in_tuple => {
-                    let (token_type, end_pos) = self.scan_value(&buf, pos)?;
-                    let raw = &buf[pos..end_pos];
-                    self.handle_value(&token_type, raw, field_pos, table_idx, writer)?;
-                    pos = end_pos;
-                }
-                _ => {
-                    writer.write_all(&[b]).map_err(|e| e.to_string())?;
-                    pos += 1;
+            let (part, end) = scanner.next(&buf, pos, self.line_nb)?;
+            match part {
+                TuplePart::Value { field_pos, token } => {
+                    self.handle_value(&token, &buf[pos..end], field_pos, table_idx, writer)?;
                 }
+                _ => writer.write_all(&buf[pos..end]).map_err(|e| e.to_string())?,
             }
+            pos = end;
         }
 
         self.tuple_buffer = buf;
@@ -547,43 +642,23 @@ impl<'a> DumpProcessor<'a> {
     #[cfg(feature = "python")]
     fn extract_row_from_buffer(&self, buf: &[u8]) -> Result<Vec<(String, String)>, String> {
         let mut row: Vec<(String, String)> = Vec::with_capacity(self.fields.len());
+        let charset = self.charsets.value();
+        let mut scanner = TupleScanner::default();
         let mut pos = 0;
-        let mut field_pos: usize = 0;
-        let mut in_tuple = false;
         while pos < buf.len() {
-            let b = buf[pos];
-            match b {
-                b'(' => {
-                    in_tuple = true;
-                    field_pos = 0;
-                    pos += 1;
-                }
-                b')' => {
-                    in_tuple = false;
-                    pos += 1;
-                }
-                b',' => {
-                    if in_tuple {
-                        field_pos += 1;
-                    }
-                    pos += 1;
-                }
-                b' ' | b'\n' => pos += 1,
-                _ if in_tuple => {
-                    let (_token_type, end_pos) = self.scan_value(buf, pos)?;
-                    let raw = &buf[pos..end_pos];
-                    if let Some(field) = self.fields.get(field_pos) {
-                        let unquoted = if raw.len() >= 2 && raw[0] == b'\'' && raw[raw.len() - 1] == b'\'' {
-                            String::from_utf8_lossy(&raw[1..raw.len() - 1]).into_owned()
-                        } else {
-                            String::from_utf8_lossy(raw).into_owned()
-                        };
-                        row.push((format!("`{}`", field.name), unquoted));
-                    }
-                    pos = end_pos;
+            let (part, end) = scanner.next(buf, pos, self.line_nb)?;
+            if let TuplePart::Value { field_pos, .. } = part {
+                let raw = &buf[pos..end];
+                if let Some(field) = self.fields.get(field_pos) {
+                    let unquoted = if raw.len() >= 2 && raw[0] == b'\'' && raw[raw.len() - 1] == b'\'' {
+                        charset.decode(&raw[1..raw.len() - 1])
+                    } else {
+                        charset.decode(raw)
+                    };
+                    row.push((format!("`{}`", field.name), unquoted));
                 }
-                _ => pos += 1,
             }
+            pos = end;
         }
         Ok(row)
     }
@@ -618,88 +693,6 @@ impl<'a> DumpProcessor<'a> {
         Ok(())
     }
 
-    fn scan_value(&self, bytes: &[u8], pos: usize) -> Result<(ValueToken, usize), String> {
-        let len = bytes.len();
-
-        // NULL
-        if pos + 4 <= len && &bytes[pos..pos + 4] == b"NULL" {
-            if pos + 4 >= len || !bytes[pos + 4].is_ascii_alphanumeric() {
-                return Ok((ValueToken::Null, pos + 4));
-            }
-        }
-
-        // Hex binary: 0x[0-9a-fA-F]+
-        if pos + 2 < len && bytes[pos] == b'0' && bytes[pos + 1] == b'x' {
-            let mut end = pos + 2;
-            while end < len && bytes[end].is_ascii_hexdigit() {
-                end += 1;
-            }
-            if end > pos + 2 {
-                return Ok((ValueToken::Raw, end));
-            }
-        }
-
-        // _binary 'string' — treated as a quoted value (the whole token including
-        // _binary prefix is passed to anonymize_token, matching C behavior)
-        if pos + 8 <= len && &bytes[pos..pos + 8] == b"_binary " {
-            if pos + 8 < len && bytes[pos + 8] == b'\'' {
-                let end = self.scan_sql_string(bytes, pos + 8)?;
-                return Ok((ValueToken::Quoted, end));
-            }
-        }
-
-        // Single-quoted string
-        if bytes[pos] == b'\'' {
-            let end = self.scan_sql_string(bytes, pos)?;
-            return Ok((ValueToken::Quoted, end));
-        }
-
-        // Numeric value: [0-9\-\.eE+]+
-        if bytes[pos].is_ascii_digit()
-            || bytes[pos] == b'-'
-            || bytes[pos] == b'.'
-        {
-            let mut end = pos;
-            while end < len {
-                let c = bytes[end];
-                if c.is_ascii_digit()
-                    || c == b'-'
-                    || c == b'.'
-                    || c == b'e'
-                    || c == b'E'
-                    || c == b'+'
-                {
-                    end += 1;
-                } else {
-                    break;
-                }
-            }
-            if end > pos {
-                return Ok((ValueToken::Unquoted, end));
-            }
-        }
-
-        Err(format!(
-            "Unexpected character '{}' at line {}",
-            bytes[pos] as char, self.line_nb
-        ))
-    }
-
-    fn scan_sql_string(&self, bytes: &[u8], pos: usize) -> Result<usize, String> {
-        let mut i = pos + 1; // skip opening quote
-        let len = bytes.len();
-        while i < len {
-            if bytes[i] == b'\\' && i + 1 < len {
-                i += 2;
-            } else if bytes[i] == b'\'' {
-                return Ok(i + 1);
-            } else {
-                i += 1;
-            }
-        }
-        Err(format!("Unterminated string at line {}", self.line_nb))
-    }
-
     fn handle_value<W: Write>(
         &mut self,
         token_type: &ValueToken,
@@ -748,6 +741,29 @@ impl<'a> DumpProcessor<'a> {
         // Increment hit counter
         self.config.tables[table_idx].fields[field_idx].infos.nbhits += 1;
 
+        // Rules work on UTF-8: transcode values of other charsets around them.
+        // Binary literals (`0x...`, `_binary '...'`) hold bytes, not text.
+        let charset = self.charsets.value();
+        let binary = *token_type == ValueToken::Raw || raw.starts_with(b"_binary ");
+        if !charset.is_utf8() && !binary {
+            let decoded = charset.decode(raw);
+            let mut out = Vec::with_capacity(raw.len());
+            self.anonymize_value(decoded.as_bytes(), table_idx, field_idx, &mut out)?;
+            writer.write_all(&charset.encode_bytes(&out)).map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+
+        self.anonymize_value(raw, table_idx, field_idx, writer)
+    }
+
+    /// Apply the field rule to a non-NULL value and write the result.
+    fn anonymize_value<W: Write>(
+        &mut self,
+        raw: &[u8],
+        table_idx: usize,
+        field_idx: usize,
+        writer: &mut W,
+    ) -> Result<(), String> {
         let field_quoted = self.config.tables[table_idx].fields[field_idx].quoted;
         let anon_type = self.config.tables[table_idx].fields[field_idx]
             .infos
@@ -763,9 +779,56 @@ impl<'a> DumpProcessor<'a> {
             return Ok(());
         }
 
+        // PHP serialized data
+        if anon_type == AnonType::PhpSerialized {
+            let handled = self.handle_php_anonymization(raw, table_idx, field_idx, writer)?;
+            if !handled {
+                writer.write_all(raw).map_err(|e| e.to_string())?;
+            }
+            return Ok(());
+        }
+
+        // XML and YAML
+        if anon_type == AnonType::Xml || anon_type == AnonType::Yaml {
+            let handled = self.handle_document_anonymization(raw, table_idx, field_idx, writer)?;
+            if !handled {
+                writer.write_all(raw).map_err(|e| e.to_string())?;
+            }
+            return Ok(());
+        }
+
+        // Personal data in free text
+        if anon_type == AnonType::Scrub {
+            if !field_quoted {
+                writer.write_all(raw).map_err(|e| e.to_string())?;
+                return Ok(());
+            }
+            return self.handle_scrub_anonymization(raw, table_idx, field_idx, writer);
+        }
+
+        // URL: values that are not URLs get the fallback rule, if any
+        let mut fallback = false;
+        if anon_type == AnonType::Url {
+            if self.handle_url_anonymization(raw, table_idx, field_idx, writer)? {
+                return Ok(());
+            }
+            let field = &mut self.config.tables[table_idx].fields[field_idx];
+            match field.url.iter_mut().find(|u| u.component == UrlComponent::Fallback) {
+                Some(rule) => rule.infos.nbhits += 1,
+                None => {
+                    writer.write_all(raw).map_err(|e| e.to_string())?;
+                    return Ok(());
+                }
+            }
+            fallback = true;
+        }
+        let anon_type = field_rule(self.config, table_idx, field_idx, fallback)
+            .anon_type
+            .clone();
+
         // Python anonymization
         if anon_type == AnonType::Py {
-            let res = self.handle_py_anonymization(raw, field_quoted, table_idx, field_idx);
+            let res = self.handle_py_anonymization(raw, field_quoted, table_idx, field_idx, fallback);
             let out_quoted = match res.quoting {
                 QuoteMode::ForceTrue => true,
                 QuoteMode::ForceFalse => false,
@@ -776,13 +839,12 @@ impl<'a> DumpProcessor<'a> {
         }
 
         // Separated values
-        let has_separator = self.config.tables[table_idx].fields[field_idx]
-            .infos
+        let has_separator = field_rule(self.config, table_idx, field_idx, fallback)
             .separator
             .is_some();
 
         if has_separator {
-            self.handle_separated_values(raw, table_idx, field_idx, field_quoted, writer)?;
+            self.handle_separated_values(raw, table_idx, field_idx, fallback, field_quoted, writer)?;
             return Ok(());
         }
 
@@ -792,9 +854,11 @@ impl<'a> DumpProcessor<'a> {
             rowindex: self.row_index,
             bfirstinsert: self.bfirstinsert,
             tablename: &self.current_table,
+            column: self.config.tables[table_idx].fields[field_idx].numeric,
+            crypto_pan: Some(&self.crypto_pan),
         };
 
-        let config = &self.config.tables[table_idx].fields[field_idx].infos;
+        let config = field_rule(self.config, table_idx, field_idx, fallback);
         let quoting = anonymize_token_into(
             &mut self.anon_scratch,
             field_quoted,
@@ -803,6 +867,7 @@ impl<'a> DumpProcessor<'a> {
             &self.secret_bytes,
             Some(&mut ctx),
         );
+        self.check_encrypt_width(table_idx, field_idx, fallback)?;
 
         let out_quoted = match quoting {
             QuoteMode::ForceTrue => true,
@@ -834,75 +899,366 @@ impl<'a> DumpProcessor<'a> {
         // Remove quotes
         let unquoted = String::from_utf8(remove_quote(raw)).unwrap_or_default();
 
-        // Remove JSON backslash escaping
-        let unbackslashed = json::remove_json_backslash(&unquoted);
-
-        // Parse JSON
-        let mut parsed = match json::json_parse_string(&unbackslashed) {
+        // Parse JSON, either to edit it in place or to re-serialize it
+        let parsed = if self.config.json_preserve {
+            json::JsonDocument::parse(&unquoted).map(StructuredValue::Document)
+        } else {
+            // Remove JSON backslash escaping
+            json::json_parse_string(&json::remove_json_backslash(&unquoted)).map(StructuredValue::Tree)
+        };
+        let mut parsed = match parsed {
             Some(v) => v,
             None => {
                 let field_name = &self.config.tables[table_idx].fields[field_idx].name;
                 eprintln!(
                     "WARNING! Table/field {}: Unable to parse json field '{}' at line {}, skip anonymization",
-                    field_name, unbackslashed, self.line_nb
+                    field_name,
+                    json::remove_json_backslash(&unquoted),
+                    self.line_nb
                 );
                 return Ok(false);
             }
         };
 
-        // Collect JSON rules
-        let json_rules: Vec<(String, crate::config::AnonBase)> = self.config.tables[table_idx]
-            .fields[field_idx]
-            .json
-            .iter()
-            .map(|j| (j.filter.clone(), j.infos.clone()))
-            .collect();
+        self.apply_field_path_rules(&mut parsed, table_idx, field_idx);
 
-        for (i, (filter, rule_config)) in json_rules.iter().enumerate() {
-            if json::json_path_has_wildcards(filter) {
-                json::json_anonymize_path(&mut parsed, filter, rule_config, &self.secret_bytes);
-            } else {
-                let current_value = json::json_get_string_at_path(&parsed, filter);
-                if current_value.is_none() {
-                    continue;
+        match parsed {
+            StructuredValue::Tree(value) => {
+                // Serialize back to JSON
+                let result_str = json::json_to_string(&value);
+                let backslashed = json::add_json_backslash(&result_str);
+                self.write_quoted_output(backslashed.as_bytes(), true, writer)?;
+            }
+            StructuredValue::Document(doc) => {
+                self.write_quoted_output(doc.as_str().as_bytes(), true, writer)?;
+            }
+            StructuredValue::Php(_) | StructuredValue::Xml(_) | StructuredValue::Yaml(_) => {
+                unreachable!("JSON fields are only parsed as JSON")
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn handle_php_anonymization<W: Write>(
+        &mut self,
+        raw: &[u8],
+        table_idx: usize,
+        field_idx: usize,
+        writer: &mut W,
+    ) -> Result<bool, String> {
+        let unquoted = String::from_utf8(remove_quote(raw)).unwrap_or_default();
+        let (unescaped, _) = mysql_unescape(&unquoted);
+        let charset = self.charsets.value();
+
+        let mut parsed = match PhpValue::parse(&unescaped, charset) {
+            Ok(value) => StructuredValue::Php(value),
+            Err(e) => {
+                let field_name = &self.config.tables[table_idx].fields[field_idx].name;
+                eprintln!(
+                    "WARNING! Table/field {}: Unable to parse PHP serialized field '{}' ({}) at line {}, skip anonymization",
+                    field_name, unescaped, e, self.line_nb
+                );
+                return Ok(false);
+            }
+        };
+
+        self.apply_field_path_rules(&mut parsed, table_idx, field_idx);
+
+        let StructuredValue::Php(value) = parsed else {
+            unreachable!("apply_path_rules keeps the kind of value");
+        };
+        let escaped = mysql_backslash_escape(&value.serialize(charset));
+        self.write_quoted_output(escaped.as_bytes(), true, writer)?;
+        Ok(true)
+    }
+
+    /// XML and YAML fields, edited in their SQL-escaped text.
+    fn handle_document_anonymization<W: Write>(
+        &mut self,
+        raw: &[u8],
+        table_idx: usize,
+        field_idx: usize,
+        writer: &mut W,
+    ) -> Result<bool, String> {
+        let unquoted = String::from_utf8(remove_quote(raw)).unwrap_or_default();
+        let field = &self.config.tables[table_idx].fields[field_idx];
+        let (parsed, kind) = if field.infos.anon_type == AnonType::Yaml {
+            (YamlDocument::parse(&unquoted).map(StructuredValue::Yaml), "yaml")
+        } else {
+            (XmlDocument::parse(&unquoted).map(StructuredValue::Xml), "xml")
+        };
+        let mut parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!(
+                    "WARNING! Table/field {}: Unable to parse {} field '{}' ({}) at line {}, skip anonymization",
+                    field.name, kind, unquoted, e, self.line_nb
+                );
+                return Ok(false);
+            }
+        };
+
+        self.apply_field_path_rules(&mut parsed, table_idx, field_idx);
+
+        let text = match &parsed {
+            StructuredValue::Xml(doc) => doc.as_str(),
+            StructuredValue::Yaml(doc) => doc.as_str(),
+            _ => unreachable!("apply_path_rules keeps the kind of value"),
+        };
+        self.write_quoted_output(text.as_bytes(), true, writer)?;
+        Ok(true)
+    }
+
+    /// Apply the path rules of a field to its parsed value, and count the
+    /// rows where each rule matched a value it applies to.
+    fn apply_field_path_rules(
+        &mut self,
+        parsed: &mut StructuredValue,
+        table_idx: usize,
+        field_idx: usize,
+    ) {
+        // Path rules never set the table key, they only read it
+        let mut tablekey = std::mem::take(&mut self.tablekey);
+        let mut hits = Vec::new();
+        self.apply_path_rules(
+            parsed,
+            &self.config.tables[table_idx].fields[field_idx].json,
+            &mut Vec::new(),
+            &mut tablekey,
+            &mut hits,
+        );
+        self.tablekey = tablekey;
+
+        hits.sort_unstable();
+        hits.dedup();
+        let field = &mut self.config.tables[table_idx].fields[field_idx];
+        for location in hits {
+            let mut rule = &mut field.json[location[0]];
+            for &i in &location[1..] {
+                rule = &mut rule.json[i];
+            }
+            rule.infos.nbhits += 1;
+        }
+    }
+
+    /// Apply JSON, PHP, XML or YAML path rules to a parsed value. Rules are identified by their
+    /// index at each nesting level (`location`); the location of every rule
+    /// that anonymized something is added to `hits`.
+    fn apply_path_rules(
+        &self,
+        target: &mut StructuredValue,
+        rules: &[AnonJson],
+        location: &mut Vec<usize>,
+        tablekey: &mut String,
+        hits: &mut Vec<Vec<usize>>,
+    ) {
+        for (i, rule) in rules.iter().enumerate() {
+            location.push(i);
+            let mut anonymize =
+                |token: &[u8]| self.anonymize_path_leaf(rule, token, location, tablekey, hits);
+            let anonymized = match (&mut *target, &rule.path) {
+                (StructuredValue::Tree(value), RulePath::Json(path)) => {
+                    json::json_anonymize_with(value, path, &rule.infos, &mut anonymize)
+                }
+                (StructuredValue::Document(doc), RulePath::Json(path)) => {
+                    doc.anonymize_with(path, &rule.infos, &mut anonymize)
+                }
+                (StructuredValue::Php(value), RulePath::Json(path)) => {
+                    php_anonymize_with(value, path, &rule.infos, &mut anonymize)
                 }
-                let current_value = current_value.unwrap();
-
-                let new_value = if rule_config.anon_type == AnonType::Fixed {
-                    rule_config.fixed_value.clone()
-                } else {
-                    let res = anonymize_token(
-                        false,
-                        rule_config,
-                        current_value.as_bytes(),
-                        &self.secret_bytes,
-                        None,
-                    );
-                    String::from_utf8(res.data).unwrap_or_default()
-                };
-
-                json::json_replace_value_at_path(&mut parsed, filter, &new_value);
+                (StructuredValue::Yaml(doc), RulePath::Json(path)) => {
+                    doc.anonymize_with(path, &rule.infos, &mut anonymize)
+                }
+                (StructuredValue::Xml(doc), RulePath::Xml(path)) => {
+                    doc.anonymize_with(path, &rule.infos, &mut anonymize)
+                }
+                // xml blocks only have XML paths, the other blocks JSON paths
+                _ => 0,
+            };
+            if anonymized > 0 {
+                hits.push(location.clone());
             }
+            location.pop();
+        }
+    }
 
-            // Increment hit counter for this JSON path
-            self.config.tables[table_idx].fields[field_idx].json[i].infos.nbhits += 1;
+    /// Anonymize a value found inside a JSON, PHP serialized, XML or YAML document,
+    /// through the same rule code as top-level fields (pydef, "separated by" or the plain rule).
+    fn anonymize_path_leaf(
+        &self,
+        rule: &AnonJson,
+        token: &[u8],
+        location: &mut Vec<usize>,
+        tablekey: &mut String,
+        hits: &mut Vec<Vec<usize>>,
+    ) -> Option<Vec<u8>> {
+        let infos = &rule.infos;
+        if infos.anon_type == AnonType::Json {
+            return self.anonymize_embedded_json(rule, token, location, tablekey, hits);
         }
+        Some(self.anonymize_leaf(infos, token, tablekey))
+    }
 
-        // Serialize back to JSON
-        let result_str = json::json_to_string(&parsed);
-        let backslashed = json::add_json_backslash(&result_str);
+    /// Anonymize a value found inside a field (document value or URL
+    /// component) with a rule: pydef, "separated by" or the plain rule.
+    fn anonymize_leaf(&self, infos: &AnonBase, token: &[u8], tablekey: &mut String) -> Vec<u8> {
+        if infos.anon_type == AnonType::Py {
+            return self.call_pydef(infos, &String::from_utf8_lossy(token));
+        }
 
-        self.write_quoted_output(backslashed.as_bytes(), true, writer)?;
+        let mut ctx = AnonContext {
+            tablekey,
+            rowindex: self.row_index,
+            bfirstinsert: self.bfirstinsert,
+            tablename: &self.current_table,
+            column: None,
+            crypto_pan: Some(&self.crypto_pan),
+        };
+        let mut out = Vec::new();
+        if infos.separator.is_some() {
+            anonymize_separated_into(
+                &mut out,
+                infos,
+                &String::from_utf8_lossy(token),
+                &self.secret_bytes,
+                Some(&mut ctx),
+            );
+        } else {
+            anonymize_token_into(&mut out, false, infos, token, &self.secret_bytes, Some(&mut ctx));
+        }
+        out
+    }
 
+    /// Replace each match of the detectors of a `scrub { ... }` block by the
+    /// result of its rule, leaving the rest of the text as it is.
+    fn handle_scrub_anonymization<W: Write>(
+        &mut self,
+        raw: &[u8],
+        table_idx: usize,
+        field_idx: usize,
+        writer: &mut W,
+    ) -> Result<(), String> {
+        let (text, _) = mysql_unescape_bytes(&remove_quote(raw));
+        let rules = &self.config.tables[table_idx].fields[field_idx].scrub;
+
+        let mut tablekey = std::mem::take(&mut self.tablekey);
+        let mut scrubbed = Vec::with_capacity(text.len());
+        let mut hits = vec![false; rules.len()];
+        // Bytes that are not UTF-8 are kept as they are, the text around them is scanned
+        for chunk in text.utf8_chunks() {
+            let valid = chunk.valid();
+            let mut last = 0;
+            for (range, i) in find_matches(valid, rules.iter().map(|r| &r.detector)) {
+                scrubbed.extend_from_slice(&valid.as_bytes()[last..range.start]);
+                let out = self.anonymize_leaf(&rules[i].infos, &valid.as_bytes()[range.clone()], &mut tablekey);
+                // The rule output is escaped for SQL, the whole text is escaped below
+                scrubbed.extend_from_slice(&mysql_unescape_bytes(&out).0);
+                last = range.end;
+                hits[i] = true;
+            }
+            scrubbed.extend_from_slice(&valid.as_bytes()[last..]);
+            scrubbed.extend_from_slice(chunk.invalid());
+        }
+        self.tablekey = tablekey;
+
+        let field = &mut self.config.tables[table_idx].fields[field_idx];
+        for (rule, hit) in field.scrub.iter_mut().zip(hits) {
+            if hit {
+                rule.infos.nbhits += 1;
+            }
+        }
+
+        let mut escaped = Vec::with_capacity(scrubbed.len() + 8);
+        mysql_backslash_escape_into(&mut escaped, &scrubbed);
+        self.write_quoted_output(&escaped, true, writer)
+    }
+
+    /// Apply the component rules of a `url { ... }` block. Returns false,
+    /// writing nothing, when the value is not a URL.
+    fn handle_url_anonymization<W: Write>(
+        &mut self,
+        raw: &[u8],
+        table_idx: usize,
+        field_idx: usize,
+        writer: &mut W,
+    ) -> Result<bool, String> {
+        let unquoted = String::from_utf8(remove_quote(raw)).unwrap_or_default();
+        let (unescaped, _) = mysql_unescape(&unquoted);
+        let Ok(mut url) = Url::parse(&unescaped) else {
+            return Ok(false);
+        };
+
+        // Component rules never set the table key, they only read it
+        let mut tablekey = std::mem::take(&mut self.tablekey);
+        let mut hits = Vec::new();
+        for (i, rule) in self.config.tables[table_idx].fields[field_idx].url.iter().enumerate() {
+            let mut anonymize = |value: &[u8]| {
+                if rule.infos.anon_type == AnonType::Remove {
+                    return None;
+                }
+                // The rule output is escaped for SQL, the whole URL is escaped below
+                let out = self.anonymize_leaf(&rule.infos, value, &mut tablekey);
+                Some(mysql_unescape_bytes(&out).0)
+            };
+            if url.apply(&rule.component, &mut anonymize) > 0 {
+                hits.push(i);
+            }
+        }
+        self.tablekey = tablekey;
+
+        let field = &mut self.config.tables[table_idx].fields[field_idx];
+        for i in hits {
+            field.url[i].infos.nbhits += 1;
+        }
+
+        let escaped = mysql_backslash_escape(&url.to_string());
+        self.write_quoted_output(escaped.as_bytes(), true, writer)?;
         Ok(true)
     }
 
+    /// Apply the nested rules of a `json { ... }` JSON action to the JSON
+    /// document held in a string, and escape the result back into a string.
+    fn anonymize_embedded_json(
+        &self,
+        rule: &AnonJson,
+        token: &[u8],
+        location: &mut Vec<usize>,
+        tablekey: &mut String,
+        hits: &mut Vec<Vec<usize>>,
+    ) -> Option<Vec<u8>> {
+        let escaped = String::from_utf8_lossy(token);
+        let parsed = if self.config.json_preserve {
+            json::JsonDocument::parse_embedded(&escaped).map(StructuredValue::Document)
+        } else {
+            json::json_parse_string(&json::json_unescape_string(&escaped)).map(StructuredValue::Tree)
+        };
+        let Some(mut parsed) = parsed else {
+            eprintln!(
+                "WARNING! JSON path '{}': Unable to parse embedded json '{}' at line {}, skip anonymization",
+                rule.filter, escaped, self.line_nb
+            );
+            return None;
+        };
+
+        self.apply_path_rules(&mut parsed, &rule.json, location, tablekey, hits);
+
+        Some(match parsed {
+            StructuredValue::Tree(value) => json::json_escape_string(&json::json_to_string(&value)).into_bytes(),
+            StructuredValue::Document(doc) => doc.as_str().as_bytes().to_vec(),
+            StructuredValue::Php(_) | StructuredValue::Xml(_) | StructuredValue::Yaml(_) => {
+                unreachable!("embedded documents are JSON")
+            }
+        })
+    }
+
     fn handle_py_anonymization(
         &self,
         raw: &[u8],
         field_quoted: bool,
         table_idx: usize,
         field_idx: usize,
+        fallback: bool,
     ) -> AnonResult {
         // Strip quotes like anonymize_token does
         let worktoken = if field_quoted {
@@ -911,18 +1267,23 @@ impl<'a> DumpProcessor<'a> {
             raw.to_vec()
         };
         let value_str = String::from_utf8_lossy(&worktoken);
-        let pydef = &self.config.tables[table_idx].fields[field_idx].infos.pydef;
-        let pyargs = &self.config.tables[table_idx].fields[field_idx].infos.pyargs;
+        AnonResult {
+            data: self.call_pydef(field_rule(self.config, table_idx, field_idx, fallback), &value_str),
+            quoting: QuoteMode::AsInput,
+        }
+    }
+
+    /// Run a pydef rule on a value. Returns an empty value if the call fails.
+    fn call_pydef(&self, infos: &AnonBase, value_str: &str) -> Vec<u8> {
+        let pydef = &infos.pydef;
+        let pyargs = &infos.pyargs;
 
         #[cfg(feature = "python")]
         {
             if let Some(ref runner) = self.python_runner {
-                match runner.call(pydef, &value_str, pyargs) {
+                match runner.call(pydef, value_str, pyargs) {
                     Ok(result) => {
-                        return AnonResult {
-                            data: result.into_bytes(),
-                            quoting: QuoteMode::AsInput,
-                        };
+                        return result.into_bytes();
                     }
                     Err(e) => {
                         eprintln!("{}", e);
@@ -933,14 +1294,11 @@ impl<'a> DumpProcessor<'a> {
 
         #[cfg(not(feature = "python"))]
         {
-            let _ = (pydef, pyargs, &value_str);
+            let _ = (pydef, pyargs, value_str);
             eprintln!("Python support not compiled in, cannot use pydef");
         }
 
-        AnonResult {
-            data: Vec::new(),
-            quoting: QuoteMode::AsInput,
-        }
+        Vec::new()
     }
 
     fn handle_separated_values<W: Write>(
@@ -948,58 +1306,58 @@ impl<'a> DumpProcessor<'a> {
         raw: &[u8],
         table_idx: usize,
         field_idx: usize,
+        fallback: bool,
         field_quoted: bool,
         writer: &mut W,
     ) -> Result<(), String> {
-        let separator = self.config.tables[table_idx].fields[field_idx]
-            .infos
-            .separator
-            .unwrap();
-
         let worktext = if field_quoted {
             String::from_utf8(remove_quote(raw)).unwrap_or_default()
         } else {
             String::from_utf8_lossy(raw).to_string()
         };
 
-        let parts: Vec<&str> = worktext.split(separator).collect();
-
-        if parts.is_empty() {
-            writer.write_all(raw).map_err(|e| e.to_string())?;
-            return Ok(());
-        }
-
-        writer.write_all(b"'").map_err(|e| e.to_string())?;
-
-        for (i, part) in parts.iter().enumerate() {
-            if i > 0 {
-                writer
-                    .write_all(&[separator as u8])
-                    .map_err(|e| e.to_string())?;
-            }
-
-            let mut ctx = AnonContext {
-                tablekey: &mut self.tablekey,
-                rowindex: self.row_index,
-                bfirstinsert: self.bfirstinsert,
-                tablename: &self.current_table,
-            };
+        let mut ctx = AnonContext {
+            tablekey: &mut self.tablekey,
+            rowindex: self.row_index,
+            bfirstinsert: self.bfirstinsert,
+            tablename: &self.current_table,
+            column: self.config.tables[table_idx].fields[field_idx].numeric,
+            crypto_pan: Some(&self.crypto_pan),
+        };
 
-            let config = &self.config.tables[table_idx].fields[field_idx].infos;
-            let res = anonymize_token(false, config, part.as_bytes(), &self.secret_bytes, Some(&mut ctx));
+        let config = field_rule(self.config, table_idx, field_idx, fallback);
+        anonymize_separated_into(
+            &mut self.anon_scratch,
+            config,
+            &worktext,
+            &self.secret_bytes,
+            Some(&mut ctx),
+        );
+        self.check_encrypt_width(table_idx, field_idx, fallback)?;
 
-            let out_quoted = match res.quoting {
-                QuoteMode::ForceTrue => true,
-                QuoteMode::ForceFalse => false,
-                QuoteMode::AsInput => false,
-            };
+        self.write_quoted_output(&self.anon_scratch, true, writer)
+    }
 
-            self.write_quoted_output(&res.data, out_quoted, writer)?;
+    /// Stop the run when the result of an `encrypt` rule, in `anon_scratch`,
+    /// does not fit the width of its column: MySQL would reject or truncate
+    /// it, and a truncated value cannot be decrypted.
+    fn check_encrypt_width(&self, table_idx: usize, field_idx: usize, fallback: bool) -> Result<(), String> {
+        let config = field_rule(self.config, table_idx, field_idx, fallback);
+        let Some(rule) = config.encrypt.as_ref().filter(|r| !r.decrypt) else {
+            return Ok(());
+        };
+        match (rule.width, rule.max_len) {
+            (Some(width), Some(max_len)) if self.anon_scratch.len() > width => Err(format!(
+                "Table {}: encrypted value of field {} at line {} has {} characters, more than width {} (values up to {} bytes fit)",
+                self.current_table,
+                self.config.tables[table_idx].fields[field_idx].name,
+                self.line_nb,
+                self.anon_scratch.len(),
+                width,
+                max_len
+            )),
+            _ => Ok(()),
         }
-
-        writer.write_all(b"'").map_err(|e| e.to_string())?;
-
-        Ok(())
     }
 
     fn write_quoted_output<W: Write>(
@@ -1020,9 +1378,92 @@ impl<'a> DumpProcessor<'a> {
 }
 
 #[derive(Debug, PartialEq)]
-enum ValueToken {
+pub(crate) enum ValueToken {
     Null,
     Raw,
     Quoted,
     Unquoted,
 }
+
+/// Scan the SQL value starting at `pos` in a VALUES list. Returns its kind and
+/// the position following it; `line_nb` is only used in error messages.
+pub(crate) fn scan_value(bytes: &[u8], pos: usize, line_nb: usize) -> Result<(ValueToken, usize), String> {
+    let len = bytes.len();
+
+    // NULL
+    if pos + 4 <= len
+        && &bytes[pos..pos + 4] == b"NULL"
+        && (pos + 4 >= len || !bytes[pos + 4].is_ascii_alphanumeric())
+    {
+        return Ok((ValueToken::Null, pos + 4));
+    }
+
+    // Hex binary: 0x[0-9a-fA-F]+
+    if pos + 2 < len && bytes[pos] == b'0' && bytes[pos + 1] == b'x' {
+        let mut end = pos + 2;
+        while end < len && bytes[end].is_ascii_hexdigit() {
+            end += 1;
+        }
+        if end > pos + 2 {
+            return Ok((ValueToken::Raw, end));
+        }
+    }
+
+    // _binary 'string' — treated as a quoted value (the whole token including
+    // _binary prefix is passed to anonymize_token, matching C behavior)
+    if pos + 8 < len && &bytes[pos..pos + 8] == b"_binary " && bytes[pos + 8] == b'\'' {
+        let end = scan_sql_string(bytes, pos + 8, line_nb)?;
+        return Ok((ValueToken::Quoted, end));
+    }
+
+    // Single-quoted string
+    if bytes[pos] == b'\'' {
+        let end = scan_sql_string(bytes, pos, line_nb)?;
+        return Ok((ValueToken::Quoted, end));
+    }
+
+    // Numeric value: [0-9\-\.eE+]+
+    if bytes[pos].is_ascii_digit()
+        || bytes[pos] == b'-'
+        || bytes[pos] == b'.'
+    {
+        let mut end = pos;
+        while end < len {
+            let c = bytes[end];
+            if c.is_ascii_digit()
+                || c == b'-'
+                || c == b'.'
+                || c == b'e'
+                || c == b'E'
+                || c == b'+'
+            {
+                end += 1;
+            } else {
+                break;
+            }
+        }
+        if end > pos {
+            return Ok((ValueToken::Unquoted, end));
+        }
+    }
+
+    Err(format!(
+        "Unexpected character '{}' at line {}",
+        bytes[pos] as char, line_nb
+    ))
+}
+
+fn scan_sql_string(bytes: &[u8], pos: usize, line_nb: usize) -> Result<usize, String> {
+    let mut i = pos + 1; // skip opening quote
+    let len = bytes.len();
+    while i < len {
+        if bytes[i] == b'\\' && i + 1 < len {
+            i += 2;
+        } else if bytes[i] == b'\'' {
+            return Ok(i + 1);
+        } else {
+            i += 1;
+        }
+    }
+    Err(format!("Unterminated string at line {}", line_nb))
+}
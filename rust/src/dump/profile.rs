@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+
+use crate::anonymize::remove_quote_slice;
+use crate::charset::Charset;
+use crate::config::{AnonType, Config, TableAction};
+use crate::dump::processor::{DumpProcessor, ValueToken};
+use crate::dump::tuples::{TupleVisitor, TupleWalker};
+use crate::synthesize::ColumnProfile;
+
+/// Read a dump and set the distribution of each `synthesize` column of the
+/// config, for an anonymization of the same dump to draw values from.
+/// Columns that are not in the dump get an empty distribution.
+pub fn profile_dump<R: Read>(config: &mut Config, reader: R) -> Result<(), String> {
+    let mut profiler = Profiler::new(config);
+    profiler.process(reader)?;
+    let mut profiles = profiler.profiles;
+
+    for (table_idx, table) in config.tables.iter_mut().enumerate() {
+        for (field_idx, field) in table.fields.iter_mut().enumerate() {
+            let Some(rule) = field.infos.synthesize.as_mut() else {
+                continue;
+            };
+            let profile = profiles.remove(&(table_idx, field_idx)).unwrap_or_default();
+            rule.profile = Some(profile.distribution(rule.categorical, rule.bins));
+            rule.column = field.name.clone();
+        }
+    }
+    Ok(())
+}
+
+/// First pass of the `synthesize` rules: collects the values of their
+/// columns, as `Reporter` collects the quasi-identifiers of its tables.
+struct Profiler<'a> {
+    config: &'a Config,
+    profiles: HashMap<(usize, usize), ColumnProfile>,
+    /// Config table of the CREATE TABLE or INSERT being read, if it has
+    /// `synthesize` columns
+    current: Option<usize>,
+    /// For each config table, the config fields to profile and their
+    /// positions in the dump tuples, once its CREATE TABLE has been read
+    positions: Vec<Option<Vec<(usize, usize)>>>,
+}
+
+impl<'a> Profiler<'a> {
+    fn new(config: &'a Config) -> Profiler<'a> {
+        Profiler {
+            config,
+            profiles: HashMap::new(),
+            current: None,
+            positions: vec![None; config.tables.len()],
+        }
+    }
+
+    fn process<R: Read>(&mut self, reader: R) -> Result<(), String> {
+        let reader = BufReader::with_capacity(65536, reader);
+        TupleWalker::new(self.config.charset).walk(reader, None::<&mut Vec<u8>>, self)
+    }
+
+    /// Config table of a dump table, as `DumpProcessor` finds it, when it
+    /// has `synthesize` columns
+    fn find_table(&self, name: &str) -> Option<usize> {
+        let idx = DumpProcessor::find_table_config(self.config, name)?;
+        let table = &self.config.tables[idx];
+        (table.action == TableAction::Anon
+            && table.fields.iter().any(|f| f.infos.anon_type == AnonType::Synthesize))
+        .then_some(idx)
+    }
+}
+
+impl TupleVisitor for Profiler<'_> {
+    fn create_table(&mut self, name: &str) -> bool {
+        self.current = self.find_table(name);
+        self.current.is_some()
+    }
+
+    fn columns(&mut self, columns: &[String]) {
+        let Some(idx) = self.current else { return };
+        let positions = self.config.tables[idx]
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.infos.anon_type == AnonType::Synthesize)
+            .filter_map(|(field_idx, f)| Some((field_idx, columns.iter().position(|c| *c == f.name)?)))
+            .collect();
+        self.positions[idx] = Some(positions);
+    }
+
+    fn insert(&mut self, name: &str) -> bool {
+        self.current = self.find_table(name);
+        self.current.is_some_and(|idx| self.positions[idx].is_some())
+    }
+
+    /// Add the values of the profiled columns
+    fn value(&mut self, field_pos: usize, token: ValueToken, raw: &[u8], charset: Charset) -> Option<&[u8]> {
+        let table_idx = self.current?;
+        let positions = self.positions[table_idx].as_deref().unwrap_or_default();
+        let &(field_idx, _) = positions.iter().find(|p| p.1 == field_pos)?;
+        if token != ValueToken::Null {
+            let quoted = token == ValueToken::Quoted;
+            let value = if quoted { remove_quote_slice(raw) } else { raw };
+            let profile = self.profiles.entry((table_idx, field_idx)).or_default();
+            // Rules work on UTF-8, as in `DumpProcessor::handle_value`
+            if charset.is_utf8() {
+                profile.add(value, quoted);
+            } else {
+                profile.add(charset.decode(value).as_bytes(), quoted);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Parser;
+    use crate::synthesize::Distribution;
+
+    #[test]
+    fn test_profile_dump() {
+        let mut config = Parser::new(
+            r#"
+            tables = {
+                `users` = {
+                    `status` = synthesize
+                    `age` = synthesize bins '4'
+                    `zip` = synthesize categorical
+                    `missing` = synthesize
+                }
+            }
+        "#,
+        )
+        .parse()
+        .unwrap();
+        let dump = b"CREATE TABLE `users` (\n  `id` int NOT NULL,\n  `age` int DEFAULT NULL,\n  `status` varchar(8) DEFAULT NULL,\n  `zip` int DEFAULT NULL\n) ENGINE=InnoDB DEFAULT CHARSET=latin1;\n\
+INSERT INTO `others` VALUES (1,99,'x',1);\n\
+INSERT INTO `users` VALUES (1,20,'active',75001),(2,30,'active',75001),\n(3,NULL,'clos\xe9',75002),(4,60,'it\\'s',75001);\n";
+        profile_dump(&mut config, &dump[..]).unwrap();
+
+        let rule = |i: usize| config.tables[0].fields[i].infos.synthesize.clone().unwrap();
+        assert_eq!(rule(0).column, "status");
+        assert_eq!(
+            rule(0).profile,
+            Some(Distribution::Categorical {
+                values: vec![b"active".to_vec(), b"clos\xc3\xa9".to_vec(), b"it\\'s".to_vec()],
+                cumulative: vec![2, 3, 4],
+            })
+        );
+        assert_eq!(
+            rule(1).profile,
+            Some(Distribution::Histogram {
+                min: 20.0,
+                max: 60.0,
+                cumulative: vec![1, 2, 2, 3],
+                decimals: 0,
+            })
+        );
+        assert_eq!(
+            rule(2).profile,
+            Some(Distribution::Categorical {
+                values: vec![b"75001".to_vec(), b"75002".to_vec()],
+                cumulative: vec![3, 4],
+            })
+        );
+        assert_eq!(
+            rule(3).profile,
+            Some(Distribution::Categorical {
+                values: vec![],
+                cumulative: vec![],
+            })
+        );
+    }
+}
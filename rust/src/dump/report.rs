@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+
+use crate::charset::Charset;
+use crate::config::{Config, ReportTable};
+use crate::dump::processor::ValueToken;
+use crate::dump::tuples::{TupleVisitor, TupleWalker};
+use crate::json::{json_to_string, JsonValue};
+
+/// Rows sharing the same quasi-identifier values
+#[derive(Default)]
+struct EquivalenceClass {
+    rows: u64,
+    /// Distinct values of each sensitive column
+    sensitive: Vec<HashSet<Vec<u8>>>,
+}
+
+/// Rows of a report table read so far
+#[derive(Default)]
+struct TableStats {
+    /// Dump positions of the quasi-identifier columns, then of the sensitive
+    /// ones; `None` until the CREATE TABLE has been read
+    positions: Option<Vec<usize>>,
+    rows: u64,
+    classes: HashMap<Vec<Vec<u8>>, EquivalenceClass>,
+}
+
+/// Re-identification risk of a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableReport {
+    pub table: String,
+    pub quasi: Vec<String>,
+    /// False when the table, or one of its columns, is not in the dump
+    pub found: bool,
+    pub rows: u64,
+    pub classes: u64,
+    /// Size of the smallest equivalence class, `None` without rows
+    pub k: Option<u64>,
+    /// Rows alone in their equivalence class (k=1)
+    pub unique_rows: u64,
+    /// For each sensitive column, the smallest number of distinct values in
+    /// an equivalence class
+    pub l_diversity: Vec<(String, Option<u64>)>,
+}
+
+/// Reads a dump, typically the output of an anonymization, and groups the
+/// rows of the `report = { ... }` tables by their quasi-identifiers.
+pub struct Reporter<'a> {
+    tables: &'a [ReportTable],
+    stats: Vec<TableStats>,
+    /// Report table of the CREATE TABLE or INSERT being read
+    current: Option<usize>,
+    row: Vec<Vec<u8>>,
+    charset: Option<Charset>,
+}
+
+impl<'a> Reporter<'a> {
+    pub fn new(config: &'a Config) -> Reporter<'a> {
+        Reporter {
+            tables: &config.report,
+            stats: config.report.iter().map(|_| TableStats::default()).collect(),
+            current: None,
+            row: Vec::new(),
+            charset: config.charset,
+        }
+    }
+
+    pub fn process<R: Read>(&mut self, reader: R) -> Result<(), String> {
+        let reader = BufReader::with_capacity(65536, reader);
+        TupleWalker::new(self.charset).walk(reader, None::<&mut Vec<u8>>, self)
+    }
+
+    fn add_row(&mut self) {
+        let Some(idx) = self.current else { return };
+        let nb_quasi = self.tables[idx].quasi.len();
+        let stats = &mut self.stats[idx];
+        let Some(positions) = &stats.positions else { return };
+        let value = |pos: &usize| self.row.get(*pos).cloned().unwrap_or_default();
+
+        let key: Vec<Vec<u8>> = positions[..nb_quasi].iter().map(value).collect();
+        let class = stats.classes.entry(key).or_default();
+        class.rows += 1;
+        class
+            .sensitive
+            .resize_with(positions.len() - nb_quasi, HashSet::new);
+        for (values, pos) in class.sensitive.iter_mut().zip(&positions[nb_quasi..]) {
+            values.insert(value(pos));
+        }
+        stats.rows += 1;
+    }
+
+    /// Risk measures of each report table
+    pub fn report(&self) -> Vec<TableReport> {
+        self.tables
+            .iter()
+            .zip(&self.stats)
+            .map(|(table, stats)| TableReport {
+                table: table.name.clone(),
+                quasi: table.quasi.clone(),
+                found: stats.positions.is_some(),
+                rows: stats.rows,
+                classes: stats.classes.len() as u64,
+                k: stats.classes.values().map(|c| c.rows).min(),
+                unique_rows: stats.classes.values().filter(|c| c.rows == 1).count() as u64,
+                l_diversity: table
+                    .sensitive
+                    .iter()
+                    .enumerate()
+                    .map(|(i, column)| {
+                        let l = stats
+                            .classes
+                            .values()
+                            .map(|c| c.sensitive.get(i).map_or(0, |v| v.len() as u64))
+                            .min();
+                        (column.clone(), l)
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+impl TupleVisitor for Reporter<'_> {
+    fn create_table(&mut self, name: &str) -> bool {
+        self.current = self.tables.iter().position(|t| t.name == name);
+        self.current.is_some()
+    }
+
+    fn columns(&mut self, columns: &[String]) {
+        let Some(idx) = self.current else { return };
+        let table = &self.tables[idx];
+        let positions: Option<Vec<usize>> = table
+            .quasi
+            .iter()
+            .chain(&table.sensitive)
+            .map(|column| columns.iter().position(|c| c == column))
+            .collect();
+        if positions.is_none() {
+            eprintln!(
+                "WARNING! Table {}: a report column from config file has not been found in dump. Maybe a config file error?",
+                table.name
+            );
+        }
+        self.stats[idx].positions = positions;
+    }
+
+    fn insert(&mut self, name: &str) -> bool {
+        self.current = self.tables.iter().position(|t| t.name == name);
+        self.current.is_some_and(|idx| self.stats[idx].positions.is_some())
+    }
+
+    fn value(&mut self, _field_pos: usize, _token: ValueToken, raw: &[u8], _charset: Charset) -> Option<&[u8]> {
+        self.row.push(raw.to_vec());
+        None
+    }
+
+    fn end_tuple(&mut self) {
+        self.add_row();
+        self.row.clear();
+    }
+}
+
+/// Report as a JSON document
+pub fn report_to_json(reports: &[TableReport]) -> String {
+    let optional = |n: Option<u64>| n.map_or(JsonValue::Null, |n| JsonValue::Int(n as i64));
+    let tables = reports
+        .iter()
+        .map(|r| {
+            JsonValue::Object(vec![
+                ("table".to_string(), JsonValue::String(r.table.clone())),
+                (
+                    "quasi_identifiers".to_string(),
+                    JsonValue::Array(r.quasi.iter().cloned().map(JsonValue::String).collect()),
+                ),
+                ("found".to_string(), JsonValue::Bool(r.found)),
+                ("rows".to_string(), JsonValue::Int(r.rows as i64)),
+                ("equivalence_classes".to_string(), JsonValue::Int(r.classes as i64)),
+                ("k".to_string(), optional(r.k)),
+                ("unique_rows".to_string(), JsonValue::Int(r.unique_rows as i64)),
+                (
+                    "l_diversity".to_string(),
+                    JsonValue::Object(
+                        r.l_diversity
+                            .iter()
+                            .map(|(column, l)| (column.clone(), optional(*l)))
+                            .collect(),
+                    ),
+                ),
+            ])
+        })
+        .collect();
+    json_to_string(&JsonValue::Object(vec![("tables".to_string(), JsonValue::Array(tables))]))
+}
+
+/// Report as text, one block per table
+pub fn report_to_text(reports: &[TableReport]) -> String {
+    let mut text = String::new();
+    for r in reports {
+        if !r.found {
+            text.push_str(&format!("Table {}: not found in dump\n", r.table));
+            continue;
+        }
+        text.push_str(&format!("Table {}: {} row(s)\n", r.table, r.rows));
+        text.push_str(&format!("  Quasi-identifiers: {}\n", r.quasi.join(", ")));
+        let k = r.k.map_or("-".to_string(), |k| k.to_string());
+        text.push_str(&format!("  k-anonymity: {}\n", k));
+        text.push_str(&format!("  Equivalence classes: {}\n", r.classes));
+        text.push_str(&format!("  Unique rows (k=1): {}\n", r.unique_rows));
+        for (column, l) in &r.l_diversity {
+            let l = l.map_or("-".to_string(), |l| l.to_string());
+            text.push_str(&format!("  l-diversity of {}: {}\n", column, l));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Parser;
+
+    const CONFIG: &str = r#"
+        report = {
+            `patients` = {
+                `zip` = quasi
+                `birth_year` = quasi
+                `diagnosis` = sensitive
+            }
+            `visits` = {
+                `date` = quasi
+            }
+        }
+    "#;
+
+    fn report(dump: &str) -> Vec<TableReport> {
+        let config = Parser::new(CONFIG).parse().unwrap();
+        let mut reporter = Reporter::new(&config);
+        reporter.process(dump.as_bytes()).unwrap();
+        reporter.report()
+    }
+
+    const DUMP: &str = "CREATE TABLE `patients` (\n  `id` int NOT NULL,\n  `zip` varchar(5),\n  `birth_year` int,\n  `diagnosis` varchar(20)\n) ENGINE=InnoDB;\n\
+INSERT INTO `patients` VALUES (1,'75000',1980,'flu'),(2,'75000',1980,'asthma'),(3,'75000',1980,'flu');\n\
+INSERT INTO `patients` VALUES\n(4,'69000',1990,'flu'),\n(5,'69000',1990,'flu'),\n(6,NULL,1990,'it''s, (rare)');\n";
+
+    #[test]
+    fn test_k_and_l() {
+        let reports = report(DUMP);
+        assert_eq!(
+            reports[0],
+            TableReport {
+                table: "patients".to_string(),
+                quasi: vec!["zip".to_string(), "birth_year".to_string()],
+                found: true,
+                rows: 6,
+                classes: 3,
+                k: Some(1),
+                unique_rows: 1,
+                l_diversity: vec![("diagnosis".to_string(), Some(1))],
+            }
+        );
+        assert!(!reports[1].found);
+        assert_eq!(reports[1].k, None);
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let reports = report(DUMP);
+        assert_eq!(
+            report_to_text(&reports),
+            "Table patients: 6 row(s)\n  Quasi-identifiers: zip, birth_year\n  k-anonymity: 1\n  \
+             Equivalence classes: 3\n  Unique rows (k=1): 1\n  l-diversity of diagnosis: 1\n\
+             Table visits: not found in dump\n"
+        );
+        let json = report_to_json(&reports);
+        assert!(json.starts_with(r#"{"tables":[{"table":"patients","quasi_identifiers":["zip","birth_year"],"found":true,"rows":6,"equivalence_classes":3,"k":1,"unique_rows":1,"l_diversity":{"diagnosis":1}}"#), "{}", json);
+        assert!(json.contains(r#""k":null"#), "{}", json);
+    }
+
+    #[test]
+    fn test_latin1_identifiers() {
+        let config = Parser::new("report = { `élèves` = { `région` = quasi } }").parse().unwrap();
+        let dump = b"/*!40101 SET NAMES latin1 */;\nCREATE TABLE `\xe9l\xe8ves` (\n  `id` int NOT NULL,\n  `r\xe9gion` varchar(20)\n) ENGINE=InnoDB;\n\
+INSERT INTO `\xe9l\xe8ves` VALUES (1,'Nord'),(2,'Nord'),(3,'Sud');\n";
+        let mut reporter = Reporter::new(&config);
+        reporter.process(&dump[..]).unwrap();
+        let reports = reporter.report();
+        assert!(reports[0].found);
+        assert_eq!((reports[0].rows, reports[0].k), (3, Some(1)));
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let reports = report(&DUMP.replace("`birth_year`", "`born`"));
+        assert!(!reports[0].found);
+        assert_eq!(reports[0].rows, 0);
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Seek, SeekFrom, Write};
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use aes::Aes128;
+
+use crate::anonymize::hmac_digest;
+use crate::charset::Charset;
+use crate::dump::processor::ValueToken;
+use crate::dump::tuples::{TupleVisitor, TupleWalker};
+
+/// Column of a table with a `shuffle` rule
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ShuffleColumn {
+    /// Position of the column in the dump tuples
+    pub pos: usize,
+    /// Columns of a same group get the same permutation
+    pub group: String,
+}
+
+/// Secret-seeded permutation of `0..n`: Fisher-Yates driven by an AES-CTR
+/// keystream whose key is the HMAC of the table, the group and `n`.
+fn permutation(n: usize, table: &str, group: &str, secret: &[u8]) -> Vec<usize> {
+    let seed = hmac_digest(format!("shuffle\0{}\0{}\0{}", table, group, n).as_bytes(), secret);
+    let mut keystream = ctr::Ctr128BE::<Aes128>::new(seed[..16].into(), &[0u8; 16].into());
+    let mut perm: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        let mut random = [0u8; 8];
+        keystream.apply_keystream(&mut random);
+        let j = ((u64::from_le_bytes(random) as u128 * (i as u128 + 1)) >> 64) as usize;
+        perm.swap(i, j);
+    }
+    perm
+}
+
+/// Output of a table with `shuffle` rules, from its CREATE TABLE to the
+/// next one: every value of a shuffled column may move to any row, so
+/// nothing can be written before the last row has been read. The output is
+/// spooled to a temporary file, which is read twice when the table ends:
+/// once to collect the values of the shuffled columns (the only data kept
+/// in memory), once to write it with these values permuted.
+pub(crate) struct ShuffleSpool {
+    file: BufWriter<File>,
+    pub table_idx: usize,
+}
+
+impl ShuffleSpool {
+    pub fn new(table_idx: usize) -> Result<ShuffleSpool, String> {
+        let file = tempfile::tempfile().map_err(|e| format!("Unable to create shuffle spool file: {}", e))?;
+        Ok(ShuffleSpool {
+            file: BufWriter::with_capacity(65536, file),
+            table_idx,
+        })
+    }
+
+    /// Write the spooled output, with the values of `columns` permuted
+    /// among the rows of `table`.
+    pub fn finish<W: Write>(
+        self,
+        table: &str,
+        charset: Charset,
+        columns: &[ShuffleColumn],
+        secret: &[u8],
+        writer: &mut W,
+    ) -> Result<(), String> {
+        let mut file = self
+            .file
+            .into_inner()
+            .map_err(|e| format!("Shuffle spool write error: {}", e.error()))?;
+
+        // Pass 1: values of the shuffled columns, in row order
+        file.seek(SeekFrom::Start(0)).map_err(|e| format!("Shuffle spool read error: {}", e))?;
+        let mut shuffled = ShuffledValues {
+            table,
+            columns,
+            values: vec![Vec::new(); columns.len()],
+            perms: Vec::new(),
+            next: vec![0; columns.len()],
+        };
+        TupleWalker::new(Some(charset)).walk(BufReader::new(&mut file), None::<&mut W>, &mut shuffled)?;
+
+        // The columns of a group share their permutation
+        let mut perms: HashMap<(&str, usize), Vec<usize>> = HashMap::new();
+        for (column, column_values) in columns.iter().zip(&shuffled.values) {
+            let n = column_values.len();
+            perms
+                .entry((column.group.as_str(), n))
+                .or_insert_with(|| permutation(n, table, &column.group, secret));
+        }
+        shuffled.perms = columns
+            .iter()
+            .zip(&shuffled.values)
+            .map(|(column, column_values)| perms[&(column.group.as_str(), column_values.len())].clone())
+            .collect();
+
+        // Pass 2: the spooled output, each shuffled value replaced
+        file.seek(SeekFrom::Start(0)).map_err(|e| format!("Shuffle spool read error: {}", e))?;
+        TupleWalker::new(Some(charset)).walk(BufReader::new(&mut file), Some(writer), &mut shuffled)
+    }
+}
+
+impl Write for ShuffleSpool {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Values of the shuffled columns of a table in the spooled output:
+/// collected by a first walk, then written permuted by a second one, once
+/// `perms` is set.
+struct ShuffledValues<'c> {
+    table: &'c str,
+    columns: &'c [ShuffleColumn],
+    /// For each column, its values in row order
+    values: Vec<Vec<Vec<u8>>>,
+    /// For each column, the row whose value goes to each row
+    perms: Vec<Vec<usize>>,
+    /// For each column, the next row to write
+    next: Vec<usize>,
+}
+
+impl TupleVisitor for ShuffledValues<'_> {
+    fn create_table(&mut self, _name: &str) -> bool {
+        false
+    }
+
+    fn insert(&mut self, name: &str) -> bool {
+        name == self.table
+    }
+
+    fn value(&mut self, field_pos: usize, token: ValueToken, raw: &[u8], _charset: Charset) -> Option<&[u8]> {
+        let col = self.columns.iter().position(|c| c.pos == field_pos)?;
+        if self.perms.is_empty() {
+            // Shuffled values move with their quotes, NULL included
+            let raw = if token == ValueToken::Null { &b"NULL"[..] } else { raw };
+            self.values[col].push(raw.to_vec());
+            return None;
+        }
+        let row = self.perms[col].get(self.next[col]).copied()?;
+        self.next[col] += 1;
+        self.values[col].get(row).map(Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permutation() {
+        let perm = permutation(1000, "users", "city", b"secret");
+        let mut sorted = perm.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..1000).collect::<Vec<_>>());
+        assert!(perm.iter().enumerate().filter(|(i, p)| i == *p).count() < 10);
+        assert_eq!(permutation(1000, "users", "city", b"secret"), perm);
+        assert_ne!(permutation(1000, "users", "job", b"secret"), perm);
+        assert_ne!(permutation(1000, "users", "city", b"other"), perm);
+        assert_eq!(permutation(0, "t", "c", b"s"), Vec::<usize>::new());
+        assert_eq!(permutation(1, "t", "c", b"s"), vec![0]);
+    }
+
+    #[test]
+    fn test_spool() {
+        let columns = vec![
+            ShuffleColumn { pos: 1, group: "address".to_string() },
+            ShuffleColumn { pos: 2, group: "address".to_string() },
+            ShuffleColumn { pos: 3, group: "job".to_string() },
+        ];
+        let mut spool = ShuffleSpool::new(0).unwrap();
+        spool.write_all(b"LOCK TABLES `people` WRITE;\nINSERT INTO `people` VALUES ").unwrap();
+        for i in 0..50 {
+            let sep = if i == 0 { "" } else { "," };
+            let row = format!("{}({},'city{}','{:05}','job, {}')", sep, i, i, i, i);
+            spool.write_all(row.as_bytes()).unwrap();
+        }
+        spool
+            .write_all(b";\nINSERT INTO `people` VALUES\n(50,NULL,NULL,'it\\'s (50)');\nINSERT INTO `other` VALUES (1,'x','y','z');\nUNLOCK TABLES;\n")
+            .unwrap();
+
+        let mut out = Vec::new();
+        spool.finish("people", Charset::Utf8, &columns, b"secret", &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("LOCK TABLES `people` WRITE;\nINSERT INTO `people` VALUES ("), "{}", out);
+        assert!(out.ends_with(");\nINSERT INTO `other` VALUES (1,'x','y','z');\nUNLOCK TABLES;\n"), "{}", out);
+
+        let re = regex::Regex::new(r"\((\d+),(NULL|'city(\d+)'),(NULL|'(\d+)'),'(?:job, \d+|it\\'s \(50\))'\)").unwrap();
+        let rows: Vec<_> = re.captures_iter(&out).take(51).collect();
+        assert_eq!(rows.len(), 51);
+        let mut moved = 0;
+        let mut cities = Vec::new();
+        for row in &rows {
+            // City and zip stay together
+            assert_eq!(row.get(3).map(|m| m.as_str().parse::<u32>().unwrap()), row.get(5).map(|m| m.as_str().parse().unwrap()));
+            let id = &row[1];
+            if row.get(3).map(|m| m.as_str()) != Some(id) {
+                moved += 1;
+            }
+            cities.push(row.get(3).map(|m| m.as_str().to_string()));
+        }
+        assert!(moved > 40, "{}", moved);
+        cities.sort();
+        let mut expected: Vec<_> = (0..50).map(|i| Some(i.to_string())).chain([None]).collect();
+        expected.sort();
+        assert_eq!(cities, expected);
+    }
+}
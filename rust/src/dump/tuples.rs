@@ -0,0 +1,300 @@
+use std::io::{BufRead, Write};
+
+use crate::charset::Charset;
+use crate::dump::processor::{scan_value, DumpProcessor, ValueToken};
+
+/// Charsets in effect while reading a dump. The config charset wins, then
+/// the connection charset (`SET NAMES`), as mysqldump converts values to
+/// it, then, for values, the charset of the table.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DumpCharsets {
+    /// Charset forced by the config
+    pub forced: Option<Charset>,
+    /// Connection charset, from `SET NAMES`
+    pub dump: Option<Charset>,
+    /// Charset of the current table, from its `CREATE TABLE`
+    pub table: Option<Charset>,
+}
+
+impl DumpCharsets {
+    /// Charset of identifiers in CREATE TABLE and INSERT statements
+    pub fn identifier(&self) -> Charset {
+        self.forced.or(self.dump).unwrap_or(Charset::Utf8)
+    }
+
+    /// Charset of the values of the current table
+    pub fn value(&self) -> Charset {
+        self.forced
+            .or(self.dump)
+            .or(self.table)
+            .unwrap_or(Charset::Utf8)
+    }
+
+    /// Charset name set by `line`, if it is a `SET NAMES` statement
+    pub fn set_names(line: &[u8]) -> Option<String> {
+        if !(line.starts_with(b"/*!") || line.starts_with(b"SET NAMES")) {
+            return None;
+        }
+        let text = String::from_utf8_lossy(line);
+        DumpProcessor::parse_charset_name(&text, "SET NAMES ").map(str::to_string)
+    }
+}
+
+/// Part of the values of an INSERT statement, as cut by `TupleScanner`
+#[derive(Debug, PartialEq)]
+pub(crate) enum TuplePart {
+    /// `(` starting a tuple
+    Open,
+    /// `)` ending a tuple
+    Close,
+    /// Value at position `field_pos` of the tuple
+    Value { field_pos: usize, token: ValueToken },
+    /// `;` ending the statement
+    End,
+    /// Anything else: commas, spaces, newlines
+    Other,
+}
+
+/// Cuts the values of an INSERT statement into tuples and values. Its state
+/// carries over from one line to the next, for statements spanning lines.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TupleScanner {
+    in_tuple: bool,
+    field_pos: usize,
+}
+
+impl TupleScanner {
+    /// Whether the last part read is inside a tuple
+    pub fn in_tuple(&self) -> bool {
+        self.in_tuple
+    }
+
+    /// Part of `bytes` starting at `pos`, and where it ends
+    pub fn next(&mut self, bytes: &[u8], pos: usize, line_nb: usize) -> Result<(TuplePart, usize), String> {
+        let part = match bytes[pos] {
+            b'(' if !self.in_tuple => {
+                self.in_tuple = true;
+                self.field_pos = 0;
+                TuplePart::Open
+            }
+            b')' if self.in_tuple => {
+                self.in_tuple = false;
+                TuplePart::Close
+            }
+            b',' if self.in_tuple => {
+                self.field_pos += 1;
+                TuplePart::Other
+            }
+            b';' if !self.in_tuple => TuplePart::End,
+            b',' | b' ' | b'\n' | b'\r' => TuplePart::Other,
+            _ if self.in_tuple => {
+                let (token, end) = scan_value(bytes, pos, line_nb)?;
+                let part = TuplePart::Value {
+                    field_pos: self.field_pos,
+                    token,
+                };
+                return Ok((part, end));
+            }
+            _ => TuplePart::Other,
+        };
+        Ok((part, pos + 1))
+    }
+}
+
+/// What a `TupleWalker` reports of the statements it walks.
+pub(crate) trait TupleVisitor {
+    /// Whether to read the columns of the table created by a CREATE TABLE
+    fn create_table(&mut self, name: &str) -> bool;
+
+    /// Columns of a table accepted by `create_table`, at the end of its
+    /// CREATE TABLE
+    fn columns(&mut self, _columns: &[String]) {}
+
+    /// Whether to walk the tuples of an INSERT (or REPLACE) into the table
+    fn insert(&mut self, name: &str) -> bool;
+
+    /// Value at position `field_pos` of a tuple, `raw` as found in the dump
+    /// (quotes and escapes included), its values decoded with `charset`.
+    /// Returns the replacement of the value in the output, `None` to keep it.
+    fn value(&mut self, field_pos: usize, token: ValueToken, raw: &[u8], charset: Charset) -> Option<&[u8]>;
+
+    /// End of a tuple
+    fn end_tuple(&mut self) {}
+}
+
+/// Walks the CREATE TABLE and INSERT statements of a dump, as
+/// `DumpProcessor` does, for the passes that only read it (`report`,
+/// `synthesize` profiles) or only move values around (`shuffle`).
+pub(crate) struct TupleWalker {
+    charsets: DumpCharsets,
+    in_create: bool,
+    in_values: bool,
+    scanner: TupleScanner,
+    columns: Vec<String>,
+    line_nb: usize,
+}
+
+impl TupleWalker {
+    /// `charset` is the charset forced by the config, if any.
+    pub fn new(charset: Option<Charset>) -> TupleWalker {
+        TupleWalker {
+            charsets: DumpCharsets {
+                forced: charset,
+                ..DumpCharsets::default()
+            },
+            in_create: false,
+            in_values: false,
+            scanner: TupleScanner::default(),
+            columns: Vec::new(),
+            line_nb: 1,
+        }
+    }
+
+    /// Walk the dump read from `reader`, copying it to `writer`, if any,
+    /// with the values replaced by `visitor`.
+    pub fn walk<R: BufRead, W: Write>(
+        &mut self,
+        mut reader: R,
+        mut writer: Option<&mut W>,
+        visitor: &mut impl TupleVisitor,
+    ) -> Result<(), String> {
+        let mut line = Vec::with_capacity(8192);
+        loop {
+            line.clear();
+            let bytes_read = reader
+                .read_until(b'\n', &mut line)
+                .map_err(|e| format!("Read error: {}", e))?;
+            if bytes_read == 0 {
+                break;
+            }
+            let start = if self.in_values { Some(0) } else { self.process_statement(&line, visitor) };
+            match start {
+                Some(start) => self.walk_values(&line, start, writer.as_deref_mut(), visitor)?,
+                None => {
+                    if let Some(w) = writer.as_mut() {
+                        w.write_all(&line).map_err(|e| e.to_string())?;
+                    }
+                }
+            }
+            self.line_nb += 1;
+        }
+        Ok(())
+    }
+
+    /// Follow a line outside of INSERT values. Returns where its tuples
+    /// start when it begins a walked INSERT.
+    fn process_statement(&mut self, line: &[u8], visitor: &mut impl TupleVisitor) -> Option<usize> {
+        if self.in_create {
+            let text = self.charsets.identifier().decode(line);
+            let trimmed = text.trim_start();
+            if let Some(stripped) = trimmed.strip_prefix('`') {
+                if let Some(end) = stripped.find('`') {
+                    self.columns.push(stripped[..end].to_string());
+                }
+            } else if trimmed.starts_with(')') {
+                self.in_create = false;
+                if let Some(name) = DumpProcessor::parse_charset_name(trimmed, "CHARSET=") {
+                    self.charsets.table = Charset::from_mysql_name(name);
+                }
+                visitor.columns(&self.columns);
+            }
+            return None;
+        }
+
+        if line.starts_with(b"CREATE TABLE `") {
+            let name = DumpProcessor::extract_table_name_bytes(line, self.charsets.identifier())?;
+            self.in_create = visitor.create_table(&name);
+            self.columns.clear();
+            self.charsets.table = None;
+        } else if line.starts_with(b"INSERT ") || line.starts_with(b"REPLACE ") {
+            let name = DumpProcessor::extract_table_name_bytes(line, self.charsets.identifier())?;
+            if visitor.insert(&name) {
+                let values_pos = line.windows(7).position(|w| w == b" VALUES")?;
+                self.in_values = true;
+                self.scanner = TupleScanner::default();
+                return Some(values_pos + 7);
+            }
+        } else if let Some(name) = DumpCharsets::set_names(line) {
+            self.charsets.dump = Charset::from_mysql_name(&name).or(self.charsets.dump);
+        }
+        None
+    }
+
+    /// Walk the tuples of an INSERT statement from `start`.
+    fn walk_values<W: Write>(
+        &mut self,
+        line: &[u8],
+        start: usize,
+        mut writer: Option<&mut W>,
+        visitor: &mut impl TupleVisitor,
+    ) -> Result<(), String> {
+        let charset = self.charsets.value();
+        let mut pos = start;
+        let mut copied = 0;
+        while pos < line.len() {
+            let (part, end) = self.scanner.next(line, pos, self.line_nb)?;
+            match part {
+                TuplePart::Close => visitor.end_tuple(),
+                TuplePart::End => {
+                    self.in_values = false;
+                    break;
+                }
+                TuplePart::Value { field_pos, token } => {
+                    if let Some(replacement) = visitor.value(field_pos, token, &line[pos..end], charset) {
+                        if let Some(w) = writer.as_mut() {
+                            w.write_all(&line[copied..pos]).map_err(|e| e.to_string())?;
+                            w.write_all(replacement).map_err(|e| e.to_string())?;
+                        }
+                        copied = end;
+                    }
+                }
+                TuplePart::Open | TuplePart::Other => {}
+            }
+            pos = end;
+        }
+        if let Some(w) = writer.as_mut() {
+            w.write_all(&line[copied..]).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(lines: &[&str]) -> Vec<(TuplePart, String)> {
+        let mut scanner = TupleScanner::default();
+        let mut parts = Vec::new();
+        for line in lines {
+            let mut pos = 0;
+            while pos < line.len() {
+                let (part, end) = scanner.next(line.as_bytes(), pos, 1).unwrap();
+                if part != TuplePart::Other {
+                    parts.push((part, line[pos..end].to_string()));
+                }
+                pos = end;
+            }
+        }
+        parts
+    }
+
+    #[test]
+    fn test_scanner_parts() {
+        let value = |field_pos, token| TuplePart::Value { field_pos, token };
+        assert_eq!(
+            parts(&["(1,'a)b;c'),\n", "(NULL, 0x1F);\n"]),
+            vec![
+                (TuplePart::Open, "(".to_string()),
+                (value(0, ValueToken::Unquoted), "1".to_string()),
+                (value(1, ValueToken::Quoted), "'a)b;c'".to_string()),
+                (TuplePart::Close, ")".to_string()),
+                (TuplePart::Open, "(".to_string()),
+                (value(0, ValueToken::Null), "NULL".to_string()),
+                (value(1, ValueToken::Raw), "0x1F".to_string()),
+                (TuplePart::Close, ")".to_string()),
+                (TuplePart::End, ";".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::anonymize::make_readable_hash_into;
+use crate::config::MAX_LEN;
+
+/// Length of the hashed label put before the configured domain by `hashdomain`
+const DOMAIN_HASH_LEN: usize = 8;
+
+/// Options of an `emailhash` rule, written after its length
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmailOptions {
+    /// `normalize`: trim and lowercase the address before hashing
+    pub normalize: bool,
+    /// `striptags`: drop the `+tag` of the local part before hashing
+    pub strip_tags: bool,
+    /// `keepdomain '...'`: domains kept as they are, compared without case
+    pub keep_domains: Vec<String>,
+    /// `hashdomain`: other domains replaced by a hash of themselves, under
+    /// the configured domain
+    pub hash_domain: bool,
+    /// `keepshape`: keep the `.`, `_` and `-` of the local part where they are
+    pub keep_shape: bool,
+}
+
+impl EmailOptions {
+    fn is_set(&self) -> bool {
+        *self != EmailOptions::default()
+    }
+}
+
+/// Hash an email address: a `len`-letter local part derived from the HMAC of
+/// the address, then `@domain`. Without options, the raw value is hashed,
+/// as in the C version.
+pub fn emailhash_into(
+    out: &mut Vec<u8>,
+    token: &[u8],
+    secret: &[u8],
+    len: usize,
+    domain: &str,
+    options: &EmailOptions,
+) {
+    if !options.is_set() {
+        make_readable_hash_into(out, token, secret, len, b'a', b'z');
+        out.push(b'@');
+        out.extend_from_slice(domain.as_bytes());
+        return;
+    }
+
+    let text = String::from_utf8_lossy(token);
+    let address = if options.normalize {
+        text.trim().to_lowercase()
+    } else {
+        text.into_owned()
+    };
+    let (mut local, original_domain) = match address.rsplit_once('@') {
+        Some((local, domain)) => (local, Some(domain)),
+        None => (address.as_str(), None),
+    };
+    if options.strip_tags {
+        local = local.split_once('+').map_or(local, |(local, _)| local);
+    }
+    let hashed = match original_domain {
+        Some(original) => format!("{}@{}", local, original),
+        None => local.to_string(),
+    };
+
+    // The shape is in characters: `zoé.x` becomes three letters, a dot and a letter
+    let separators = local.chars().filter(|c| matches!(c, '.' | '_' | '-')).count();
+    let letters = local.chars().count() - separators;
+    if options.keep_shape && separators > 0 && letters <= MAX_LEN as usize {
+        let mut hash = Vec::with_capacity(letters);
+        make_readable_hash_into(&mut hash, hashed.as_bytes(), secret, letters, b'a', b'z');
+        let mut hash = hash.into_iter();
+        for c in local.chars() {
+            match c {
+                '.' | '_' | '-' => out.push(c as u8),
+                _ => out.extend(hash.next()),
+            }
+        }
+    } else {
+        make_readable_hash_into(out, hashed.as_bytes(), secret, len, b'a', b'z');
+    }
+
+    out.push(b'@');
+    match original_domain {
+        Some(original) if options.keep_domains.iter().any(|d| d.eq_ignore_ascii_case(original)) => {
+            out.extend_from_slice(original.as_bytes());
+        }
+        Some(original) if options.hash_domain => {
+            let lowered = original.to_lowercase();
+            make_readable_hash_into(out, lowered.as_bytes(), secret, DOMAIN_HASH_LEN, b'a', b'z');
+            out.push(b'.');
+            out.extend_from_slice(domain.as_bytes());
+        }
+        _ => out.extend_from_slice(domain.as_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emailhash(token: &str, options: &EmailOptions) -> String {
+        let mut out = Vec::new();
+        emailhash_into(&mut out, token.as_bytes(), b"secret", 10, "example.com", options);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_without_options() {
+        // Same result as the C version: the raw value is hashed
+        let mut expected = Vec::new();
+        make_readable_hash_into(&mut expected, b"Alice@Corp.com", b"secret", 10, b'a', b'z');
+        expected.extend_from_slice(b"@example.com");
+        assert_eq!(emailhash("Alice@Corp.com", &EmailOptions::default()).as_bytes(), expected);
+        assert_ne!(
+            emailhash("Alice@Corp.com", &EmailOptions::default()),
+            emailhash("alice@corp.com", &EmailOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_normalize_and_tags() {
+        let options = EmailOptions {
+            normalize: true,
+            strip_tags: true,
+            ..Default::default()
+        };
+        let hash = emailhash("alice@corp.com", &options);
+        assert_eq!(emailhash(" Alice@Corp.COM ", &options), hash);
+        assert_eq!(emailhash("alice+newsletter@corp.com", &options), hash);
+        assert_ne!(emailhash("alice@other.com", &options), hash);
+        assert_eq!(hash.len(), "aaaaaaaaaa@example.com".len());
+    }
+
+    #[test]
+    fn test_domains() {
+        let options = EmailOptions {
+            keep_domains: vec!["example.org".to_string()],
+            hash_domain: true,
+            ..Default::default()
+        };
+        let qa = emailhash("qa.tester@Example.org", &options);
+        assert!(qa.ends_with("@Example.org"), "{}", qa);
+        assert_ne!(&qa[..9], "qa.tester");
+
+        let a = emailhash("alice@corp.com", &options);
+        let b = emailhash("bob@corp.com", &options);
+        let (_, domain_a) = a.split_once('@').unwrap();
+        let (_, domain_b) = b.split_once('@').unwrap();
+        assert_eq!(domain_a, domain_b);
+        assert_eq!(domain_a.len(), DOMAIN_HASH_LEN + ".example.com".len());
+        assert!(domain_a.ends_with(".example.com"));
+        assert_ne!(emailhash("carol@gmail.com", &options).split_once('@').unwrap().1, domain_a);
+    }
+
+    #[test]
+    fn test_keep_shape() {
+        let options = EmailOptions {
+            keep_shape: true,
+            ..Default::default()
+        };
+        let hash = emailhash("john.smith-jr@corp.com", &options);
+        let (local, domain) = hash.split_once('@').unwrap();
+        assert_eq!(domain, "example.com");
+        assert_eq!(local.len(), "john.smith-jr".len());
+        assert_eq!((&local[4..5], &local[10..11]), (".", "-"));
+        assert_ne!(&local[..4], "john");
+        // One letter per character
+        let hash = emailhash("zoé.x@corp.com", &options);
+        assert_eq!(hash.split_once('@').unwrap().0.len(), "zoe.x".len());
+        assert_eq!(&hash[3..4], ".");
+        // Without separators, the configured length is used
+        assert_eq!(emailhash("johnsmith@corp.com", &options).len(), "aaaaaaaaaa@example.com".len());
+    }
+}
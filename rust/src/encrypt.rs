@@ -0,0 +1,356 @@
+use aes::cipher::{KeyInit, KeyIvInit, StreamCipher};
+use aes::Aes256;
+use cmac::{Cmac, Mac};
+
+use crate::anonymize::{hmac_digest, mysql_backslash_escape_into, mysql_unescape_bytes};
+use crate::config::{AnonBase, AnonField, AnonTable, AnonType, Config, TableAction};
+
+type Block = [u8; 16];
+type Aes256Ctr = ctr::Ctr128BE<Aes256>;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// AES-SIV key (RFC 5297, AES-256 for both halves), derived from a named
+/// secret of the `keys` block.
+#[derive(Clone, PartialEq)]
+pub struct SivKey {
+    mac: [u8; 32],
+    enc: [u8; 32],
+}
+
+impl std::fmt::Debug for SivKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SivKey(..)")
+    }
+}
+
+/// Doubling in GF(2^128), as in CMAC
+fn dbl(block: Block) -> Block {
+    let value = u128::from_be_bytes(block);
+    let carry = if value >> 127 == 1 { 0x87 } else { 0 };
+    ((value << 1) ^ carry).to_be_bytes()
+}
+
+fn xor(a: Block, b: Block) -> Block {
+    (u128::from_be_bytes(a) ^ u128::from_be_bytes(b)).to_be_bytes()
+}
+
+/// S2V of RFC 5297 over the CMAC function `cmac`
+fn s2v(cmac: &dyn Fn(&[u8]) -> Block, ad: &[&[u8]], plaintext: &[u8]) -> Block {
+    let mut d = cmac(&[0u8; 16]);
+    for a in ad {
+        d = xor(dbl(d), cmac(a));
+    }
+    if plaintext.len() >= 16 {
+        let mut t = plaintext.to_vec();
+        let end = t.len() - 16;
+        for (x, y) in t[end..].iter_mut().zip(d) {
+            *x ^= y;
+        }
+        cmac(&t)
+    } else {
+        let mut padded = [0u8; 16];
+        padded[..plaintext.len()].copy_from_slice(plaintext);
+        padded[plaintext.len()] = 0x80;
+        cmac(&xor(dbl(d), padded))
+    }
+}
+
+/// Counter block of a synthetic IV: bits 63 and 31 cleared
+fn ctr_iv(v: Block) -> Block {
+    let mut q = v;
+    q[8] &= 0x7f;
+    q[12] &= 0x7f;
+    q
+}
+
+impl SivKey {
+    /// Key derived from a secret: HMAC-SHA256 of two labels keyed by it.
+    pub fn derive(secret: &str) -> SivKey {
+        SivKey {
+            mac: hmac_digest(b"myanon encrypt mac", secret.as_bytes()),
+            enc: hmac_digest(b"myanon encrypt enc", secret.as_bytes()),
+        }
+    }
+
+    fn cmac(&self, data: &[u8]) -> Block {
+        let mut mac = <Cmac<Aes256> as KeyInit>::new_from_slice(&self.mac).expect("CMAC key is 32 bytes");
+        mac.update(data);
+        mac.finalize().into_bytes().into()
+    }
+
+    fn apply_ctr(&self, v: Block, data: &mut [u8]) {
+        Aes256Ctr::new(&self.enc.into(), &ctr_iv(v).into()).apply_keystream(data);
+    }
+
+    /// Synthetic IV (16 bytes) followed by the ciphertext. The same
+    /// plaintext always gives the same result.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let v = s2v(&|data| self.cmac(data), &[], plaintext);
+        let mut out = Vec::with_capacity(16 + plaintext.len());
+        out.extend_from_slice(&v);
+        out.extend_from_slice(plaintext);
+        self.apply_ctr(v, &mut out[16..]);
+        out
+    }
+
+    /// Plaintext of an `encrypt` result, `None` if it was not encrypted with
+    /// this key or has been altered.
+    pub fn decrypt(&self, data: &[u8]) -> Option<Vec<u8>> {
+        let v: Block = data.get(..16)?.try_into().ok()?;
+        let mut plaintext = data[16..].to_vec();
+        self.apply_ctr(v, &mut plaintext);
+        (s2v(&|data| self.cmac(data), &[], &plaintext) == v).then_some(plaintext)
+    }
+}
+
+/// Text form of an `encrypt` result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    /// Standard alphabet, with padding
+    Base64,
+    /// Lowercase
+    Hex,
+}
+
+impl Encoding {
+    /// Length of the encoded result for a plaintext of `len` bytes
+    pub fn encoded_len(self, len: usize) -> usize {
+        match self {
+            Encoding::Base64 => (16 + len).div_ceil(3) * 4,
+            Encoding::Hex => (16 + len) * 2,
+        }
+    }
+
+    /// Longest plaintext, in bytes, whose encoded result has at most `width`
+    /// characters; `None` when not even an empty one fits.
+    pub fn max_plaintext_len(self, width: usize) -> Option<usize> {
+        let data_len = match self {
+            Encoding::Base64 => width / 4 * 3,
+            Encoding::Hex => width / 2,
+        };
+        data_len.checked_sub(16)
+    }
+
+    fn encode_into(self, out: &mut Vec<u8>, data: &[u8]) {
+        match self {
+            Encoding::Base64 => {
+                for chunk in data.chunks(3) {
+                    let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+                    for i in 0..4 {
+                        if i <= chunk.len() {
+                            out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize]);
+                        } else {
+                            out.push(b'=');
+                        }
+                    }
+                }
+            }
+            Encoding::Hex => {
+                for b in data {
+                    out.extend_from_slice(format!("{:02x}", b).as_bytes());
+                }
+            }
+        }
+    }
+
+    fn decode(self, text: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            Encoding::Base64 => {
+                if !text.len().is_multiple_of(4) {
+                    return None;
+                }
+                let mut out = Vec::with_capacity(text.len() / 4 * 3);
+                for chunk in text.chunks(4) {
+                    let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+                    if padding > 2 {
+                        return None;
+                    }
+                    let mut n = 0u32;
+                    for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+                        let value = BASE64.iter().position(|&b| b == c)? as u32;
+                        n |= value << (18 - 6 * i);
+                    }
+                    out.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+                }
+                // Padding only ends the text
+                (!text[..text.len().saturating_sub(4)].contains(&b'=')).then_some(out)
+            }
+            Encoding::Hex => {
+                if !text.len().is_multiple_of(2) {
+                    return None;
+                }
+                text.chunks(2)
+                    .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Settings of an `encrypt` rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncryptRule {
+    /// Name of the key in the `keys` block
+    pub key_name: String,
+    pub key: SivKey,
+    pub encoding: Encoding,
+    /// Width of the column (`width '64'`): a value whose result would be
+    /// longer stops the run
+    pub width: Option<usize>,
+    /// Longest value, in bytes, whose result fits `width`
+    pub max_len: Option<usize>,
+    /// Reverse the rule, for `myanon decrypt`
+    pub decrypt: bool,
+}
+
+/// Encrypt a value with AES-SIV, the same value always giving the same
+/// result, written in base64 or hex. The value is unescaped first, so that
+/// decrypting gives it back as stored in the database. When the rule is in
+/// decrypt mode, reverse it instead, returning false, writing nothing, for
+/// values that were not encrypted with the key.
+pub fn encrypt_into(out: &mut Vec<u8>, token: &[u8], rule: &EncryptRule) -> bool {
+    if rule.decrypt {
+        let Some(plaintext) = rule.encoding.decode(token).and_then(|data| rule.key.decrypt(&data)) else {
+            return false;
+        };
+        mysql_backslash_escape_into(out, &plaintext);
+    } else {
+        let (plaintext, _) = mysql_unescape_bytes(token);
+        rule.encoding.encode_into(out, &rule.key.encrypt(&plaintext));
+    }
+    true
+}
+
+/// Decrypt a single value of an anonymized dump, as stored in the database.
+pub fn decrypt_value(value: &str, key: &SivKey, encoding: Encoding) -> Option<Vec<u8>> {
+    key.decrypt(&encoding.decode(value.trim().as_bytes())?)
+}
+
+/// Config decrypting one column of a dump, for `myanon decrypt`: the only
+/// rule is `encrypt` in decrypt mode, the rest of the dump is copied.
+pub fn decrypt_config(table: &str, column: &str, rule: EncryptRule) -> Config {
+    let infos = AnonBase {
+        anon_type: AnonType::Encrypt,
+        encrypt: Some(EncryptRule { decrypt: true, ..rule }),
+        ..Default::default()
+    };
+    Config {
+        tables: vec![AnonTable {
+            name: table.to_string(),
+            regex: None,
+            action: TableAction::Anon,
+            fields: vec![AnonField {
+                name: column.to_string(),
+                pos: -1,
+                quoted: false,
+                numeric: None,
+                infos,
+                json: Vec::new(),
+                url: Vec::new(),
+                scrub: Vec::new(),
+            }],
+        }],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::Aes128;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        let hex: String = hex.split_whitespace().collect();
+        Encoding::Hex.decode(hex.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_rfc5297_vector() {
+        // RFC 5297, A.1 (AES-128 halves, one associated data)
+        let key = from_hex("fffefdfc fbfaf9f8 f7f6f5f4 f3f2f1f0 f0f1f2f3 f4f5f6f7 f8f9fafb fcfdfeff");
+        let ad = from_hex("10111213 14151617 18191a1b 1c1d1e1f 20212223 24252627");
+        let plaintext = from_hex("11223344 55667788 99aabbcc ddee");
+        let cmac = |data: &[u8]| -> Block {
+            let mut mac = <Cmac<Aes128> as KeyInit>::new_from_slice(&key[..16]).unwrap();
+            mac.update(data);
+            mac.finalize().into_bytes().into()
+        };
+        let v = s2v(&cmac, &[&ad], &plaintext);
+        assert_eq!(v.to_vec(), from_hex("85632d07 c6e8f37f 950acd32 0a2ecc93"));
+        let mut ciphertext = plaintext.clone();
+        let iv: Block = ctr_iv(v);
+        ctr::Ctr128BE::<Aes128>::new(key[16..].into(), &iv.into()).apply_keystream(&mut ciphertext);
+        assert_eq!(ciphertext, from_hex("40c02b96 90c4dc04 daef7f6a fe5c"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = SivKey::derive("billing key");
+        for plaintext in [&b""[..], b"FR7630006000011234567890189", b"exactly 16 bytes", "Zoë".as_bytes()] {
+            let data = key.encrypt(plaintext);
+            assert_eq!(data.len(), 16 + plaintext.len());
+            assert_eq!(key.encrypt(plaintext), data);
+            assert_eq!(key.decrypt(&data).as_deref(), Some(plaintext));
+        }
+        let data = key.encrypt(b"secret");
+        assert_eq!(SivKey::derive("other key").decrypt(&data), None);
+        let mut altered = data.clone();
+        altered[17] ^= 1;
+        assert_eq!(key.decrypt(&altered), None);
+        assert_eq!(key.decrypt(&data[..15]), None);
+    }
+
+    #[test]
+    fn test_encodings() {
+        for data in [&b""[..], b"f", b"fo", b"foo", b"foob", b"fooba", b"foobar"] {
+            let mut out = Vec::new();
+            Encoding::Base64.encode_into(&mut out, data);
+            assert_eq!(Encoding::Base64.decode(&out).as_deref(), Some(data));
+        }
+        let mut out = Vec::new();
+        Encoding::Base64.encode_into(&mut out, b"foobar\xff\xfe");
+        assert_eq!(out, b"Zm9vYmFy//4=");
+        for text in [&b"Zm9"[..], b"Zm=v", b"Zg==Zg==", b"Z===", b"Zm9*"] {
+            assert_eq!(Encoding::Base64.decode(text), None, "{}", String::from_utf8_lossy(text));
+        }
+        assert_eq!(Encoding::Hex.decode(b"00ff10"), Some(vec![0, 255, 16]));
+        assert_eq!(Encoding::Hex.decode(b"0g"), None);
+        assert_eq!(Encoding::Base64.encoded_len(6), 32);
+        assert_eq!(Encoding::Hex.encoded_len(6), 44);
+        for encoding in [Encoding::Base64, Encoding::Hex] {
+            for width in 0..100 {
+                match encoding.max_plaintext_len(width) {
+                    Some(n) => {
+                        assert!(encoding.encoded_len(n) <= width);
+                        assert!(encoding.encoded_len(n + 1) > width);
+                    }
+                    None => assert!(encoding.encoded_len(0) > width),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule() {
+        let mut rule = EncryptRule {
+            key_name: "billing".to_string(),
+            key: SivKey::derive("billing key"),
+            encoding: Encoding::Base64,
+            width: None,
+            max_len: None,
+            decrypt: false,
+        };
+        let mut encrypted = Vec::new();
+        assert!(encrypt_into(&mut encrypted, b"O\\'Brien", &rule));
+        assert_eq!(encrypted.len(), Encoding::Base64.encoded_len(7));
+        let text = std::str::from_utf8(&encrypted).unwrap();
+        assert_eq!(decrypt_value(text, &rule.key, Encoding::Base64).unwrap(), b"O'Brien");
+
+        rule.decrypt = true;
+        let mut out = Vec::new();
+        assert!(encrypt_into(&mut out, &encrypted, &rule));
+        assert_eq!(out, b"O\\'Brien");
+        assert!(!encrypt_into(&mut Vec::new(), b"not encrypted", &rule));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::anonymize::{mychar_count, mysql_escape_into, mysubstr_into};
+use crate::noise::{format_decimal, parse_decimal, NumericType};
+
+/// Coarsening applied by a `generalize` rule
+#[derive(Debug, Clone, PartialEq)]
+pub enum Generalization {
+    /// Lower bound of the bucket of width `size` (in units of 10^-scale)
+    Bucket { size: i128, scale: u32 },
+    /// Nearest multiple of `step` (in units of 10^-scale)
+    Round { step: i128, scale: u32 },
+    /// Dates truncated to the first day of their year, quarter or month
+    Year,
+    Quarter,
+    Month,
+    /// First `len` characters, the others replaced by `pad` or dropped
+    Prefix { len: usize, pad: Option<char> },
+}
+
+impl Generalization {
+    /// `bucket '10'` or `round '0.01'`: the width must be a positive number.
+    pub fn numeric(round: bool, width: &str) -> Result<Generalization, String> {
+        match parse_decimal(width.trim()) {
+            Some((step, scale)) if step > 0 => Ok(if round {
+                Generalization::Round { step, scale }
+            } else {
+                Generalization::Bucket { size: step, scale }
+            }),
+            _ => Err(format!(
+                "invalid generalize {} '{}', expected a positive number",
+                if round { "round" } else { "bucket" },
+                width
+            )),
+        }
+    }
+}
+
+/// `value` (in units of 10^-from) in units of 10^-to
+fn rescale(value: i128, from: u32, to: u32) -> i128 {
+    if to >= from {
+        value.saturating_mul(10i128.pow(to - from))
+    } else {
+        value / 10i128.pow(from - to)
+    }
+}
+
+/// Round `value` to the nearest multiple of `step`, halves away from zero
+fn round_to(value: i128, step: i128) -> i128 {
+    let rounded = (value.abs() + step / 2) / step * step;
+    if value < 0 {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+/// Bucket or round a number, keeping its number of decimals. A result out
+/// of the column type range is moved back by one step, then clamped.
+fn generalize_number(token: &[u8], rule: &Generalization, column: Option<NumericType>) -> Option<String> {
+    let (value, scale) = parse_decimal(std::str::from_utf8(token).ok()?)?;
+    let (step, step_scale) = match *rule {
+        Generalization::Bucket { size, scale } => (size, scale),
+        Generalization::Round { step, scale } => (step, scale),
+        _ => return None,
+    };
+    let common = scale.max(step_scale);
+    let (value_c, step_c) = (rescale(value, scale, common), rescale(step, step_scale, common));
+    let mut new = match rule {
+        Generalization::Bucket { .. } => value_c.div_euclid(step_c) * step_c,
+        _ => round_to(value_c, step_c),
+    };
+
+    if let Some((min, max)) = column.map(|c| c.bounds(common)) {
+        if max.is_some_and(|max| new > max) {
+            new -= step_c;
+        } else if min.is_some_and(|min| new < min) {
+            new += step_c;
+        }
+        new = new.clamp(min.unwrap_or(i128::MIN), max.unwrap_or(i128::MAX));
+    }
+    let new = if common > scale {
+        round_to(new, 10i128.pow(common - scale)) / 10i128.pow(common - scale)
+    } else {
+        new
+    };
+    Some(format_decimal(new, scale))
+}
+
+/// Truncate a `YYYY-MM-DD` date, or the date of a `YYYY-MM-DD hh:mm:ss`
+/// datetime whose time becomes midnight. Zero dates are left as they are.
+fn generalize_date(token: &[u8], rule: &Generalization) -> Option<Vec<u8>> {
+    let date = token.get(..10)?;
+    if date[4] != b'-' || date[7] != b'-' {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<u32> {
+        std::str::from_utf8(&date[range]).ok()?.parse().ok()
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    if month == 0 || day == 0 {
+        return None;
+    }
+    let rest = &token[10..];
+    if !rest.is_empty() && !rest.starts_with(b" ") && !rest.starts_with(b"T") {
+        return None;
+    }
+
+    let month = match rule {
+        Generalization::Year => 1,
+        Generalization::Quarter => (month - 1) / 3 * 3 + 1,
+        _ => month,
+    };
+    let mut out = format!("{:04}-{:02}-01", year, month).into_bytes();
+    out.extend(rest.iter().map(|&b| if b.is_ascii_digit() { b'0' } else { b }));
+    Some(out)
+}
+
+/// Coarsen a value. Numbers keep their number of decimals and stay in the
+/// range of the column type; values the rule does not apply to (text for a
+/// numeric rule, non-dates for a date rule) are copied as they are.
+pub fn generalize_into(out: &mut Vec<u8>, token: &[u8], rule: &Generalization, column: Option<NumericType>) {
+    match rule {
+        Generalization::Bucket { .. } | Generalization::Round { .. } => {
+            match generalize_number(token, rule, column) {
+                Some(number) => out.extend_from_slice(number.as_bytes()),
+                None => out.extend_from_slice(token),
+            }
+        }
+        Generalization::Year | Generalization::Quarter | Generalization::Month => {
+            match generalize_date(token, rule) {
+                Some(date) => out.extend_from_slice(&date),
+                None => out.extend_from_slice(token),
+            }
+        }
+        Generalization::Prefix { len, pad } => {
+            let kept = mysubstr_into(out, token, *len);
+            if let Some(pad) = pad {
+                let mut buf = [0u8; 4];
+                for _ in 0..mychar_count(&token[kept..]) {
+                    mysql_escape_into(out, pad.encode_utf8(&mut buf));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generalize(rule: &Generalization, token: &str, column: Option<&str>) -> String {
+        let mut out = Vec::new();
+        generalize_into(&mut out, token.as_bytes(), rule, column.and_then(NumericType::parse));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_bucket() {
+        let rule = Generalization::numeric(false, "10").unwrap();
+        assert_eq!(generalize(&rule, "37", None), "30");
+        assert_eq!(generalize(&rule, "40", None), "40");
+        assert_eq!(generalize(&rule, "-3", None), "-10");
+        assert_eq!(generalize(&rule, "37.50", None), "30.00");
+        assert_eq!(generalize(&rule, "n/a", None), "n/a");
+        let rule = Generalization::numeric(false, "2.5").unwrap();
+        assert_eq!(generalize(&rule, "6.30", None), "5.00");
+    }
+
+    #[test]
+    fn test_round() {
+        let rule = Generalization::numeric(true, "5000").unwrap();
+        assert_eq!(generalize(&rule, "52499.99", None), "50000.00");
+        assert_eq!(generalize(&rule, "52500", None), "55000");
+        assert_eq!(generalize(&rule, "-2500", None), "-5000");
+        let rule = Generalization::numeric(true, "0.01").unwrap();
+        assert_eq!(generalize(&rule, "48.856613", None), "48.860000");
+        assert_eq!(generalize(&rule, "-2.352222", None), "-2.350000");
+        assert_eq!(generalize(&rule, "48", None), "48");
+
+        // Kept in the column range, on a multiple of the step
+        let rule = Generalization::numeric(true, "10").unwrap();
+        assert_eq!(generalize(&rule, "255", Some("tinyint unsigned")), "250");
+        assert_eq!(generalize(&rule, "-128", Some("tinyint")), "-120");
+        assert_eq!(generalize(&rule, "999.96", Some("decimal(5,2)")), "990.00");
+
+        for width in ["0", "-5", "abc", ""] {
+            assert!(Generalization::numeric(true, width).is_err(), "{}", width);
+        }
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(generalize(&Generalization::Year, "1985-07-14", None), "1985-01-01");
+        assert_eq!(generalize(&Generalization::Quarter, "1985-08-14", None), "1985-07-01");
+        assert_eq!(generalize(&Generalization::Quarter, "1985-03-31", None), "1985-01-01");
+        assert_eq!(generalize(&Generalization::Month, "1985-07-14", None), "1985-07-01");
+        assert_eq!(
+            generalize(&Generalization::Month, "2024-02-29 13:45:10.123", None),
+            "2024-02-01 00:00:00.000"
+        );
+        assert_eq!(generalize(&Generalization::Year, "0000-00-00", None), "0000-00-00");
+        assert_eq!(generalize(&Generalization::Year, "1985", None), "1985");
+        assert_eq!(generalize(&Generalization::Year, "14/07/1985", None), "14/07/1985");
+    }
+
+    #[test]
+    fn test_prefix() {
+        let rule = Generalization::Prefix { len: 2, pad: Some('0') };
+        assert_eq!(generalize(&rule, "75011", None), "75000");
+        assert_eq!(generalize(&rule, "7", None), "7");
+        let rule = Generalization::Prefix { len: 3, pad: None };
+        assert_eq!(generalize(&rule, "SW1A 1AA", None), "SW1");
+        let rule = Generalization::Prefix { len: 2, pad: Some('\'') };
+        assert_eq!(generalize(&rule, "Zoë\\'s", None), "Zo''''''");
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+use crate::anonymize::hmac_digest;
+
+/// National identifier generated by an `idhash 'kind'` rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdKind {
+    /// US Social Security Number: `AAA-GG-SSSS`
+    Ssn,
+    /// French NIR (INSEE number): 13 digits and a mod-97 key
+    Nir,
+    /// UK National Insurance number: `AB123456C`
+    Nino,
+    /// Spanish DNI (`12345678Z`), or NIE (`X1234567L`) for NIE values
+    Dni,
+}
+
+impl IdKind {
+    pub fn parse(kind: &str) -> Result<IdKind, String> {
+        match kind {
+            "ssn" => Ok(IdKind::Ssn),
+            "nir" => Ok(IdKind::Nir),
+            "nino" => Ok(IdKind::Nino),
+            "dni" => Ok(IdKind::Dni),
+            _ => Err(format!(
+                "unknown idhash kind '{}', expected 'ssn', 'nir', 'nino' or 'dni'",
+                kind
+            )),
+        }
+    }
+}
+
+impl fmt::Display for IdKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdKind::Ssn => write!(f, "ssn"),
+            IdKind::Nir => write!(f, "nir"),
+            IdKind::Nino => write!(f, "nino"),
+            IdKind::Dni => write!(f, "dni"),
+        }
+    }
+}
+
+/// Control letters of DNI and NIE numbers, indexed by the number modulo 23
+const DNI_LETTERS: &[u8; 23] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+/// Letters allowed as first and second letter of a NINO prefix
+const NINO_FIRST: &[u8] = b"ABCEGHJKLMNOPRSTWXYZ";
+const NINO_SECOND: &[u8] = b"ABCEGHJKLMNPRSTWXYZ";
+const NINO_UNALLOCATED: [&[u8; 2]; 7] = [b"BG", b"GB", b"KN", b"NK", b"NT", b"TN", b"ZZ"];
+
+/// Number in `0..n` taken from 4 bytes of the digest
+fn pick(digest: &[u8; 32], offset: usize, n: u32) -> u32 {
+    u32::from_be_bytes(digest[offset..offset + 4].try_into().unwrap()) % n
+}
+
+/// Generate an identifier of `kind` from the digest, without separators.
+/// `nie` selects NIE numbers for the `dni` kind.
+fn generate(kind: IdKind, digest: &[u8; 32], nie: bool) -> String {
+    match kind {
+        IdKind::Ssn => {
+            // Area 001-899 without 666, group 01-99, serial 0001-9999
+            let mut area = pick(digest, 0, 898) + 1;
+            if area >= 666 {
+                area += 1;
+            }
+            let group = pick(digest, 4, 99) + 1;
+            let serial = pick(digest, 8, 9999) + 1;
+            format!("{:03}{:02}{:04}", area, group, serial)
+        }
+        IdKind::Nir => {
+            let sex = pick(digest, 0, 2) + 1;
+            let year = pick(digest, 4, 100);
+            let month = pick(digest, 8, 12) + 1;
+            // Mainland departments, 20 being Corsica (2A and 2B)
+            let mut department = pick(digest, 12, 94) + 1;
+            if department >= 20 {
+                department += 1;
+            }
+            let commune = pick(digest, 16, 990) + 1;
+            let order = pick(digest, 20, 999) + 1;
+            let number = format!(
+                "{}{:02}{:02}{:02}{:03}{:03}",
+                sex, year, month, department, commune, order
+            );
+            let key = 97 - number.parse::<u64>().unwrap() % 97;
+            format!("{}{:02}", number, key)
+        }
+        IdKind::Nino => {
+            let first = NINO_FIRST[pick(digest, 0, NINO_FIRST.len() as u32) as usize];
+            let mut second_idx = pick(digest, 4, NINO_SECOND.len() as u32) as usize;
+            while NINO_UNALLOCATED.contains(&&[first, NINO_SECOND[second_idx]]) {
+                second_idx = (second_idx + 1) % NINO_SECOND.len();
+            }
+            let suffix = b"ABCD"[pick(digest, 12, 4) as usize];
+            format!(
+                "{}{}{:06}{}",
+                first as char,
+                NINO_SECOND[second_idx] as char,
+                pick(digest, 8, 1_000_000),
+                suffix as char
+            )
+        }
+        IdKind::Dni if nie => {
+            let prefix = pick(digest, 0, 3);
+            let number = pick(digest, 4, 10_000_000);
+            let letter = DNI_LETTERS[((prefix * 10_000_000 + number) % 23) as usize];
+            format!("{}{:07}{}", b"XYZ"[prefix as usize] as char, number, letter as char)
+        }
+        IdKind::Dni => {
+            let number = pick(digest, 0, 100_000_000);
+            format!("{:08}{}", number, DNI_LETTERS[(number % 23) as usize] as char)
+        }
+    }
+}
+
+/// Replace an identifier by a valid one of `kind` derived from its HMAC.
+/// When the value has as many letters and digits as the generated
+/// identifier, they are put in place of them, keeping separators;
+/// otherwise the identifier is written in its usual form. Values without
+/// any letter or digit are left as they are.
+pub fn idhash_into(out: &mut Vec<u8>, token: &[u8], secret: &[u8], kind: IdKind) {
+    let compact: Vec<u8> = token
+        .iter()
+        .filter(|b| b.is_ascii_alphanumeric())
+        .map(u8::to_ascii_uppercase)
+        .collect();
+    if compact.is_empty() {
+        out.extend_from_slice(token);
+        return;
+    }
+    let nie = kind == IdKind::Dni && matches!(compact[0], b'X' | b'Y' | b'Z');
+    let generated = generate(kind, &hmac_digest(&compact, secret), nie);
+
+    if compact.len() == generated.len() {
+        let mut next = generated.bytes();
+        out.extend(token.iter().map(|&b| {
+            if b.is_ascii_alphanumeric() {
+                next.next().unwrap()
+            } else {
+                b
+            }
+        }));
+    } else if kind == IdKind::Ssn {
+        out.extend_from_slice(
+            format!("{}-{}-{}", &generated[..3], &generated[3..5], &generated[5..]).as_bytes(),
+        );
+    } else {
+        out.extend_from_slice(generated.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idhash(kind: IdKind, token: &str) -> String {
+        let mut out = Vec::new();
+        idhash_into(&mut out, token.as_bytes(), b"secret", kind);
+        String::from_utf8(out).unwrap()
+    }
+
+    fn digits(text: &str) -> u64 {
+        text.bytes()
+            .filter(u8::is_ascii_digit)
+            .fold(0, |n, b| n * 10 + (b - b'0') as u64)
+    }
+
+    #[test]
+    fn test_ssn() {
+        let ssn = idhash(IdKind::Ssn, "078-05-1120");
+        assert_eq!(ssn.len(), 11);
+        assert_eq!((&ssn[3..4], &ssn[6..7]), ("-", "-"));
+        let area: u32 = ssn[..3].parse().unwrap();
+        assert!((1..900).contains(&area) && area != 666);
+        assert_ne!(&ssn[4..6], "00");
+        assert_ne!(&ssn[7..], "0000");
+        // Same number without separators: same digits
+        assert_eq!(idhash(IdKind::Ssn, "078051120"), ssn.replace('-', ""));
+        assert_eq!(idhash(IdKind::Ssn, "78051120").len(), 11);
+    }
+
+    #[test]
+    fn test_nir() {
+        let nir = idhash(IdKind::Nir, "1 85 05 78 006 084 36");
+        assert_eq!(nir.len(), 21);
+        assert_eq!(nir.matches(' ').count(), 6);
+        let compact = nir.replace(' ', "");
+        let (number, key) = compact.split_at(13);
+        assert_eq!(97 - digits(number) % 97, digits(key));
+        let month: u32 = compact[3..5].parse().unwrap();
+        assert!((1..=12).contains(&month));
+        // Corsican departments are letters, still replaced in place
+        assert_eq!(idhash(IdKind::Nir, "2 69 05 2A 123 456 12").len(), 21);
+    }
+
+    #[test]
+    fn test_nino() {
+        for input in ["QQ 12 34 56 C", "AB123456C", "ab123456c"] {
+            let nino = idhash(IdKind::Nino, input);
+            assert_eq!(nino.len(), input.len());
+            let compact = nino.replace(' ', "");
+            let bytes = compact.as_bytes();
+            assert!(NINO_FIRST.contains(&bytes[0]));
+            assert!(NINO_SECOND.contains(&bytes[1]));
+            assert!(!NINO_UNALLOCATED.contains(&&[bytes[0], bytes[1]]));
+            assert!(bytes[2..8].iter().all(u8::is_ascii_digit));
+            assert!(b"ABCD".contains(&bytes[8]));
+        }
+        assert_eq!(idhash(IdKind::Nino, "AB123456C"), idhash(IdKind::Nino, "ab123456c"));
+    }
+
+    #[test]
+    fn test_dni_nie() {
+        let dni = idhash(IdKind::Dni, "12345678-Z");
+        assert_eq!(&dni[8..9], "-");
+        let number = digits(&dni);
+        assert_eq!(dni.as_bytes()[9], DNI_LETTERS[(number % 23) as usize]);
+
+        let nie = idhash(IdKind::Dni, "X1234567L");
+        let prefix = b"XYZ".iter().position(|&b| b == nie.as_bytes()[0]).unwrap() as u64;
+        let number = prefix * 10_000_000 + digits(&nie);
+        assert_eq!(nie.as_bytes()[8], DNI_LETTERS[(number % 23) as usize]);
+
+        assert_eq!(DNI_LETTERS[12345678 % 23], b'Z');
+        assert_eq!(idhash(IdKind::Dni, ""), "");
+        assert_eq!(idhash(IdKind::Dni, " - "), " - ");
+    }
+}
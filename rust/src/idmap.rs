@@ -0,0 +1,166 @@
+use crate::anonymize::hmac_digest;
+use crate::noise::NumericType;
+
+/// Number of Feistel rounds
+const ROUNDS: u8 = 8;
+
+/// Range of integers permuted by an `idmap` rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdDomain {
+    pub min: i128,
+    pub max: i128,
+}
+
+impl IdDomain {
+    /// `'1-99999999'`, `'-128-127'`: bounds included, at most 2^64 values.
+    pub fn parse(range: &str) -> Result<IdDomain, String> {
+        let range = range.trim();
+        let invalid = || format!("invalid idmap range '{}', expected 'min-max'", range);
+        // The minimum may be negative: the separator is the first '-' after it
+        let split = range
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i)
+            .ok_or_else(invalid)?;
+        let bound = |s: &str| s.trim().parse::<i128>().map_err(|_| invalid());
+        let (min, max) = (bound(&range[..split])?, bound(&range[split + 1..])?);
+        if min > max {
+            return Err(format!("invalid idmap range '{}', min is greater than max", range));
+        }
+        if max - min > u64::MAX as i128 {
+            return Err(format!("invalid idmap range '{}', more than 2^64 values", range));
+        }
+        Ok(IdDomain { min, max })
+    }
+
+    /// Whole range of an integer column type
+    fn of_column(column: Option<NumericType>) -> Option<IdDomain> {
+        match column? {
+            NumericType::Integer { min, max } => Some(IdDomain { min, max }),
+            _ => None,
+        }
+    }
+}
+
+/// Keyed permutation of [0, 2^(2*half)): a balanced Feistel network whose
+/// round function is the HMAC of the round, the domain and the right half.
+fn feistel(value: u128, half: u32, domain: &IdDomain, secret: &[u8]) -> u128 {
+    let mask = (1u128 << half) - 1;
+    let (mut left, mut right) = (value >> half, value & mask);
+    let mut input = Vec::with_capacity(48);
+    for round in 0..ROUNDS {
+        input.clear();
+        input.extend_from_slice(b"idmap");
+        input.extend_from_slice(&domain.min.to_be_bytes());
+        input.extend_from_slice(&domain.max.to_be_bytes());
+        input.push(round);
+        input.extend_from_slice(&(right as u64).to_be_bytes());
+        let digest = hmac_digest(&input, secret);
+        let f = u64::from_be_bytes(digest[..8].try_into().unwrap()) as u128 & mask;
+        (left, right) = (right, left ^ f);
+    }
+    (left << half) | right
+}
+
+/// Replace an integer by its image through a keyed permutation of its
+/// domain: the configured one, or the range of the integer column type.
+/// Distinct values stay distinct and within the domain, so primary and
+/// foreign keys mapped with the same domain still join. Returns false,
+/// writing nothing, for values that are not integers of the domain.
+pub fn idmap_into(
+    out: &mut Vec<u8>,
+    token: &[u8],
+    secret: &[u8],
+    domain: Option<IdDomain>,
+    column: Option<NumericType>,
+) -> bool {
+    let Some(domain) = domain.or_else(|| IdDomain::of_column(column)) else {
+        return false;
+    };
+    let Some(value) = std::str::from_utf8(token).ok().and_then(|t| t.parse::<i128>().ok()) else {
+        return false;
+    };
+    if value < domain.min || value > domain.max {
+        return false;
+    }
+
+    let size = (domain.max - domain.min) as u128 + 1;
+    // Smallest even number of bits covering the domain: the permutation is
+    // over at most 4 times the domain, and values landing outside of it are
+    // encrypted again (cycle walking) until they fall back in.
+    let bits = (128 - (size - 1).leading_zeros()).max(2);
+    let half = bits.div_ceil(2);
+    let mut mapped = (value - domain.min) as u128;
+    loop {
+        mapped = feistel(mapped, half, &domain, secret);
+        if mapped < size {
+            break;
+        }
+    }
+    out.extend_from_slice((domain.min + mapped as i128).to_string().as_bytes());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn idmap(token: &str, domain: Option<IdDomain>, column: Option<&str>) -> Option<i128> {
+        let mut out = Vec::new();
+        idmap_into(&mut out, token.as_bytes(), b"secret", domain, column.and_then(NumericType::parse))
+            .then(|| String::from_utf8(out).unwrap().parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse_domain() {
+        assert_eq!(IdDomain::parse("1-1000"), Ok(IdDomain { min: 1, max: 1000 }));
+        assert_eq!(IdDomain::parse(" -128 - 127 "), Ok(IdDomain { min: -128, max: 127 }));
+        assert_eq!(IdDomain::parse("-10--5"), Ok(IdDomain { min: -10, max: -5 }));
+        assert_eq!(
+            IdDomain::parse("0-18446744073709551615"),
+            Ok(IdDomain { min: 0, max: u64::MAX as i128 })
+        );
+        for range in ["", "1000", "5-1", "a-b", "-1", "0-18446744073709551616"] {
+            assert!(IdDomain::parse(range).is_err(), "{}", range);
+        }
+    }
+
+    #[test]
+    fn test_bijective() {
+        for range in ["1-1000", "0-1", "-5-5", "7-7", "100-355"] {
+            let domain = IdDomain::parse(range).unwrap();
+            let images: HashSet<i128> = (domain.min..=domain.max)
+                .map(|v| idmap(&v.to_string(), Some(domain), None).unwrap())
+                .collect();
+            assert_eq!(images.len() as i128, domain.max - domain.min + 1, "{}", range);
+            assert!(images.iter().all(|v| (domain.min..=domain.max).contains(v)), "{}", range);
+        }
+        let domain = IdDomain::parse("1-1000").unwrap();
+        let moved = (1..=1000).filter(|v| idmap(&v.to_string(), Some(domain), None) != Some(*v)).count();
+        assert!(moved > 900, "{}", moved);
+    }
+
+    #[test]
+    fn test_column_domain() {
+        // Same value, same domain: same image, whatever the column
+        let domain = IdDomain::parse("1-4294967295").unwrap();
+        assert_eq!(
+            idmap("42", Some(domain), Some("int unsigned")),
+            idmap("42", Some(domain), Some("bigint"))
+        );
+        for v in [-128, -1, 0, 1, 127] {
+            let mapped = idmap(&v.to_string(), None, Some("tinyint")).unwrap();
+            assert!((-128..=127).contains(&mapped));
+        }
+        let big = idmap("18446744073709551615", None, Some("bigint unsigned")).unwrap();
+        assert!((0..=u64::MAX as i128).contains(&big));
+
+        // Not an integer of the domain: left to the caller
+        assert_eq!(idmap("42", None, None), None);
+        assert_eq!(idmap("42", None, Some("varchar(10)")), None);
+        assert_eq!(idmap("1001", Some(IdDomain::parse("1-1000").unwrap()), None), None);
+        assert_eq!(idmap("4.2", Some(domain), None), None);
+    }
+}
@@ -0,0 +1,230 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes128;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::anonymize::QuoteMode;
+
+/// Prefix-preserving address pseudonymization (Crypto-PAn): two addresses
+/// sharing their first n bits still share exactly n bits once anonymized.
+pub struct CryptoPan {
+    cipher: Aes128,
+    pad: u128,
+}
+
+impl CryptoPan {
+    /// The 32-byte Crypto-PAn key is the HMAC-SHA256 of "iphash" keyed by the
+    /// secret: an AES-128 key, then the block encrypted to make the pad.
+    pub fn new(secret: &[u8]) -> CryptoPan {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(secret).expect("HMAC accepts any key length");
+        mac.update(b"iphash");
+        let key = mac.finalize().into_bytes();
+        let cipher = Aes128::new_from_slice(&key[..16]).expect("AES-128 key is 16 bytes");
+        let mut pan = CryptoPan { cipher, pad: 0 };
+        pan.pad = pan.encrypt(u128::from_be_bytes(key[16..].try_into().unwrap()));
+        pan
+    }
+
+    fn encrypt(&self, block: u128) -> u128 {
+        let mut block = block.to_be_bytes().into();
+        self.cipher.encrypt_block(&mut block);
+        u128::from_be_bytes(block.into())
+    }
+
+    /// Anonymize the `bits` first bits of `addr` (left-aligned): bit i is
+    /// flipped by the first bit of the encryption of the i previous bits of
+    /// the address followed by the pad.
+    fn anonymize_bits(&self, addr: u128, bits: u32) -> u128 {
+        let mut flips = 0u128;
+        for pos in 0..bits {
+            let mask = u128::MAX.checked_shl(128 - pos).unwrap_or(0);
+            let input = (self.pad & !mask) | (addr & mask);
+            flips |= (self.encrypt(input) >> 127) << (127 - pos);
+        }
+        addr ^ flips
+    }
+
+    pub fn anonymize_v4(&self, addr: Ipv4Addr) -> Ipv4Addr {
+        let bits = (u32::from(addr) as u128) << 96;
+        Ipv4Addr::from((self.anonymize_bits(bits, 32) >> 96) as u32)
+    }
+
+    pub fn anonymize_v6(&self, addr: Ipv6Addr) -> Ipv6Addr {
+        Ipv6Addr::from(self.anonymize_bits(u128::from(addr), 128))
+    }
+}
+
+/// Private, loopback, link-local, multicast, documentation and other
+/// reserved IPv4 ranges
+fn is_reserved_v4(addr: Ipv4Addr) -> bool {
+    let [a, b, ..] = addr.octets();
+    addr.is_private()
+        || addr.is_loopback()
+        || addr.is_link_local()
+        || addr.is_multicast()
+        || addr.is_documentation()
+        || a == 0
+        || a >= 240
+        || (a == 100 && (64..128).contains(&b))
+}
+
+/// Loopback, unspecified, unique local, link-local, multicast and
+/// documentation IPv6 ranges, and IPv4-mapped reserved addresses
+fn is_reserved_v6(addr: Ipv6Addr) -> bool {
+    addr.is_loopback()
+        || addr.is_unspecified()
+        || addr.is_unique_local()
+        || addr.is_unicast_link_local()
+        || addr.is_multicast()
+        || addr.segments()[..2] == [0x2001, 0xdb8]
+        || addr.to_ipv4_mapped().is_some_and(is_reserved_v4)
+}
+
+/// Anonymize an IP address written as dotted IPv4 or IPv6 text, as an
+/// unsigned integer (`INET_ATON`) or as a 4 or 16-byte `0x` hex literal
+/// (`INET6_ATON` with `--hex-blob`), keeping its form. Returns `None`,
+/// writing nothing, for values that are not addresses.
+pub fn iphash_into(
+    out: &mut Vec<u8>,
+    token: &[u8],
+    pan: &CryptoPan,
+    keep_private: bool,
+) -> Option<QuoteMode> {
+    let text = std::str::from_utf8(token).ok()?;
+
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| hex.get(i..i + 2).and_then(|h| u8::from_str_radix(h, 16).ok()))
+            .collect::<Option<Vec<u8>>>()?;
+        let anonymized = match bytes.len() {
+            4 => {
+                let addr = Ipv4Addr::from(<[u8; 4]>::try_from(bytes).unwrap());
+                let new = if keep_private && is_reserved_v4(addr) { addr } else { pan.anonymize_v4(addr) };
+                new.octets().to_vec()
+            }
+            16 => {
+                let addr = Ipv6Addr::from(<[u8; 16]>::try_from(bytes).unwrap());
+                let new = if keep_private && is_reserved_v6(addr) { addr } else { pan.anonymize_v6(addr) };
+                new.octets().to_vec()
+            }
+            _ => return None,
+        };
+        out.extend_from_slice(b"0x");
+        for b in anonymized {
+            out.extend_from_slice(format!("{:02X}", b).as_bytes());
+        }
+        return Some(QuoteMode::ForceFalse);
+    }
+
+    if !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit()) {
+        let addr = Ipv4Addr::from(text.parse::<u32>().ok()?);
+        let new = if keep_private && is_reserved_v4(addr) { addr } else { pan.anonymize_v4(addr) };
+        out.extend_from_slice(u32::from(new).to_string().as_bytes());
+    } else if let Ok(addr) = text.parse::<Ipv4Addr>() {
+        let new = if keep_private && is_reserved_v4(addr) { addr } else { pan.anonymize_v4(addr) };
+        out.extend_from_slice(new.to_string().as_bytes());
+    } else if let Ok(addr) = text.parse::<Ipv6Addr>() {
+        let new = if keep_private && is_reserved_v6(addr) { addr } else { pan.anonymize_v6(addr) };
+        out.extend_from_slice(new.to_string().as_bytes());
+    } else {
+        return None;
+    }
+    Some(QuoteMode::AsInput)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn common_prefix(a: u128, b: u128) -> u32 {
+        (a ^ b).leading_zeros()
+    }
+
+    #[test]
+    fn test_crypto_pan_reference() {
+        // Test vector from the reference Crypto-PAn implementation
+        let key = [
+            21, 34, 23, 141, 51, 164, 207, 128, 19, 10, 91, 22, 73, 144, 125, 16, 216, 152, 143, 131,
+            121, 121, 101, 39, 98, 87, 76, 45, 42, 132, 34, 2,
+        ];
+        let cipher = Aes128::new_from_slice(&key[..16]).unwrap();
+        let mut pan = CryptoPan { cipher, pad: 0 };
+        pan.pad = pan.encrypt(u128::from_be_bytes(key[16..].try_into().unwrap()));
+        for (orig, anon) in [
+            ("128.11.68.132", "135.242.180.132"),
+            ("129.118.74.4", "134.136.186.123"),
+            ("130.132.252.244", "133.68.164.234"),
+            ("141.223.7.43", "141.167.8.160"),
+        ] {
+            assert_eq!(
+                pan.anonymize_v4(orig.parse().unwrap()).to_string(),
+                anon
+            );
+        }
+    }
+
+    #[test]
+    fn test_prefix_preserved() {
+        let pan = CryptoPan::new(b"secret");
+        let pairs: [(&str, &str); 3] = [
+            ("203.0.113.7", "203.0.113.200"),
+            ("198.51.100.1", "198.51.200.1"),
+            ("2001:db8:1:2::1", "2001:db8:1:3::1"),
+        ];
+        for (a, b) in pairs {
+            let (a, b, anon_a, anon_b) = match (a.parse::<Ipv4Addr>(), b.parse::<Ipv4Addr>()) {
+                (Ok(a), Ok(b)) => (
+                    u32::from(a) as u128,
+                    u32::from(b) as u128,
+                    u32::from(pan.anonymize_v4(a)) as u128,
+                    u32::from(pan.anonymize_v4(b)) as u128,
+                ),
+                _ => {
+                    let (a, b): (Ipv6Addr, Ipv6Addr) = (a.parse().unwrap(), b.parse().unwrap());
+                    (a.into(), b.into(), pan.anonymize_v6(a).into(), pan.anonymize_v6(b).into())
+                }
+            };
+            assert_ne!(a, anon_a);
+            assert_eq!(common_prefix(a, b), common_prefix(anon_a, anon_b));
+        }
+    }
+
+    fn iphash(token: &str, keep_private: bool) -> Option<(String, QuoteMode)> {
+        let mut out = Vec::new();
+        let quoting = iphash_into(&mut out, token.as_bytes(), &CryptoPan::new(b"secret"), keep_private)?;
+        Some((String::from_utf8(out).unwrap(), quoting))
+    }
+
+    #[test]
+    fn test_forms() {
+        let (dotted, quoting) = iphash("203.0.113.7", false).unwrap();
+        assert_eq!(quoting, QuoteMode::AsInput);
+        let dotted: Ipv4Addr = dotted.parse().unwrap();
+        let (int, _) = iphash(&u32::from(Ipv4Addr::new(203, 0, 113, 7)).to_string(), false).unwrap();
+        assert_eq!(int, u32::from(dotted).to_string());
+        let (hex, quoting) = iphash("0xCB007107", false).unwrap();
+        assert_eq!(quoting, QuoteMode::ForceFalse);
+        assert_eq!(hex, format!("0x{:08X}", u32::from(dotted)));
+
+        let (v6, _) = iphash("2a00:1450:4007:80e::200e", false).unwrap();
+        let v6: Ipv6Addr = v6.parse().unwrap();
+        let (hex, _) = iphash("0x2a0014504007080e000000000000200e", false).unwrap();
+        assert_eq!(hex, format!("0x{:032X}", u128::from(v6)));
+
+        assert!(iphash("not an ip", false).is_none());
+        assert!(iphash("0x1234", false).is_none());
+        assert!(iphash("99999999999", false).is_none());
+    }
+
+    #[test]
+    fn test_keep_private() {
+        for addr in ["10.1.2.3", "192.168.0.1", "127.0.0.1", "100.64.0.1", "::1", "fd00::1", "fe80::1", "::ffff:10.0.0.1", "0x0A000001"] {
+            assert_eq!(iphash(addr, true).unwrap().0, addr);
+            assert_ne!(iphash(addr, false).unwrap().0, addr);
+        }
+        assert_ne!(iphash("8.8.8.8", true).unwrap().0, "8.8.8.8");
+    }
+}
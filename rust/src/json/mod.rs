@@ -1,10 +1,15 @@
 pub mod types;
 pub mod parser;
 pub mod path;
+pub mod preserve;
 
 pub use types::JsonValue;
-pub use parser::json_parse_string;
+pub use parser::{json_parse_spanned, json_parse_string, Span};
 pub use path::{
-    json_get_string_at_path, json_replace_value_at_path, json_anonymize_path,
-    json_path_has_wildcards, json_to_string, remove_json_backslash, add_json_backslash,
+    json_get_string_at_path, json_replace_value_at_path, json_anonymize_path, json_anonymize,
+    json_anonymize_with, json_remove,
+    json_escape_string, json_for_each_match, json_path_has_wildcards, json_to_string,
+    json_unescape_string, remove_json_backslash,
+    add_json_backslash, path_locations, JsonPath, PathNode,
 };
+pub use preserve::JsonDocument;
@@ -20,11 +20,20 @@ enum Token {
 struct Lexer<'a> {
     input: &'a [u8],
     pos: usize,
+    /// Offset of the first byte of the last token read
+    token_start: usize,
+    /// Read integers that do not fit in an i64 as floats instead of failing
+    big_numbers: bool,
 }
 
 impl<'a> Lexer<'a> {
     fn new(input: &'a [u8]) -> Self {
-        Lexer { input, pos: 0 }
+        Lexer {
+            input,
+            pos: 0,
+            token_start: 0,
+            big_numbers: false,
+        }
     }
 
     fn peek_byte(&self) -> Option<u8> {
@@ -57,20 +66,20 @@ impl<'a> Lexer<'a> {
 
     fn read_string(&mut self) -> Result<String, String> {
         // opening " already consumed
-        let mut s = String::new();
+        let mut s = Vec::new();
         loop {
             match self.advance() {
                 None => return Err("Unterminated string".to_string()),
-                Some(b'"') => return Ok(s),
+                Some(b'"') => return Ok(String::from_utf8_lossy(&s).into_owned()),
                 Some(b'\\') => {
                     // Copy escape sequences literally (matching C behavior)
-                    s.push('\\');
+                    s.push(b'\\');
                     match self.advance() {
                         None => return Err("Unterminated string escape".to_string()),
-                        Some(c) => s.push(c as char),
+                        Some(c) => s.push(c),
                     }
                 }
-                Some(c) => s.push(c as char),
+                Some(c) => s.push(c),
             }
         }
     }
@@ -111,6 +120,10 @@ impl<'a> Lexer<'a> {
         } else {
             match s.parse::<i64>() {
                 Ok(v) => Ok(Token::NumberInt(v)),
+                Err(_) if self.big_numbers => s
+                    .parse::<f64>()
+                    .map(Token::NumberFloat)
+                    .map_err(|_| format!("Invalid integer: {}", s)),
                 Err(_) => Err(format!("Invalid integer: {}", s)),
             }
         }
@@ -118,6 +131,7 @@ impl<'a> Lexer<'a> {
 
     fn next_token(&mut self) -> Result<Token, String> {
         self.skip_whitespace();
+        self.token_start = self.pos;
         match self.advance() {
             None => Ok(Token::Eof),
             Some(b'{') => Ok(Token::LBrace),
@@ -163,27 +177,55 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// Byte range of a parsed value in the input, with the ranges of its children.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Span {
+    /// First byte of the member key for an object member, else same as `start`
+    pub key_start: usize,
+    pub start: usize,
+    pub end: usize,
+    /// Spans of the object members or array elements, in document order
+    pub children: Vec<Span>,
+}
+
 struct Parser<'a> {
     lexer: Lexer<'a>,
     current: Token,
+    /// Byte range of the current token
+    start: usize,
+    end: usize,
+    /// Record the spans of nested values
+    record_spans: bool,
 }
 
 impl<'a> Parser<'a> {
-    fn new(input: &'a [u8]) -> Result<Self, String> {
+    fn new(input: &'a [u8], record_spans: bool) -> Result<Self, String> {
         let mut lexer = Lexer::new(input);
+        // Spans are used to keep the original text, so precision does not matter
+        lexer.big_numbers = record_spans;
         let current = lexer.next_token()?;
-        Ok(Parser { lexer, current })
+        Ok(Parser {
+            start: lexer.token_start,
+            end: lexer.pos,
+            lexer,
+            current,
+            record_spans,
+        })
     }
 
     fn advance(&mut self) -> Result<(), String> {
         self.current = self.lexer.next_token()?;
+        self.start = self.lexer.token_start;
+        self.end = self.lexer.pos;
         Ok(())
     }
 
-    fn parse_value(&mut self) -> Result<JsonValue, String> {
+    fn parse_value(&mut self, span: &mut Span) -> Result<JsonValue, String> {
+        span.start = self.start;
+        span.end = self.end;
         match &self.current {
-            Token::LBrace => self.parse_object(),
-            Token::LBracket => self.parse_array(),
+            Token::LBrace => self.parse_object(span),
+            Token::LBracket => self.parse_array(span),
             Token::String(s) => {
                 let val = JsonValue::String(s.clone());
                 self.advance()?;
@@ -215,12 +257,24 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_object(&mut self) -> Result<JsonValue, String> {
+    /// Parse a member or element value, recording its span if requested.
+    fn parse_child(&mut self, parent: &mut Span, key_start: usize) -> Result<JsonValue, String> {
+        let mut span = Span::default();
+        let value = self.parse_value(&mut span)?;
+        if self.record_spans {
+            span.key_start = key_start;
+            parent.children.push(span);
+        }
+        Ok(value)
+    }
+
+    fn parse_object(&mut self, span: &mut Span) -> Result<JsonValue, String> {
         // consume '{'
         self.advance()?;
         let mut members = Vec::new();
 
         if self.current == Token::RBrace {
+            span.end = self.end;
             self.advance()?;
             return Ok(JsonValue::Object(members));
         }
@@ -230,6 +284,7 @@ impl<'a> Parser<'a> {
                 Token::String(s) => s.clone(),
                 _ => return Err(format!("Expected string key, got {:?}", self.current)),
             };
+            let key_start = self.start;
             self.advance()?;
 
             if self.current != Token::Colon {
@@ -237,10 +292,11 @@ impl<'a> Parser<'a> {
             }
             self.advance()?;
 
-            let value = self.parse_value()?;
+            let value = self.parse_child(span, key_start)?;
             members.push((key, value));
 
             if self.current == Token::RBrace {
+                span.end = self.end;
                 self.advance()?;
                 return Ok(JsonValue::Object(members));
             }
@@ -252,21 +308,23 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_array(&mut self) -> Result<JsonValue, String> {
+    fn parse_array(&mut self, span: &mut Span) -> Result<JsonValue, String> {
         // consume '['
         self.advance()?;
         let mut elements = Vec::new();
 
         if self.current == Token::RBracket {
+            span.end = self.end;
             self.advance()?;
             return Ok(JsonValue::Array(elements));
         }
 
         loop {
-            let value = self.parse_value()?;
+            let value = self.parse_child(span, self.start)?;
             elements.push(value);
 
             if self.current == Token::RBracket {
+                span.end = self.end;
                 self.advance()?;
                 return Ok(JsonValue::Array(elements));
             }
@@ -280,20 +338,55 @@ impl<'a> Parser<'a> {
 }
 
 pub fn json_parse_string(input: &str) -> Option<JsonValue> {
-    let mut parser = match Parser::new(input.as_bytes()) {
+    let mut parser = match Parser::new(input.as_bytes(), false) {
         Ok(p) => p,
         Err(_) => return None,
     };
-    match parser.parse_value() {
-        Ok(v) => Some(v),
-        Err(_) => None,
+    parser.parse_value(&mut Span::default()).ok()
+}
+
+/// Parse a JSON document, also returning the byte span of every value.
+/// Anything but whitespace after the document is an error.
+pub fn json_parse_spanned(input: &str) -> Option<(JsonValue, Span)> {
+    let mut parser = Parser::new(input.as_bytes(), true).ok()?;
+    let mut span = Span::default();
+    let value = parser.parse_value(&mut span).ok()?;
+    if parser.current != Token::Eof {
+        return None;
     }
+    Some((value, span))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_multibyte_string() {
+        match json_parse_string(r#"{"city":"Kraków","emoji":"\u00e9 ✓"}"#).unwrap() {
+            JsonValue::Object(members) => {
+                assert!(matches!(&members[0].1, JsonValue::String(s) if s == "Kraków"));
+                assert!(matches!(&members[1].1, JsonValue::String(s) if s == "\\u00e9 ✓"));
+            }
+            _ => panic!("Expected object"),
+        }
+    }
+
+    #[test]
+    fn test_parse_spanned() {
+        let input = r#"{ "a": [1, 2.50], "b" :"x" }"#;
+        let (_, span) = json_parse_spanned(input).unwrap();
+        assert_eq!((span.start, span.end), (0, input.len()));
+        let a = &span.children[0];
+        assert_eq!(&input[a.key_start..a.end], r#""a": [1, 2.50]"#);
+        assert_eq!(&input[a.children[1].start..a.children[1].end], "2.50");
+        let b = &span.children[1];
+        assert_eq!(&input[b.start..b.end], r#""x""#);
+        assert!(json_parse_spanned("[1] 2").is_none());
+        assert!(json_parse_spanned("[12345678901234567890]").is_some());
+        assert!(json_parse_string("[12345678901234567890]").is_none());
+    }
+
     #[test]
     fn test_parse_simple_object() {
         let json = json_parse_string(r#"{"key": "value"}"#).unwrap();
@@ -1,157 +1,320 @@
 use super::types::JsonValue;
 use crate::anonymize::anonymize_token;
-use crate::config::AnonBase;
+use crate::config::{AnonBase, AnonType};
 
-/// Check if a JSON path contains wildcards (i.e., `[]`).
-pub fn json_path_has_wildcards(path: &str) -> bool {
-    path.contains("[]")
+/// What a path segment selects in the current value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    /// `.name` or `['name']`: member of an object
+    Key(String),
+    /// `[n]`: element of an array
+    Index(usize),
+    /// `[]`: every element of an array
+    AllElements,
+    /// `*` or `[*]`: every member of an object or element of an array
+    Wildcard,
 }
 
-/// Get a string value at a given JSON path.
-pub fn json_get_string_at_path(root: &JsonValue, path: &str) -> Option<String> {
-    let path = path.strip_prefix('.').unwrap_or(path);
-    get_value_at_path(root, path).and_then(|v| match v {
-        JsonValue::String(s) => Some(s.clone()),
-        _ => None,
-    })
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub selector: Selector,
+    /// Set for `..` (recursive descent): the selector applies at any depth.
+    pub descendant: bool,
 }
 
-fn get_value_at_path<'a>(value: &'a JsonValue, path: &str) -> Option<&'a JsonValue> {
-    if path.is_empty() {
-        return Some(value);
-    }
+/// A compiled JSON path.
+///
+/// Supported syntax: `name`, `.name`, `$.name`, `['quoted key']`, `["quoted key"]`,
+/// `[n]`, `[]`, `*`, `[*]` and `..` (recursive descent), e.g. `..email`,
+/// `addresses[].street`, `$['first-name']`, `settings.*`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    pub segments: Vec<Segment>,
+}
 
-    // Extract segment
-    let (segment, rest) = split_path_segment(path);
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
 
-    match value {
-        JsonValue::Object(members) => {
-            for (key, val) in members {
-                if key == segment {
-                    return get_value_at_path(val, rest);
-                }
-            }
-            None
+impl JsonPath {
+    pub fn parse(path: &str) -> Result<JsonPath, String> {
+        let chars: Vec<char> = path.chars().collect();
+        let mut segments = Vec::new();
+        let mut pos = 0;
+
+        if chars.first() == Some(&'$') {
+            pos = 1;
+        } else if chars.first().is_some_and(|&c| is_name_char(c) || c == '*') {
+            // Bare leading name, as in 'email'
+            let (selector, end) = Self::parse_name(&chars, pos)?;
+            segments.push(Segment {
+                selector,
+                descendant: false,
+            });
+            pos = end;
         }
-        JsonValue::Array(elements) => {
-            if segment.starts_with('[') {
-                let idx_str = &segment[1..segment.len() - 1];
-                if let Ok(idx) = idx_str.parse::<usize>() {
-                    if idx < elements.len() {
-                        return get_value_at_path(&elements[idx], rest);
+
+        while pos < chars.len() {
+            match chars[pos] {
+                '.' => {
+                    let descendant = chars.get(pos + 1) == Some(&'.');
+                    pos += if descendant { 2 } else { 1 };
+                    match chars.get(pos) {
+                        // `..[...]`, or `.[...]` which is the same as `[...]`
+                        Some('[') => {
+                            let (selector, end) = Self::parse_bracket(&chars, pos)?;
+                            segments.push(Segment { selector, descendant });
+                            pos = end;
+                        }
+                        // A lone dot designates the root
+                        None if !descendant && pos == 1 => {}
+                        _ => {
+                            let (selector, end) = Self::parse_name(&chars, pos)?;
+                            segments.push(Segment { selector, descendant });
+                            pos = end;
+                        }
                     }
                 }
+                '[' => {
+                    let (selector, end) = Self::parse_bracket(&chars, pos)?;
+                    segments.push(Segment {
+                        selector,
+                        descendant: false,
+                    });
+                    pos = end;
+                }
+                c => return Err(format!("unexpected character '{}'", c)),
             }
-            None
         }
-        _ => None,
-    }
-}
 
-/// Replace the string value at a given JSON path.
-pub fn json_replace_value_at_path(root: &mut JsonValue, path: &str, new_value: &str) {
-    let path = path.strip_prefix('.').unwrap_or(path);
-    set_value_at_path(root, path, new_value);
-}
+        Ok(JsonPath { segments })
+    }
 
-fn set_value_at_path(value: &mut JsonValue, path: &str, new_value: &str) -> bool {
-    if path.is_empty() {
-        if let JsonValue::String(ref mut s) = value {
-            *s = new_value.to_string();
-            return true;
+    /// Parse a bare member name or `*` starting at `pos`.
+    fn parse_name(chars: &[char], pos: usize) -> Result<(Selector, usize), String> {
+        if chars.get(pos) == Some(&'*') {
+            return Ok((Selector::Wildcard, pos + 1));
         }
-        return false;
-    }
-
-    // Handle array wildcard at start
-    if path.starts_with("[]") {
-        if let JsonValue::Array(ref mut elements) = value {
-            let remaining = &path[2..];
-            let remaining = remaining.strip_prefix('.').unwrap_or(remaining);
-            let mut any_success = false;
-            for elem in elements.iter_mut() {
-                if set_value_at_path(elem, remaining, new_value) {
-                    any_success = true;
-                }
-            }
-            return any_success;
+        let mut end = pos;
+        while end < chars.len() && is_name_char(chars[end]) {
+            end += 1;
         }
-        return false;
+        if end == pos {
+            return match chars.get(pos) {
+                Some(c) => Err(format!("unexpected character '{}'", c)),
+                None => Err("missing member name at end of path".to_string()),
+            };
+        }
+        Ok((Selector::Key(chars[pos..end].iter().collect()), end))
     }
 
-    // Extract segment
-    let (segment, rest) = split_path_segment(path);
-
-    match value {
-        JsonValue::Object(ref mut members) => {
-            for (key, val) in members.iter_mut() {
-                if key.as_str() == segment {
-                    // Check if rest starts with array access
-                    if rest.starts_with('[') {
-                        if rest.starts_with("[]") {
-                            if let JsonValue::Array(ref mut elements) = val {
-                                let remaining = &rest[2..];
-                                let remaining =
-                                    remaining.strip_prefix('.').unwrap_or(remaining);
-                                let mut any_success = false;
-                                for elem in elements.iter_mut() {
-                                    if set_value_at_path(elem, remaining, new_value) {
-                                        any_success = true;
-                                    }
-                                }
-                                return any_success;
-                            }
-                        } else {
-                            // Specific index [n]
-                            if let Some(end_bracket) = rest.find(']') {
-                                let idx_str = &rest[1..end_bracket];
-                                if let Ok(idx) = idx_str.parse::<usize>() {
-                                    if let JsonValue::Array(ref mut elements) = val {
-                                        if idx < elements.len() {
-                                            let remaining = &rest[end_bracket + 1..];
-                                            let remaining = remaining
-                                                .strip_prefix('.')
-                                                .unwrap_or(remaining);
-                                            return set_value_at_path(
-                                                &mut elements[idx],
-                                                remaining,
-                                                new_value,
-                                            );
-                                        }
-                                    }
-                                }
+    /// Parse a bracketed selector (`[]`, `[*]`, `[n]`, `['key']`) starting at `pos`.
+    fn parse_bracket(chars: &[char], pos: usize) -> Result<(Selector, usize), String> {
+        let mut i = pos + 1;
+        let selector = match chars.get(i) {
+            Some(']') => Selector::AllElements,
+            Some('*') => {
+                i += 1;
+                Selector::Wildcard
+            }
+            Some(&q) if q == '\'' || q == '"' => {
+                i += 1;
+                let mut key = String::new();
+                loop {
+                    match chars.get(i) {
+                        None => return Err("unterminated quoted key".to_string()),
+                        Some('\\') => {
+                            match chars.get(i + 1) {
+                                Some(&c) => key.push(c),
+                                None => return Err("unterminated quoted key".to_string()),
                             }
+                            i += 2;
                         }
-                        return false;
-                    } else {
-                        return set_value_at_path(val, rest, new_value);
-                    }
-                }
-            }
-            false
-        }
-        JsonValue::Array(ref mut elements) => {
-            if segment.starts_with('[') {
-                if segment == "[]" {
-                    let mut any_success = false;
-                    for elem in elements.iter_mut() {
-                        if set_value_at_path(elem, rest, new_value) {
-                            any_success = true;
+                        Some(&c) if c == q => {
+                            i += 1;
+                            break;
                         }
-                    }
-                    return any_success;
-                } else {
-                    let idx_str = &segment[1..segment.len() - 1];
-                    if let Ok(idx) = idx_str.parse::<usize>() {
-                        if idx < elements.len() {
-                            return set_value_at_path(&mut elements[idx], rest, new_value);
+                        Some(&c) => {
+                            key.push(c);
+                            i += 1;
                         }
                     }
                 }
+                Selector::Key(key)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let digits: String = chars[start..i].iter().collect();
+                let index = digits
+                    .parse::<usize>()
+                    .map_err(|_| format!("invalid array index '{}'", digits))?;
+                Selector::Index(index)
             }
-            false
+            Some(c) => return Err(format!("unexpected character '{}' after '['", c)),
+            None => return Err("unterminated '['".to_string()),
+        };
+        if chars.get(i) != Some(&']') {
+            return Err("expected ']'".to_string());
         }
-        _ => false,
+        Ok((selector, i + 1))
+    }
+
+    /// True if the path may match more than one value.
+    pub fn has_wildcards(&self) -> bool {
+        self.segments.iter().any(|s| {
+            s.descendant || matches!(s.selector, Selector::AllElements | Selector::Wildcard)
+        })
+    }
+}
+
+/// Check if a JSON path contains wildcards (`[]`, `*` or `..`).
+pub fn json_path_has_wildcards(path: &str) -> bool {
+    JsonPath::parse(path).is_ok_and(|p| p.has_wildcards())
+}
+
+/// Call `f` on every value matched by `path`, once each. Returns the number
+/// of matches.
+pub fn json_for_each_match(
+    root: &mut JsonValue,
+    path: &JsonPath,
+    f: &mut dyn FnMut(&mut JsonValue),
+) -> usize {
+    let locations = path_locations(&*root, path);
+    for location in &locations {
+        f(node_mut(root, location));
+    }
+    locations.len()
+}
+
+fn node_mut<'v>(mut value: &'v mut JsonValue, location: &[usize]) -> &'v mut JsonValue {
+    for &i in location {
+        value = match value {
+            JsonValue::Object(members) => &mut members[i].1,
+            JsonValue::Array(elements) => &mut elements[i],
+            _ => unreachable!("locations only go through containers"),
+        };
+    }
+    value
+}
+
+/// Read-only view of a tree that paths can be matched against.
+pub trait PathNode {
+    /// True for an object, whose children are keyed members
+    fn is_map(&self) -> bool;
+    /// True for an array, whose children are indexed elements
+    fn is_list(&self) -> bool;
+    fn child_count(&self) -> usize;
+    /// Key of the `i`-th member of an object
+    fn child_key(&self, i: usize) -> Option<&str>;
+    fn child(&self, i: usize) -> &Self;
+}
+
+impl PathNode for JsonValue {
+    fn is_map(&self) -> bool {
+        matches!(self, JsonValue::Object(_))
+    }
+
+    fn is_list(&self) -> bool {
+        matches!(self, JsonValue::Array(_))
+    }
+
+    fn child_count(&self) -> usize {
+        match self {
+            JsonValue::Object(members) => members.len(),
+            JsonValue::Array(elements) => elements.len(),
+            _ => 0,
+        }
+    }
+
+    fn child_key(&self, i: usize) -> Option<&str> {
+        match self {
+            JsonValue::Object(members) => Some(&members[i].0),
+            _ => None,
+        }
+    }
+
+    fn child(&self, i: usize) -> &JsonValue {
+        match self {
+            JsonValue::Object(members) => &members[i].1,
+            JsonValue::Array(elements) => &elements[i],
+            _ => unreachable!("scalars have no children"),
+        }
+    }
+}
+
+/// Locate every node matched by `path`, as lists of child indexes from the
+/// root. A path with several `..` segments may reach a node by more than
+/// one route: each node is only listed once. Descendants come before their
+/// ancestors, and later siblings before earlier ones, so that replacing or
+/// removing the children of a node leaves the locations still to visit valid.
+pub fn path_locations<N: PathNode>(root: &N, path: &JsonPath) -> Vec<Vec<usize>> {
+    let mut found = Vec::new();
+    locate_segments(root, &path.segments, &mut Vec::new(), &mut found);
+    found.sort_unstable_by(|a, b| b.cmp(a));
+    found.dedup();
+    found
+}
+
+fn locate_segments<N: PathNode>(
+    node: &N,
+    segments: &[Segment],
+    location: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        found.push(location.clone());
+        return;
+    };
+
+    let selected: Vec<usize> = match &segment.selector {
+        Selector::Key(name) if node.is_map() => (0..node.child_count())
+            .filter(|&i| node.child_key(i) == Some(name.as_str()))
+            .collect(),
+        Selector::Index(idx) if node.is_list() && *idx < node.child_count() => vec![*idx],
+        Selector::AllElements if node.is_list() => (0..node.child_count()).collect(),
+        Selector::Wildcard if node.is_list() || node.is_map() => {
+            (0..node.child_count()).collect()
+        }
+        _ => Vec::new(),
+    };
+    for i in selected {
+        location.push(i);
+        locate_segments(node.child(i), rest, location, found);
+        location.pop();
+    }
+
+    if segment.descendant {
+        // Keep looking for the same segment below every child
+        for i in 0..node.child_count() {
+            location.push(i);
+            locate_segments(node.child(i), segments, location, found);
+            location.pop();
+        }
+    }
+}
+
+/// Get a string value at a given JSON path: the first string matched.
+pub fn json_get_string_at_path(root: &JsonValue, path: &str) -> Option<String> {
+    let path = JsonPath::parse(path).ok()?;
+    path_locations(root, &path).iter().rev().find_map(|location| {
+        match location.iter().fold(root, |node, &i| node.child(i)) {
+            JsonValue::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    })
+}
+
+/// Replace the string values at a given JSON path.
+pub fn json_replace_value_at_path(root: &mut JsonValue, path: &str, new_value: &str) {
+    if let Ok(path) = JsonPath::parse(path) {
+        json_for_each_match(root, &path, &mut |v| {
+            if let JsonValue::String(s) = v {
+                *s = new_value.to_string();
+            }
+        });
     }
 }
 
@@ -162,125 +325,180 @@ pub fn json_anonymize_path(
     config: &AnonBase,
     secret: &[u8],
 ) {
-    let path = path.strip_prefix('.').unwrap_or(path);
-    anonymize_at_path(root, path, config, secret);
+    if let Ok(path) = JsonPath::parse(path) {
+        json_anonymize(root, &path, config, secret);
+    }
 }
 
-fn anonymize_at_path(
-    value: &mut JsonValue,
-    path: &str,
+/// Anonymize all values matched by a compiled path.
+///
+/// Strings are anonymized as before. Other values keep their JSON type where
+/// the rule allows it: `inthash` yields a number, `fixed null` yields `null`
+/// and a `fixed` value that reads as a JSON literal (`42`, `true`, `null`)
+/// replaces a non-string value with that literal, converted to a boolean
+/// or a number when it replaces one. Objects and arrays can only
+/// be replaced by `fixed` rules or dropped by `remove`. Returns the number of
+/// values anonymized.
+pub fn json_anonymize(
+    root: &mut JsonValue,
+    path: &JsonPath,
     config: &AnonBase,
     secret: &[u8],
-) -> bool {
-    if path.is_empty() {
-        if let JsonValue::String(ref mut s) = value {
-            use crate::config::AnonType;
-            if config.anon_type == AnonType::Fixed {
-                *s = config.fixed_value.clone();
-            } else {
-                let res = anonymize_token(false, config, s.as_bytes(), secret, None);
-                *s = String::from_utf8_lossy(&res.data).to_string();
-            }
-            return true;
+) -> usize {
+    json_anonymize_with(root, path, config, &mut |token| {
+        Some(anonymize_token(false, config, token, secret, None).data)
+    })
+}
+
+/// Same as `json_anonymize`, with `anonymize` computing the anonymized text
+/// of a scalar for the rules that depend on the original value, or `None`
+/// to leave it unchanged.
+pub fn json_anonymize_with(
+    root: &mut JsonValue,
+    path: &JsonPath,
+    config: &AnonBase,
+    anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+) -> usize {
+    if config.anon_type == AnonType::Remove {
+        return json_remove(root, path);
+    }
+    let mut anonymized = 0;
+    json_for_each_match(root, path, &mut |v| {
+        if let Some(new_value) = anonymize_json_value(v, None, config, anonymize) {
+            *v = new_value;
+            anonymized += 1;
         }
-        return false;
-    }
-
-    // Handle array wildcard at start
-    if path.starts_with("[]") {
-        if let JsonValue::Array(ref mut elements) = value {
-            let remaining = &path[2..];
-            let remaining = remaining.strip_prefix('.').unwrap_or(remaining);
-            let mut any_success = false;
-            for elem in elements.iter_mut() {
-                if anonymize_at_path(elem, remaining, config, secret) {
-                    any_success = true;
-                }
+    });
+    anonymized
+}
+
+/// Compute the replacement for a matched value, or `None` if the rule does
+/// not apply to this kind of value. `text` is the value as found in the
+/// document, when known; numbers are otherwise anonymized as re-serialized.
+/// Strings of the returned value are JSON-escaped, as the parser keeps them.
+pub(crate) fn anonymize_json_value(
+    value: &JsonValue,
+    text: Option<&str>,
+    config: &AnonBase,
+    anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+) -> Option<JsonValue> {
+    let is_fixed = matches!(
+        config.anon_type,
+        AnonType::Fixed | AnonType::FixedQuoted | AnonType::FixedUnquoted
+    );
+    match value {
+        _ if config.anon_type == AnonType::FixedNull => Some(JsonValue::Null),
+        JsonValue::String(_) if is_fixed => Some(JsonValue::String(json_escape_string(&config.fixed_value))),
+        _ if is_fixed => Some(match json_literal(&config.fixed_value) {
+            Some(literal) => coerce_literal(literal, value),
+            None => JsonValue::String(json_escape_string(&config.fixed_value)),
+        }),
+        // A JSON document held in the string is edited in its escaped form
+        JsonValue::String(s) if config.anon_type == AnonType::Json => Some(JsonValue::String(
+            String::from_utf8_lossy(&anonymize(s.as_bytes())?).to_string(),
+        )),
+        // Other rules see the decoded string, whatever escapes it was written with
+        JsonValue::String(s) => {
+            let new = anonymize(json_unescape_string(s).as_bytes())?;
+            Some(JsonValue::String(json_escape_string(&String::from_utf8_lossy(&new))))
+        }
+        JsonValue::Null | JsonValue::Object(_) | JsonValue::Array(_) => None,
+        scalar => {
+            let original = text.map_or_else(|| json_to_string(scalar), str::to_string);
+            let text = String::from_utf8_lossy(&anonymize(original.as_bytes())?).to_string();
+            // Keep the value's type when the result still reads as one
+            match (json_literal(&text), scalar) {
+                (Some(n @ (JsonValue::Int(_) | JsonValue::Float(_))), JsonValue::Int(_))
+                | (Some(n @ (JsonValue::Int(_) | JsonValue::Float(_))), JsonValue::Float(_))
+                | (Some(n @ JsonValue::Bool(_)), JsonValue::Bool(_)) => Some(n),
+                _ => Some(JsonValue::String(json_escape_string(&text))),
             }
-            return any_success;
         }
-        return false;
     }
+}
 
-    // Extract segment
-    let (segment, rest) = split_path_segment(path);
+/// Remove every object member or array element matched by `path`.
+/// Returns the number of values removed.
+pub fn json_remove(root: &mut JsonValue, path: &JsonPath) -> usize {
+    // The root itself cannot be removed
+    let Some((last, parents)) = path.segments.split_last() else {
+        return 0;
+    };
+    let parents = JsonPath {
+        segments: parents.to_vec(),
+    };
+    let mut removed = 0;
+    for location in path_locations(&*root, &parents) {
+        removed += remove_children(node_mut(root, &location), last);
+    }
+    removed
+}
 
-    match value {
-        JsonValue::Object(ref mut members) => {
-            for (key, val) in members.iter_mut() {
-                if key.as_str() == segment {
-                    if rest.starts_with('[') {
-                        if rest.starts_with("[]") {
-                            if let JsonValue::Array(ref mut elements) = val {
-                                let remaining = &rest[2..];
-                                let remaining =
-                                    remaining.strip_prefix('.').unwrap_or(remaining);
-                                let mut any_success = false;
-                                for elem in elements.iter_mut() {
-                                    if anonymize_at_path(
-                                        elem, remaining, config, secret,
-                                    ) {
-                                        any_success = true;
-                                    }
-                                }
-                                return any_success;
-                            }
-                        }
-                        return false;
-                    } else {
-                        return anonymize_at_path(val, rest, config, secret);
-                    }
+fn remove_children(value: &mut JsonValue, segment: &Segment) -> usize {
+    let mut removed = match (&segment.selector, &mut *value) {
+        // Every member with that key, should the name be repeated
+        (Selector::Key(name), JsonValue::Object(members)) => {
+            let before = members.len();
+            members.retain(|(key, _)| key != name);
+            before - members.len()
+        }
+        (Selector::Index(idx), JsonValue::Array(elements)) if *idx < elements.len() => {
+            elements.remove(*idx);
+            1
+        }
+        (Selector::AllElements | Selector::Wildcard, JsonValue::Array(elements)) => {
+            std::mem::take(elements).len()
+        }
+        (Selector::Wildcard, JsonValue::Object(members)) => std::mem::take(members).len(),
+        _ => 0,
+    };
+
+    if segment.descendant {
+        match value {
+            JsonValue::Object(members) => {
+                for (_, child) in members.iter_mut() {
+                    removed += remove_children(child, segment);
                 }
             }
-            false
-        }
-        JsonValue::Array(ref mut elements) => {
-            if segment.starts_with('[') {
-                if segment == "[]" {
-                    let mut any_success = false;
-                    for elem in elements.iter_mut() {
-                        if anonymize_at_path(elem, rest, config, secret) {
-                            any_success = true;
-                        }
-                    }
-                    return any_success;
+            JsonValue::Array(elements) => {
+                for child in elements.iter_mut() {
+                    removed += remove_children(child, segment);
                 }
             }
-            false
+            _ => {}
         }
-        _ => false,
     }
-}
 
-/// Split a path into the first segment and the rest.
-fn split_path_segment(path: &str) -> (&str, &str) {
-    if path.is_empty() {
-        return ("", "");
-    }
+    removed
+}
 
-    // Handle array bracket at start
-    if path.starts_with('[') {
-        if let Some(end) = path.find(']') {
-            let segment = &path[..=end];
-            let rest = &path[end + 1..];
-            let rest = rest.strip_prefix('.').unwrap_or(rest);
-            return (segment, rest);
-        }
+/// Give a `fixed` literal the type of the boolean or number it replaces:
+/// numbers become `true` unless 0, booleans become 1 or 0.
+fn coerce_literal(literal: JsonValue, value: &JsonValue) -> JsonValue {
+    match (literal, value) {
+        (JsonValue::Int(n), JsonValue::Bool(_)) => JsonValue::Bool(n != 0),
+        (JsonValue::Float(f), JsonValue::Bool(_)) => JsonValue::Bool(f != 0.0),
+        (JsonValue::Bool(b), JsonValue::Int(_) | JsonValue::Float(_)) => JsonValue::Int(b as i64),
+        (literal, _) => literal,
     }
+}
 
-    // Regular field name
-    let mut end = path.len();
-    for (i, c) in path.char_indices() {
-        if c == '.' || c == '[' {
-            end = i;
-            break;
+/// Parse a config value as a JSON scalar literal (number, boolean or null).
+fn json_literal(text: &str) -> Option<JsonValue> {
+    match text {
+        "null" => Some(JsonValue::Null),
+        "true" => Some(JsonValue::Bool(true)),
+        "false" => Some(JsonValue::Bool(false)),
+        _ if text.starts_with(|c: char| c == '-' || c.is_ascii_digit())
+            && text.chars().all(|c| c.is_ascii_digit() || "-+.eE".contains(c)) =>
+        {
+            match text.parse::<i64>() {
+                Ok(n) => Some(JsonValue::Int(n)),
+                Err(_) => text.parse::<f64>().ok().map(JsonValue::Float),
+            }
         }
+        _ => None,
     }
-
-    let segment = &path[..end];
-    let rest = &path[end..];
-    let rest = rest.strip_prefix('.').unwrap_or(rest);
-    (segment, rest)
 }
 
 /// Remove JSON backslash escaping (SQL-stored JSON layer).
@@ -292,12 +510,12 @@ pub fn remove_json_backslash(src: &str) -> String {
     let mut result = Vec::with_capacity(len);
     let mut backslash_count: i32 = 0;
 
-    for i in 0..len {
-        if bytes[i] != b'\\' {
+    for &b in bytes {
+        if b != b'\\' {
             if backslash_count == 1 {
                 backslash_count = 0;
             }
-            result.push(bytes[i]);
+            result.push(b);
         } else {
             backslash_count += 1;
             if backslash_count % 2 == 0 {
@@ -323,6 +541,30 @@ pub fn add_json_backslash(src: &str) -> String {
     result
 }
 
+/// Escape text to be the content of a JSON string.
+pub fn json_escape_string(src: &str) -> String {
+    let mut result = String::with_capacity(src.len() + 2);
+    for c in src.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\x08' => result.push_str("\\b"),
+            '\x0c' => result.push_str("\\f"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Decode the content of a JSON string (`\n`, `\"`, `\u00e9`, ...).
+pub fn json_unescape_string(src: &str) -> String {
+    super::preserve::json_string_unescape(src).0
+}
+
 /// Serialize a JSON value to a compact JSON string (no spaces after `:` or `,`).
 pub fn json_to_string(value: &JsonValue) -> String {
     let mut buf = String::new();
@@ -424,6 +666,13 @@ mod tests {
         assert_eq!(json_get_string_at_path(&parsed, "missing"), None);
     }
 
+    #[test]
+    fn test_get_string_at_wildcard_path() {
+        let parsed = json_parse_string(r#"{"a":[{"id":1},{"email":2},{"email":"x@y.z"}]}"#).unwrap();
+        assert_eq!(json_get_string_at_path(&parsed, "..email"), Some("x@y.z".to_string()));
+        assert_eq!(json_get_string_at_path(&parsed, "a[*].id"), None);
+    }
+
     #[test]
     fn test_replace_value_at_path() {
         let mut parsed =
@@ -441,6 +690,313 @@ mod tests {
         assert!(!json_path_has_wildcards("contact.email"));
     }
 
+    #[test]
+    fn test_parse_path() {
+        let path = JsonPath::parse("$.users[0]['first-name']").unwrap();
+        assert_eq!(
+            path.segments.iter().map(|s| &s.selector).collect::<Vec<_>>(),
+            vec![
+                &Selector::Key("users".to_string()),
+                &Selector::Index(0),
+                &Selector::Key("first-name".to_string()),
+            ]
+        );
+        let path = JsonPath::parse("..email").unwrap();
+        assert!(path.segments[0].descendant);
+        assert!(JsonPath::parse("email").unwrap() == JsonPath::parse(".email").unwrap());
+        assert!(JsonPath::parse("a[x]").is_err());
+        assert!(JsonPath::parse("a.").is_err());
+    }
+
+    #[test]
+    fn test_path_has_new_wildcards() {
+        assert!(json_path_has_wildcards("..email"));
+        assert!(json_path_has_wildcards("settings.*"));
+        assert!(json_path_has_wildcards("items[*].id"));
+        assert!(!json_path_has_wildcards("items[2].id"));
+        assert!(!json_path_has_wildcards("['first-name']"));
+    }
+
+    fn replace_all(input: &str, path: &str) -> String {
+        let mut parsed = json_parse_string(input).unwrap();
+        json_replace_value_at_path(&mut parsed, path, "X");
+        json_to_string(&parsed)
+    }
+
+    #[test]
+    fn test_quoted_keys() {
+        let input = r#"{"first-name":"a","user name":{"e.mail":"b"},"c":"d"}"#;
+        assert_eq!(
+            replace_all(input, "['first-name']"),
+            r#"{"first-name":"X","user name":{"e.mail":"b"},"c":"d"}"#
+        );
+        assert_eq!(
+            replace_all(input, r#".["user name"]['e.mail']"#),
+            r#"{"first-name":"a","user name":{"e.mail":"X"},"c":"d"}"#
+        );
+    }
+
+    #[test]
+    fn test_object_wildcard() {
+        let input = r#"{"a":{"x":"1","y":"2"},"b":"3"}"#;
+        assert_eq!(replace_all(input, "a.*"), r#"{"a":{"x":"X","y":"X"},"b":"3"}"#);
+        assert_eq!(replace_all(input, "*"), r#"{"a":{"x":"1","y":"2"},"b":"X"}"#);
+    }
+
+    #[test]
+    fn test_recursive_descent() {
+        let input = r#"{"email":"a","contacts":[{"email":"b"},{"other":{"email":"c"}}]}"#;
+        assert_eq!(
+            replace_all(input, "..email"),
+            r#"{"email":"X","contacts":[{"email":"X"},{"other":{"email":"X"}}]}"#
+        );
+        assert_eq!(
+            replace_all(input, "contacts..email"),
+            r#"{"email":"a","contacts":[{"email":"X"},{"other":{"email":"X"}}]}"#
+        );
+    }
+
+    #[test]
+    fn test_array_index() {
+        let input = r#"{"items":[{"id":"a"},{"id":"b"}],"list":["x","y"]}"#;
+        assert_eq!(
+            replace_all(input, "items[1].id"),
+            r#"{"items":[{"id":"a"},{"id":"X"}],"list":["x","y"]}"#
+        );
+        assert_eq!(
+            replace_all(input, "list[0]"),
+            r#"{"items":[{"id":"a"},{"id":"b"}],"list":["X","y"]}"#
+        );
+        assert_eq!(
+            replace_all(input, "list[5]"),
+            r#"{"items":[{"id":"a"},{"id":"b"}],"list":["x","y"]}"#
+        );
+    }
+
+    fn anonymize_all(input: &str, path: &str, infos: &AnonBase) -> (String, usize) {
+        let mut parsed = json_parse_string(input).unwrap();
+        let path = JsonPath::parse(path).unwrap();
+        let count = json_anonymize(&mut parsed, &path, infos, b"secret");
+        (json_to_string(&parsed), count)
+    }
+
+    #[test]
+    fn test_inthash_keeps_numbers() {
+        let infos = AnonBase {
+            anon_type: AnonType::IntHash,
+            len: 6,
+            ..Default::default()
+        };
+        let (out, count) = anonymize_all(r#"{"id":12345,"ids":[1,2.5],"s":"42"}"#, "..*", &infos);
+        let parsed = json_parse_string(&out).unwrap();
+        let JsonValue::Object(members) = parsed else {
+            panic!("expected object");
+        };
+        assert!(matches!(members[0].1, JsonValue::Int(n) if (100000..1000000).contains(&n)));
+        let JsonValue::Array(ids) = &members[1].1 else {
+            panic!("expected array");
+        };
+        assert!(ids.iter().all(|v| matches!(v, JsonValue::Int(_))));
+        assert!(matches!(&members[2].1, JsonValue::String(s) if s.len() == 6));
+        // id, ids[0], ids[1] and s; the array itself is not a valid target
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_inthash_too_long_for_i64() {
+        let infos = AnonBase {
+            anon_type: AnonType::IntHash,
+            len: 25,
+            ..Default::default()
+        };
+        let (out, count) = anonymize_all(r#"{"id":7}"#, "id", &infos);
+        assert_eq!(count, 1);
+        let number = out
+            .strip_prefix(r#"{"id":"#)
+            .and_then(|rest| rest.strip_suffix('}'))
+            .unwrap();
+        assert_eq!(number.len(), 25);
+        assert!(number.bytes().all(|b| b.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_fixed_on_non_strings() {
+        let fixed = |value: &str| AnonBase {
+            anon_type: AnonType::Fixed,
+            fixed_value: value.to_string(),
+            ..Default::default()
+        };
+        let input = r#"{"n":5,"b":true,"o":{"k":"v"},"s":"x"}"#;
+        assert_eq!(
+            anonymize_all(input, "*", &fixed("0")).0,
+            r#"{"n":0,"b":false,"o":0,"s":"0"}"#
+        );
+        // Booleans stay booleans, numbers stay numbers
+        assert_eq!(
+            anonymize_all(input, "b", &fixed("1")).0,
+            r#"{"n":5,"b":true,"o":{"k":"v"},"s":"x"}"#
+        );
+        assert_eq!(
+            anonymize_all(input, "n", &fixed("true")).0,
+            r#"{"n":1,"b":true,"o":{"k":"v"},"s":"x"}"#
+        );
+        assert_eq!(
+            anonymize_all(input, "b", &fixed("false")).0,
+            r#"{"n":5,"b":false,"o":{"k":"v"},"s":"x"}"#
+        );
+        assert_eq!(
+            anonymize_all(input, "n", &fixed("hidden")).0,
+            r#"{"n":"hidden","b":true,"o":{"k":"v"},"s":"x"}"#
+        );
+        assert_eq!(
+            anonymize_all(input, "o", &fixed("-1.5")).0,
+            r#"{"n":5,"b":true,"o":-1.5,"s":"x"}"#
+        );
+    }
+
+    #[test]
+    fn test_fixed_null() {
+        let infos = AnonBase {
+            anon_type: AnonType::FixedNull,
+            ..Default::default()
+        };
+        let (out, count) = anonymize_all(r#"{"a":[1,2],"b":{"c":1},"d":"x"}"#, "*", &infos);
+        assert_eq!(out, r#"{"a":null,"b":null,"d":null}"#);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_hash_skips_containers_and_null() {
+        let infos = AnonBase {
+            anon_type: AnonType::TextHash,
+            len: 4,
+            ..Default::default()
+        };
+        let input = r#"{"a":[1],"b":{"c":"d"},"n":null}"#;
+        let (out, count) = anonymize_all(input, "*", &infos);
+        assert_eq!(out, input);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_texthash_on_number_gives_string() {
+        let infos = AnonBase {
+            anon_type: AnonType::TextHash,
+            len: 4,
+            ..Default::default()
+        };
+        let (out, count) = anonymize_all(r#"{"a":12,"b":false}"#, "*", &infos);
+        assert_eq!(count, 2);
+        let Some(JsonValue::Object(m)) = json_parse_string(&out) else {
+            panic!("expected object");
+        };
+        assert!(m.iter().all(|(_, v)| matches!(v, JsonValue::String(s) if s.len() == 4)));
+    }
+
+    fn remove_all(input: &str, path: &str) -> (String, usize) {
+        let mut parsed = json_parse_string(input).unwrap();
+        let count = json_remove(&mut parsed, &JsonPath::parse(path).unwrap());
+        (json_to_string(&parsed), count)
+    }
+
+    #[test]
+    fn test_remove() {
+        let input = r#"{"a":1,"s":{"token":"x","keep":[1,2,3]},"l":[{"token":1},{"b":2}]}"#;
+        assert_eq!(
+            remove_all(input, "s.token"),
+            (r#"{"a":1,"s":{"keep":[1,2,3]},"l":[{"token":1},{"b":2}]}"#.to_string(), 1)
+        );
+        assert_eq!(
+            remove_all(input, "..token"),
+            (r#"{"a":1,"s":{"keep":[1,2,3]},"l":[{},{"b":2}]}"#.to_string(), 2)
+        );
+        assert_eq!(
+            remove_all(input, "s.keep[1]"),
+            (r#"{"a":1,"s":{"token":"x","keep":[1,3]},"l":[{"token":1},{"b":2}]}"#.to_string(), 1)
+        );
+        assert_eq!(
+            remove_all(input, "s.keep[]"),
+            (r#"{"a":1,"s":{"token":"x","keep":[]},"l":[{"token":1},{"b":2}]}"#.to_string(), 3)
+        );
+        assert_eq!(remove_all(input, "missing"), (input.to_string(), 0));
+        assert_eq!(
+            remove_all(r#"{"t":1,"a":2,"t":3}"#, "t"),
+            (r#"{"a":2}"#.to_string(), 2)
+        );
+        assert_eq!(remove_all(input, "."), (input.to_string(), 0));
+    }
+
+    #[test]
+    fn test_value_reached_by_several_routes() {
+        // `..a..b` reaches the inner `b` through both `a`: it is handled once
+        let infos = AnonBase {
+            anon_type: AnonType::TextHash,
+            len: 5,
+            ..Default::default()
+        };
+        let input = r#"{"a":{"a":{"b":"x","c":[1,2]}}}"#;
+        let (out, count) = anonymize_all(input, "..a..b", &infos);
+        assert_eq!((out, count), anonymize_all(input, "a.a.b", &infos));
+        assert_eq!(count, 1);
+        assert_eq!(
+            remove_all(input, "..a..c[0]"),
+            (r#"{"a":{"a":{"b":"x","c":[2]}}}"#.to_string(), 1)
+        );
+    }
+
+    #[test]
+    fn test_anonymize_with_remove_rule() {
+        let infos = AnonBase {
+            anon_type: AnonType::Remove,
+            ..Default::default()
+        };
+        let (out, count) = anonymize_all(r#"{"a":1,"b":2}"#, "b", &infos);
+        assert_eq!(out, r#"{"a":1}"#);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_substring_keeps_number_type() {
+        let infos = AnonBase {
+            anon_type: AnonType::Substring,
+            len: 2,
+            ..Default::default()
+        };
+        let (out, _) = anonymize_all(r#"{"a":12345,"b":"12345"}"#, "*", &infos);
+        assert_eq!(out, r#"{"a":12,"b":"12"}"#);
+    }
+
+    #[test]
+    fn test_rules_see_decoded_strings() {
+        let infos = AnonBase {
+            anon_type: AnonType::Substring,
+            len: 2,
+            ..Default::default()
+        };
+        let (out, _) = anonymize_all(r#"{"a":"\u00e9t\u00e9","b":"\"q\"\n"}"#, "*", &infos);
+        assert_eq!(out, r#"{"a":"ét","b":"\"q"}"#);
+
+        // Same string, same hash, however it is escaped
+        let infos = AnonBase {
+            anon_type: AnonType::TextHash,
+            len: 8,
+            ..Default::default()
+        };
+        let (out, _) = anonymize_all(r#"["\u00e9\/","é/"]"#, "[]", &infos);
+        assert_eq!(out[1..11], out[12..22], "{}", out);
+    }
+
+    #[test]
+    fn test_json_escape_roundtrip() {
+        let text = "{\"a\":\"b\\\"c\"}\n\té\u{1}";
+        let escaped = json_escape_string(text);
+        assert_eq!(escaped, r#"{\"a\":\"b\\\"c\"}\n\té\u0001"#);
+        assert_eq!(json_unescape_string(&escaped), text);
+        assert_eq!(json_unescape_string(r"\u00e9\ud83d\ude00\/"), "é😀/");
+        // Invalid escapes are kept
+        assert_eq!(json_unescape_string(r"\u12"), r"\u12");
+    }
+
     #[test]
     fn test_remove_json_backslash() {
         assert_eq!(remove_json_backslash(r#"hello"#), "hello");
@@ -0,0 +1,383 @@
+use super::parser::{json_parse_spanned, Span};
+use super::path::{
+    add_json_backslash, anonymize_json_value, json_escape_string, json_to_string, path_locations,
+    JsonPath,
+};
+use super::types::JsonValue;
+use crate::anonymize::mysql_unescape;
+use crate::config::{AnonBase, AnonType};
+
+/// A JSON document anonymized in place.
+///
+/// The document is kept as its escaped text: SQL-escaped as found in the
+/// dump, or JSON-escaped for a document held in a JSON string. Rules only
+/// rewrite the values they target: whitespace, number formatting, escapes and
+/// key order of everything else are left byte-for-byte identical.
+pub struct JsonDocument {
+    text: String,
+    escaping: Escaping,
+    parsed: Option<Parsed>,
+}
+
+#[derive(Clone, Copy)]
+enum Escaping {
+    Sql,
+    JsonString,
+}
+
+/// Parse of the current text, dropped whenever the text is edited.
+struct Parsed {
+    /// JSON text with the escaping removed
+    json: String,
+    /// Offset in `text` of every byte of `json`, plus the end of `text`
+    offsets: Vec<usize>,
+    value: JsonValue,
+    span: Span,
+}
+
+/// Replacement of `json[start..end]` by `with` (unescaped JSON text).
+struct Edit {
+    start: usize,
+    end: usize,
+    with: String,
+}
+
+impl JsonDocument {
+    /// Wrap the SQL-escaped text of a JSON value (without its quotes).
+    /// Returns `None` if it is not a valid JSON document.
+    pub fn parse(escaped: &str) -> Option<JsonDocument> {
+        Self::parse_escaped(escaped, Escaping::Sql)
+    }
+
+    /// Wrap the raw content of a JSON string (between its double quotes)
+    /// that holds a JSON document itself.
+    pub fn parse_embedded(escaped: &str) -> Option<JsonDocument> {
+        Self::parse_escaped(escaped, Escaping::JsonString)
+    }
+
+    fn parse_escaped(escaped: &str, escaping: Escaping) -> Option<JsonDocument> {
+        let mut doc = JsonDocument {
+            text: escaped.to_string(),
+            escaping,
+            parsed: None,
+        };
+        doc.parsed = Some(doc.reparse()?);
+        Some(doc)
+    }
+
+    /// Current escaped text of the document.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    fn reparse(&self) -> Option<Parsed> {
+        let (json, offsets) = match self.escaping {
+            Escaping::Sql => mysql_unescape(&self.text),
+            Escaping::JsonString => json_string_unescape(&self.text),
+        };
+        let (value, span) = json_parse_spanned(&json)?;
+        Some(Parsed {
+            json,
+            offsets,
+            value,
+            span,
+        })
+    }
+
+    /// Apply one rule to the values matched by `path`, with `anonymize`
+    /// computing the anonymized text of a scalar (see `json_anonymize_with`).
+    /// Returns the number of values anonymized or removed.
+    pub fn anonymize_with(
+        &mut self,
+        path: &JsonPath,
+        config: &AnonBase,
+        anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+    ) -> usize {
+        if self.parsed.is_none() {
+            self.parsed = self.reparse();
+        }
+        let Some(parsed) = &self.parsed else {
+            return 0;
+        };
+
+        let locations = path_locations(&parsed.value, path);
+        let mut edits = Vec::new();
+        let count = if config.anon_type == AnonType::Remove {
+            removal_edits(parsed, &locations, &mut edits)
+        } else {
+            for location in &locations {
+                let (value, span) = parsed.node(location);
+                let text = &parsed.json[span.start..span.end];
+                if let Some(new_value) = anonymize_json_value(value, Some(text), config, anonymize)
+                {
+                    edits.push(Edit {
+                        start: span.start,
+                        end: span.end,
+                        with: json_to_string(&new_value),
+                    });
+                }
+            }
+            edits.len()
+        };
+
+        if !edits.is_empty() {
+            self.apply(edits);
+        }
+        count
+    }
+
+    /// Splice the edits into the escaped text. An edit inside a range already
+    /// edited (e.g. a member of a removed object) is dropped.
+    fn apply(&mut self, mut edits: Vec<Edit>) {
+        let Some(parsed) = self.parsed.take() else {
+            return;
+        };
+        edits.sort_by_key(|e| e.start);
+
+        let mut out = String::with_capacity(self.text.len());
+        let mut copied = 0;
+        for edit in edits {
+            let start = parsed.offsets[edit.start];
+            let end = parsed.offsets[edit.end];
+            if start < copied {
+                continue;
+            }
+            out.push_str(&self.text[copied..start]);
+            out.push_str(&match self.escaping {
+                Escaping::Sql => add_json_backslash(&edit.with),
+                Escaping::JsonString => json_escape_string(&edit.with),
+            });
+            copied = end;
+        }
+        out.push_str(&self.text[copied..]);
+        self.text = out;
+    }
+}
+
+impl Parsed {
+    fn node(&self, location: &[usize]) -> (&JsonValue, &Span) {
+        let mut value = &self.value;
+        let mut span = &self.span;
+        for &i in location {
+            value = match value {
+                JsonValue::Object(members) => &members[i].1,
+                JsonValue::Array(elements) => &elements[i],
+                _ => unreachable!("locations only go through containers"),
+            };
+            span = &span.children[i];
+        }
+        (value, span)
+    }
+}
+
+/// Build the edits removing the members or elements at `locations`, with
+/// the separators around them. Returns the number of values removed.
+fn removal_edits(parsed: &Parsed, locations: &[Vec<usize>], edits: &mut Vec<Edit>) -> usize {
+    // Group the removed children by parent
+    let mut groups: Vec<(&[usize], Vec<usize>)> = Vec::new();
+    for location in locations {
+        let Some((&i, parent)) = location.split_last() else {
+            // The root itself cannot be removed
+            continue;
+        };
+        match groups.iter_mut().find(|(p, _)| *p == parent) {
+            Some((_, removed)) => removed.push(i),
+            None => groups.push((parent, vec![i])),
+        }
+    }
+
+    let mut count = 0;
+    for (parent, mut removed) in groups {
+        removed.sort_unstable();
+        removed.dedup();
+        count += removed.len();
+        let children = &parsed.node(parent).1.children;
+        let last_kept = (0..children.len()).rev().find(|i| removed.binary_search(i).is_err());
+        for &i in &removed {
+            let (start, end) = match last_kept {
+                // Nothing left: remove from the first member to the last one
+                None if i == 0 => (children[0].key_start, children[children.len() - 1].end),
+                None => continue,
+                // Before a kept child: take the separator that follows
+                Some(kept) if i < kept => (children[i].key_start, children[i + 1].key_start),
+                // After the last kept child: take the separator that precedes
+                Some(_) => (children[i - 1].end, children[i].end),
+            };
+            edits.push(Edit {
+                start,
+                end,
+                with: String::new(),
+            });
+        }
+    }
+    count
+}
+
+/// Decode the escapes of a JSON string content. Returns the decoded text
+/// and, for each of its bytes, the offset of the escape sequence it comes
+/// from. Invalid escapes are kept as they are.
+pub(crate) fn json_string_unescape(text: &str) -> (String, Vec<usize>) {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(bytes.len());
+    let mut offsets = Vec::with_capacity(bytes.len() + 1);
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let decoded = match (bytes[i], bytes.get(i + 1)) {
+            (b'\\', Some(b'u')) => match hex4(bytes, i + 2) {
+                Some(high @ 0xd800..=0xdbff) => match (bytes.get(i + 6..i + 8), hex4(bytes, i + 8)) {
+                    (Some(b"\\u"), Some(low @ 0xdc00..=0xdfff)) => {
+                        i += 12;
+                        char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+                    }
+                    _ => None,
+                },
+                Some(code) => {
+                    i += 6;
+                    char::from_u32(code)
+                }
+                None => None,
+            },
+            (b'\\', Some(&c)) => {
+                i += 2;
+                Some(match c {
+                    b'b' => '\x08',
+                    b'f' => '\x0c',
+                    b'n' => '\n',
+                    b'r' => '\r',
+                    b't' => '\t',
+                    c => c as char,
+                })
+            }
+            _ => None,
+        };
+        match decoded {
+            Some(c) => {
+                offsets.extend(std::iter::repeat_n(start, c.len_utf8()));
+                out.push(c);
+            }
+            None => {
+                // Plain character, or an escape we cannot decode
+                i = start;
+                let c = text[i..].chars().next().unwrap_or('\\');
+                offsets.extend(std::iter::repeat_n(start, c.len_utf8()));
+                out.push(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+    offsets.push(bytes.len());
+    (out, offsets)
+}
+
+fn hex4(bytes: &[u8], at: usize) -> Option<u32> {
+    let digits = std::str::from_utf8(bytes.get(at..at + 4)?).ok()?;
+    u32::from_str_radix(digits, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anonymize(escaped: &str, path: &str, infos: &AnonBase) -> (String, usize) {
+        let mut doc = JsonDocument::parse(escaped).unwrap();
+        let path = JsonPath::parse(path).unwrap();
+        let count = doc.anonymize_with(&path, infos, &mut |_| Some(b"X".to_vec()));
+        (doc.as_str().to_string(), count)
+    }
+
+    fn rule(anon_type: AnonType) -> AnonBase {
+        AnonBase {
+            anon_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_untouched_text_is_kept() {
+        let input = r#"{ \"a\" : 1.10,\n  \"b\": \"x\", \"c\": [1e3, 12345678901234567890] }"#;
+        let (out, count) = anonymize(input, "b", &rule(AnonType::TextHash));
+        assert_eq!(count, 1);
+        assert_eq!(
+            out,
+            r#"{ \"a\" : 1.10,\n  \"b\": \"X\", \"c\": [1e3, 12345678901234567890] }"#
+        );
+    }
+
+    #[test]
+    fn test_numbers_anonymized_from_their_text() {
+        let mut doc = JsonDocument::parse(r#"{\"a\": 1.10}"#).unwrap();
+        let mut seen = Vec::new();
+        doc.anonymize_with(
+            &JsonPath::parse("a").unwrap(),
+            &rule(AnonType::IntHash),
+            &mut |token| {
+                seen.push(token.to_vec());
+                Some(b"42".to_vec())
+            },
+        );
+        assert_eq!(seen, vec![b"1.10".to_vec()]);
+        assert_eq!(doc.as_str(), r#"{\"a\": 42}"#);
+    }
+
+    #[test]
+    fn test_multibyte_and_escapes() {
+        let input = r#"{\"name\": \"Zoë \\\"Z\\\"\", \"city\": \"Kraków\"}"#;
+        let (out, _) = anonymize(input, "city", &rule(AnonType::TextHash));
+        assert_eq!(out, r#"{\"name\": \"Zoë \\\"Z\\\"\", \"city\": \"X\"}"#);
+    }
+
+    #[test]
+    fn test_fixed_value_is_escaped() {
+        let mut doc = JsonDocument::parse(r#"{\"a\": \"x\", \"b\": \"y\"}"#).unwrap();
+        let fixed = AnonBase {
+            anon_type: AnonType::Fixed,
+            fixed_value: r#"q"\"#.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(doc.anonymize_with(&JsonPath::parse("a").unwrap(), &fixed, &mut |_| None), 1);
+        // The document still parses, so later rules still apply
+        let count = doc.anonymize_with(
+            &JsonPath::parse("b").unwrap(),
+            &rule(AnonType::TextHash),
+            &mut |_| Some(b"X".to_vec()),
+        );
+        assert_eq!(count, 1);
+        assert_eq!(doc.as_str(), r#"{\"a\": \"q\\\"\\\\\", \"b\": \"X\"}"#);
+    }
+
+    #[test]
+    fn test_remove_members() {
+        let input = r#"{\"a\": 1, \"b\": 2, \"c\": 3}"#;
+        let remove = rule(AnonType::Remove);
+        assert_eq!(anonymize(input, "a", &remove).0, r#"{\"b\": 2, \"c\": 3}"#);
+        assert_eq!(anonymize(input, "b", &remove).0, r#"{\"a\": 1, \"c\": 3}"#);
+        assert_eq!(anonymize(input, "c", &remove).0, r#"{\"a\": 1, \"b\": 2}"#);
+        assert_eq!(anonymize(input, "*", &remove), (r#"{}"#.to_string(), 3));
+    }
+
+    #[test]
+    fn test_remove_elements() {
+        let input = "[ 1, 2, 3, 4 ]";
+        let remove = rule(AnonType::Remove);
+        assert_eq!(anonymize(input, "[2]", &remove).0, "[ 1, 2, 4 ]");
+        assert_eq!(anonymize(input, "[3]", &remove).0, "[ 1, 2, 3 ]");
+        assert_eq!(anonymize(input, "[]", &remove).0, "[  ]");
+    }
+
+    #[test]
+    fn test_nested_edits() {
+        let input = r#"{\"a\": {\"token\": \"t\"}, \"token\": \"u\"}"#;
+        let (out, count) = anonymize(input, "..token", &rule(AnonType::Remove));
+        assert_eq!(out, r#"{\"a\": {}}"#);
+        assert_eq!(count, 2);
+        let (out, _) = anonymize(input, "..*", &rule(AnonType::FixedNull));
+        assert_eq!(out, r#"{\"a\": null, \"token\": null}"#);
+    }
+
+    #[test]
+    fn test_invalid_document() {
+        assert!(JsonDocument::parse("{\"a\": ").is_none());
+        assert!(JsonDocument::parse("[1] x").is_none());
+    }
+}
@@ -1,6 +1,27 @@
+// Lints the original unit tests predate
+#![cfg_attr(test, allow(clippy::manual_range_contains, clippy::approx_constant))]
+
 pub mod config;
 pub mod anonymize;
 pub mod json;
+pub mod phpserialized;
+pub mod xml;
+pub mod yaml;
+pub mod url;
+pub mod scrub;
+pub mod regexreplace;
+pub mod iphash;
+pub mod cardhash;
+pub mod emailhash;
+pub mod idhash;
+pub mod idmap;
+pub mod mask;
+pub mod encrypt;
+pub mod uuidhash;
+pub mod noise;
+pub mod generalize;
+pub mod synthesize;
 pub mod dump;
+pub mod charset;
 #[cfg(feature = "python")]
 pub mod python;
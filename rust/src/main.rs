@@ -1,21 +1,301 @@
 use std::env;
 use std::fs;
-use std::io::{self, BufWriter, Write};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
 use std::process;
 use std::time::Instant;
 
-use myanon::config::Parser;
-use myanon::dump::DumpProcessor;
+use myanon::charset::Charset;
+use myanon::config::{AnonJson, Parser};
+use myanon::dump::report::{report_to_json, report_to_text};
+use myanon::dump::{profile_dump, DumpProcessor, Reporter};
+use myanon::encrypt::{decrypt_config, decrypt_value, Encoding, EncryptRule};
+use myanon::url::UrlComponent;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const PACKAGE_NAME: &str = env!("CARGO_PKG_NAME");
 const STDOUT_BUFFER_SIZE: usize = 1048576;
 
+/// Warn about JSON paths, nested ones included, that never matched.
+fn warn_unused_json_rules(table: &str, field: &str, parent: &str, rules: &[AnonJson]) {
+    for json_rule in rules {
+        let filter = format!("{}{}", parent, json_rule.filter);
+        if json_rule.infos.nbhits == 0 {
+            eprintln!(
+                "WARNING! Field {}:{} - JSON path '{}' from config file has not been found in dump. Maybe a config file error?",
+                table, field, filter
+            );
+        }
+        warn_unused_json_rules(table, field, &format!("{} > ", filter), &json_rule.json);
+    }
+}
+
+/// Copy stdin to a temporary file, for rules reading the dump twice.
+fn spool_stdin() -> io::Result<fs::File> {
+    let mut file = tempfile::tempfile()?;
+    io::copy(&mut io::stdin().lock(), &mut file)?;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(file)
+}
+
+/// Charset given with `-c`, exiting when it is not supported.
+fn charset_arg(name: &str) -> Charset {
+    match Charset::from_mysql_name(name) {
+        Some(c) => c,
+        None => {
+            eprintln!("Unsupported charset: {}", name);
+            process::exit(1);
+        }
+    }
+}
+
+/// `myanon report -f config_file [-c charset] [--json]`: read a dump on stdin, usually
+/// the output of an anonymization, and report the re-identification risk of
+/// the tables of the `report` block.
+fn report_main(args: &[String]) {
+    let mut config_file: Option<String> = None;
+    let mut json = false;
+    let mut charset: Option<Charset> = None;
+
+    let mut i = 2;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-f" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Option -f requires a config file as argument.");
+                    process::exit(1);
+                }
+                config_file = Some(args[i].clone());
+            }
+            "-c" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Option -c requires a charset as argument.");
+                    process::exit(1);
+                }
+                charset = Some(charset_arg(&args[i]));
+            }
+            "--json" => {
+                json = true;
+            }
+            "-h" | "--help" => {
+                println!("Usage: {} report -f config_file [-c charset] [--json]", args[0]);
+                println!("\nReads a dump on stdin and reports k-anonymity and l-diversity");
+                println!("of the tables of the report block of the config file.");
+                println!("\nOptions:");
+                println!("  -f <file>      Configuration file");
+                println!("  -c <charset>   Dump charset (overrides SET NAMES)");
+                println!("  --json         JSON output instead of text");
+                process::exit(0);
+            }
+            _ => {
+                eprintln!("Unknown option: {}", args[i]);
+                process::exit(1);
+            }
+        }
+        i += 1;
+    }
+
+    let config_file = match config_file {
+        Some(f) => f,
+        None => {
+            eprintln!("Usage: {} report -f config_file [-c charset] [--json]", args[0]);
+            process::exit(1);
+        }
+    };
+    let input = match fs::read_to_string(&config_file) {
+        Ok(s) => s,
+        Err(_) => {
+            eprintln!("Unable to load config {}", config_file);
+            process::exit(1);
+        }
+    };
+    let mut config = match Parser::new(&input).parse() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    config.charset = charset;
+    if config.report.is_empty() {
+        eprintln!("No report block in config {}", config_file);
+        process::exit(1);
+    }
+
+    let mut reporter = Reporter::new(&config);
+    if let Err(e) = reporter.process(io::stdin().lock()) {
+        eprintln!("Dump parsing error: {}", e);
+        process::exit(1);
+    }
+    let reports = reporter.report();
+    if json {
+        println!("{}", report_to_json(&reports));
+    } else {
+        print!("{}", report_to_text(&reports));
+    }
+}
+
+/// `myanon decrypt -f config_file [-k key] [--hex] value...` or
+/// `myanon decrypt -f config_file [-k key] [--hex] [-c charset] -t table --column column`:
+/// reverse `encrypt` rules, on the given values or on a column of a dump
+/// read on stdin.
+fn decrypt_main(args: &[String]) {
+    let usage = format!(
+        "Usage: {0} decrypt -f config_file [-k key] [--hex] value...\n       {0} decrypt -f config_file [-k key] [--hex] [-c charset] -t table --column column",
+        args[0]
+    );
+    let mut config_file: Option<String> = None;
+    let mut key_name: Option<String> = None;
+    let mut table: Option<String> = None;
+    let mut column: Option<String> = None;
+    let mut encoding = Encoding::Base64;
+    let mut charset: Option<Charset> = None;
+    let mut values = Vec::new();
+
+    let mut i = 2;
+    while i < args.len() {
+        match args[i].as_str() {
+            option @ ("-f" | "-k" | "-t" | "--column" | "-c") => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Option {} requires an argument.", option);
+                    process::exit(1);
+                }
+                let value = Some(args[i].clone());
+                match option {
+                    "-f" => config_file = value,
+                    "-k" => key_name = value,
+                    "-t" => table = value,
+                    "--column" => column = value,
+                    _ => charset = Some(charset_arg(&args[i])),
+                }
+            }
+            "--hex" => {
+                encoding = Encoding::Hex;
+            }
+            "-h" | "--help" => {
+                println!("{}", usage);
+                println!("\nReverses encrypt rules: prints the decrypted values, or reads a dump");
+                println!("on stdin and writes it with the column decrypted.");
+                println!("\nOptions:");
+                println!("  -f <file>          Configuration file holding the keys block");
+                println!("  -k <key>           Key name (optional if the config has a single key)");
+                println!("  --hex              Values are hex instead of base64");
+                println!("  -t <table>         Table of the column to decrypt in the dump");
+                println!("  --column <column>  Column to decrypt in the dump");
+                println!("  -c <charset>       Dump charset (overrides SET NAMES)");
+                process::exit(0);
+            }
+            value if !value.starts_with('-') => values.push(value.to_string()),
+            _ => {
+                eprintln!("Unknown option: {}", args[i]);
+                process::exit(1);
+            }
+        }
+        i += 1;
+    }
+
+    let Some(config_file) = config_file else {
+        eprintln!("{}", usage);
+        process::exit(1);
+    };
+    let input = match fs::read_to_string(&config_file) {
+        Ok(s) => s,
+        Err(_) => {
+            eprintln!("Unable to load config {}", config_file);
+            process::exit(1);
+        }
+    };
+    let config = match Parser::new(&input).parse() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let key = match (&key_name, config.keys.as_slice()) {
+        (Some(name), keys) => keys.iter().find(|k| &k.name == name),
+        (None, [key]) => Some(key),
+        (None, _) => {
+            eprintln!("Option -k is required when the config does not have exactly one key.");
+            process::exit(1);
+        }
+    };
+    let Some(key) = key else {
+        eprintln!("Unknown key {} in config {}", key_name.unwrap_or_default(), config_file);
+        process::exit(1);
+    };
+
+    match (table, column) {
+        (Some(table), Some(column)) if values.is_empty() => {
+            let rule = EncryptRule {
+                key_name: key.name.clone(),
+                key: key.key.clone(),
+                encoding,
+                width: None,
+                max_len: None,
+                decrypt: true,
+            };
+            let mut config = decrypt_config(&table, &column, rule);
+            config.charset = charset;
+            let mut writer = BufWriter::with_capacity(STDOUT_BUFFER_SIZE, io::stdout().lock());
+            let result = DumpProcessor::new(&mut config).and_then(|mut p| p.process(io::stdin().lock(), &mut writer));
+            writer.flush().ok();
+            if let Err(e) = result {
+                eprintln!("\nDump parsing error: {}", e);
+                process::exit(1);
+            }
+            if config.tables[0].fields[0].infos.nbhits == 0 {
+                eprintln!(
+                    "WARNING! Field {}:{} has not been found in dump. Maybe a typo?",
+                    table, column
+                );
+            }
+        }
+        (None, None) if !values.is_empty() => {
+            let mut failed = false;
+            let stdout = io::stdout();
+            let mut out = stdout.lock();
+            for value in &values {
+                match decrypt_value(value, &key.key, encoding) {
+                    Some(plaintext) => {
+                        let _ = out.write_all(&plaintext);
+                        let _ = out.write_all(b"\n");
+                    }
+                    None => {
+                        eprintln!("Unable to decrypt {} with key {}", value, key.name);
+                        failed = true;
+                    }
+                }
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        _ => {
+            eprintln!("{}", usage);
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
 
+    if args.get(1).map(String::as_str) == Some("report") {
+        report_main(&args);
+        return;
+    }
+    if args.get(1).map(String::as_str) == Some("decrypt") {
+        decrypt_main(&args);
+        return;
+    }
+
     let mut config_file: Option<String> = None;
+    let mut dump_file: Option<String> = None;
     let mut debug = false;
+    let mut charset: Option<Charset> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -28,6 +308,22 @@ fn main() {
                 }
                 config_file = Some(args[i].clone());
             }
+            "-i" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Option -i requires a dump file as argument.");
+                    process::exit(1);
+                }
+                dump_file = Some(args[i].clone());
+            }
+            "-c" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Option -c requires a charset as argument.");
+                    process::exit(1);
+                }
+                charset = Some(charset_arg(&args[i]));
+            }
             "-d" => {
                 debug = true;
             }
@@ -36,9 +332,13 @@ fn main() {
                 process::exit(0);
             }
             "-h" | "--help" => {
-                println!("Usage: {} -f config_file [-d]", args[0]);
+                println!("Usage: {} -f config_file [-i dump_file] [-c charset] [-d]", args[0]);
+                println!("       {} report -f config_file [-c charset] [--json]", args[0]);
+                println!("       {} decrypt -f config_file [-k key] [--hex] value...", args[0]);
                 println!("\nOptions:");
                 println!("  -f <file>      Configuration file");
+                println!("  -i <file>      Dump file (default: stdin)");
+                println!("  -c <charset>   Dump charset (overrides SET NAMES)");
                 println!("  -d             Debug mode");
                 println!("  -v, --version  Show version");
                 println!("  -h, --help     Show this help");
@@ -55,9 +355,11 @@ fn main() {
     let config_file = match config_file {
         Some(f) => f,
         None => {
-            eprintln!("Usage: {} -f config_file [-d]", args[0]);
+            eprintln!("Usage: {} -f config_file [-i dump_file] [-c charset] [-d]", args[0]);
             eprintln!("\nOptions:");
             eprintln!("  -f <file>      Configuration file");
+            eprintln!("  -i <file>      Dump file (default: stdin)");
+            eprintln!("  -c <charset>   Dump charset (overrides SET NAMES)");
             eprintln!("  -d             Debug mode");
             eprintln!("  -v, --version  Show version");
             eprintln!("  -h, --help     Show this help");
@@ -84,10 +386,46 @@ fn main() {
             process::exit(1);
         }
     };
+    config.charset = charset;
+
+    let mut input = dump_file.map(|path| match fs::File::open(&path) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("Unable to open dump {}: {}", path, e);
+            process::exit(1);
+        }
+    });
+
+    // `synthesize` rules profile the dump before anonymizing it: a dump
+    // read on stdin is spooled to a temporary file to be read twice
+    let synthesize = config
+        .tables
+        .iter()
+        .any(|t| t.fields.iter().any(|f| f.infos.synthesize.is_some()));
+    if synthesize {
+        let mut file = match input.take().map_or_else(spool_stdin, Ok) {
+            Ok(f) => f,
+            Err(e) => {
+                eprintln!("Unable to spool dump to a temporary file: {}", e);
+                process::exit(1);
+            }
+        };
+        let profiled = profile_dump(&mut config, &file)
+            .and_then(|_| file.seek(SeekFrom::Start(0)).map_err(|e| format!("Read error: {}", e)));
+        if let Err(e) = profiled {
+            eprintln!("Dump parsing error: {}", e);
+            process::exit(1);
+        }
+        input = Some(file);
+    }
 
     // Process dump
     let stdin = io::stdin();
     let stdout = io::stdout();
+    let reader: Box<dyn Read> = match input {
+        Some(file) => Box::new(file),
+        None => Box::new(stdin.lock()),
+    };
 
     let result = if debug {
         let mut writer = stdout.lock();
@@ -98,7 +436,7 @@ fn main() {
                 process::exit(1);
             }
         };
-        processor.process(stdin.lock(), &mut writer)
+        processor.process(reader, &mut writer)
     } else {
         let mut writer = BufWriter::with_capacity(STDOUT_BUFFER_SIZE, stdout.lock());
         let mut processor = match DumpProcessor::new(&mut config) {
@@ -108,7 +446,7 @@ fn main() {
                 process::exit(1);
             }
         };
-        let result = processor.process(stdin.lock(), &mut writer);
+        let result = processor.process(reader, &mut writer);
         writer.flush().ok();
         result
     };
@@ -123,11 +461,21 @@ fn main() {
     // Report warnings for fields not found
     for table in &config.tables {
         for field in &table.fields {
-            for json_rule in &field.json {
-                if json_rule.infos.nbhits == 0 {
+            warn_unused_json_rules(&table.name, &field.name, "", &field.json);
+            for url_rule in &field.url {
+                // A fallback only applies to values that are not URLs
+                if url_rule.infos.nbhits == 0 && url_rule.component != UrlComponent::Fallback {
+                    eprintln!(
+                        "WARNING! Field {}:{} - URL {} from config file has not been found in dump. Maybe a config file error?",
+                        table.name, field.name, url_rule.component
+                    );
+                }
+            }
+            for scrub_rule in &field.scrub {
+                if scrub_rule.infos.nbhits == 0 {
                     eprintln!(
-                        "WARNING! Field {}:{} - JSON path '{}' from config file has not been found in dump. Maybe a config file error?",
-                        table.name, field.name, json_rule.filter
+                        "WARNING! Field {}:{} - scrub {} from config file has not been found in dump. Maybe a config file error?",
+                        table.name, field.name, scrub_rule.detector
                     );
                 }
             }
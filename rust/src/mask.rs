@@ -0,0 +1,101 @@
+use crate::anonymize::{mychar_len, mysql_escape_into};
+
+/// Settings of a `mask` rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskRule {
+    /// Characters kept at the start (`first N`)
+    pub first: usize,
+    /// Characters kept at the end (`last M`)
+    pub last: usize,
+    /// Character written in place of the others
+    pub mask: char,
+    /// Separators (`skip ' -'`), kept where they are and not counted
+    pub skip: Vec<char>,
+}
+
+impl Default for MaskRule {
+    fn default() -> Self {
+        MaskRule {
+            first: 0,
+            last: 0,
+            mask: '*',
+            skip: Vec::new(),
+        }
+    }
+}
+
+/// Mask a value as a display would: its first and last characters are kept,
+/// separators too, and the others are replaced by the mask character.
+/// Characters are counted as `mysubstr` does, an escape sequence counting as
+/// one. Values with no more than `first + last` characters are kept.
+pub fn mask_into(out: &mut Vec<u8>, token: &[u8], rule: &MaskRule) {
+    let mut chars = Vec::new();
+    let mut pos = 0;
+    while pos < token.len() {
+        // A byte that is not valid UTF-8 counts as one character
+        let len = mychar_len(&token[pos..]).max(1);
+        chars.push(&token[pos..pos + len]);
+        pos += len;
+    }
+
+    let mut buf = [0u8; 4];
+    let is_separator = |c: &[u8]| rule.skip.iter().any(|s| s.encode_utf8(&mut [0u8; 4]).as_bytes() == c);
+    let counted = chars.iter().filter(|c| !is_separator(c)).count();
+    let mut index = 0;
+    for c in chars {
+        if is_separator(c) {
+            out.extend_from_slice(c);
+            continue;
+        }
+        if index < rule.first || index + rule.last >= counted {
+            out.extend_from_slice(c);
+        } else {
+            mysql_escape_into(out, rule.mask.encode_utf8(&mut buf));
+        }
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask(token: &str, first: usize, last: usize, skip: &str) -> String {
+        let rule = MaskRule {
+            first,
+            last,
+            skip: skip.chars().collect(),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        mask_into(&mut out, token.as_bytes(), &rule);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_displays() {
+        assert_eq!(mask("4111-1111-1111-1234", 0, 4, "-"), "****-****-****-1234");
+        assert_eq!(mask("4111-1111-1111-1234", 0, 4, ""), "***************1234");
+        assert_eq!(mask("alice@example.com", 1, 12, ""), "a****@example.com");
+        assert_eq!(mask("+33 6 12 34 56 12", 4, 2, " "), "+33 6 ** ** ** 12");
+        assert_eq!(mask("secret", 0, 0, ""), "******");
+        assert_eq!(mask("", 1, 1, ""), "");
+        // Too short to hide anything
+        assert_eq!(mask("abc", 2, 2, ""), "abc");
+    }
+
+    #[test]
+    fn test_characters() {
+        assert_eq!(mask("Zoë Åström", 1, 1, " "), "Z** *****m");
+        assert_eq!(mask("O\\'Brien", 2, 0, ""), "O\\'*****");
+        assert_eq!(mask("ab\\\\cd", 0, 3, ""), "**\\\\cd");
+        let rule = MaskRule {
+            last: 1,
+            mask: '\'',
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        mask_into(&mut out, b"abc", &rule);
+        assert_eq!(out, b"''''c");
+    }
+}
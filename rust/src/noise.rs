@@ -0,0 +1,347 @@
+use crate::anonymize::hmac_digest;
+
+/// Numeric column type, read from `CREATE TABLE`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericType {
+    /// TINYINT to BIGINT, signed or not
+    Integer { min: i128, max: i128 },
+    /// DECIMAL(precision, scale)
+    Decimal {
+        precision: u32,
+        scale: u32,
+        unsigned: bool,
+    },
+    /// FLOAT, DOUBLE
+    Float { unsigned: bool },
+}
+
+impl NumericType {
+    /// Numeric type of a column definition (`int unsigned NOT NULL`,
+    /// `decimal(10,2) DEFAULT NULL`, ...), if it is one.
+    pub fn parse(definition: &str) -> Option<NumericType> {
+        let lower = definition.to_lowercase();
+        let name_end = lower
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(lower.len());
+        let (name, rest) = lower.split_at(name_end);
+        let (args, rest) = match rest.strip_prefix('(') {
+            Some(rest) => rest.split_once(')')?,
+            None => ("", rest),
+        };
+        let unsigned = rest.split_whitespace().next() == Some("unsigned");
+
+        let bits = match name {
+            "tinyint" => 8,
+            "smallint" => 16,
+            "mediumint" => 24,
+            "int" | "integer" => 32,
+            "bigint" => 64,
+            "decimal" | "numeric" | "dec" | "fixed" => {
+                let mut args = args.split(',').map(|a| a.trim().parse::<u32>());
+                let precision = match args.next() {
+                    Some(Ok(p)) => p,
+                    _ => 10,
+                };
+                let scale = match args.next() {
+                    Some(Ok(s)) => s,
+                    _ => 0,
+                };
+                return Some(NumericType::Decimal {
+                    precision,
+                    scale,
+                    unsigned,
+                });
+            }
+            "float" | "double" | "real" => return Some(NumericType::Float { unsigned }),
+            _ => return None,
+        };
+        Some(if unsigned {
+            NumericType::Integer {
+                min: 0,
+                max: (1i128 << bits) - 1,
+            }
+        } else {
+            NumericType::Integer {
+                min: -(1i128 << (bits - 1)),
+                max: (1i128 << (bits - 1)) - 1,
+            }
+        })
+    }
+
+    /// Bounds of the type, in units of 10^-scale
+    pub(crate) fn bounds(&self, scale: u32) -> (Option<i128>, Option<i128>) {
+        let rescale = |n: i128, from: u32| {
+            if scale >= from {
+                match 10i128.checked_pow(scale - from) {
+                    Some(factor) => n.saturating_mul(factor),
+                    None if n < 0 => i128::MIN,
+                    None => n.signum() * i128::MAX,
+                }
+            } else {
+                10i128.checked_pow(from - scale).map_or(0, |factor| n / factor)
+            }
+        };
+        match *self {
+            NumericType::Integer { min, max } => (Some(rescale(min, 0)), Some(rescale(max, 0))),
+            NumericType::Decimal {
+                precision,
+                scale: column_scale,
+                unsigned,
+            } => {
+                let max = rescale(10i128.pow(precision.min(38)) - 1, column_scale);
+                (Some(if unsigned { 0 } else { -max }), Some(max))
+            }
+            NumericType::Float { unsigned } => (unsigned.then_some(0), None),
+        }
+    }
+}
+
+/// Settings of a `noise` rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseSpec {
+    /// Percentage of the value (`'10%'`) rather than an absolute offset (`'500'`)
+    pub relative: bool,
+    pub amount: f64,
+    /// Derive the noise from the table key rather than from the value (`bykey`)
+    pub by_key: bool,
+}
+
+impl NoiseSpec {
+    pub fn parse(spec: &str) -> Result<NoiseSpec, String> {
+        let (number, relative) = match spec.trim().strip_suffix('%') {
+            Some(number) => (number, true),
+            None => (spec.trim(), false),
+        };
+        match number.trim().parse::<f64>() {
+            Ok(amount) if amount.is_finite() && amount > 0.0 => Ok(NoiseSpec {
+                relative,
+                amount,
+                by_key: false,
+            }),
+            _ => Err(format!(
+                "invalid noise amount '{}', expected a positive number or percentage",
+                spec
+            )),
+        }
+    }
+}
+
+/// A decimal number as written in the dump: sign and digits in units of
+/// 10^-scale.
+pub(crate) fn parse_decimal(text: &str) -> Option<(i128, u32)> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty()
+        || int_part.len() + frac_part.len() > 36
+        || !int_part.bytes().chain(frac_part.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let value: i128 = format!("{}{}", int_part, frac_part).parse().ok()?;
+    Some((if negative { -value } else { value }, frac_part.len() as u32))
+}
+
+pub(crate) fn format_decimal(value: i128, scale: u32) -> String {
+    let digits = format!("{:0width$}", value.unsigned_abs(), width = scale as usize + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale as usize);
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        format!("{}{}", sign, int_part)
+    } else {
+        format!("{}{}.{}", sign, int_part, frac_part)
+    }
+}
+
+/// A number as written in the dump, with an optional exponent (`1.5e+20`):
+/// its mantissa as `parse_decimal` reads it, the exponent, and the exponent
+/// text (`e+20`, empty without one).
+fn parse_number(text: &str) -> Option<(i128, u32, i32, &str)> {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(pos) => (&text[..pos], &text[pos..]),
+        None => (text, ""),
+    };
+    let (value, scale) = parse_decimal(mantissa)?;
+    let exp = match exponent.get(1..) {
+        Some(exp) => exp.parse::<i32>().ok().filter(|e| e.abs() <= 400)?,
+        None => 0,
+    };
+    Some((value, scale, exp, exponent))
+}
+
+/// Add deterministic noise to a number, keeping its number of decimals, its
+/// exponent if written with one, and its sign (zero stays zero), and clamping
+/// it to the column type. The noise comes from the HMAC of the value, or of
+/// `key` when given. Returns false, writing nothing, for values that are not
+/// numbers.
+pub fn noise_into(
+    out: &mut Vec<u8>,
+    token: &[u8],
+    spec: &NoiseSpec,
+    secret: &[u8],
+    key: Option<&str>,
+    column: Option<NumericType>,
+) -> bool {
+    let Some((value, scale, exp, exponent)) = std::str::from_utf8(token).ok().and_then(parse_number) else {
+        return false;
+    };
+    // The value is `value` in units of 10^-units
+    let units = scale as i32 - exp;
+
+    // Factor in [-1, 1)
+    let digest = hmac_digest(key.map_or(token, str::as_bytes), secret);
+    let factor = u64::from_be_bytes(digest[..8].try_into().unwrap()) as f64 / 2f64.powi(63) - 1.0;
+    let delta = if spec.relative {
+        (value as f64 * factor * spec.amount / 100.0).round()
+    } else {
+        (factor * spec.amount * 10f64.powi(units)).round()
+    } as i128;
+
+    let mut new = value.saturating_add(delta);
+    if value == 0 {
+        new = 0;
+    } else if new.signum() != value.signum() {
+        // Going the other way keeps the sign
+        new = value.saturating_sub(delta);
+    }
+    if let Some(column) = column {
+        let (min, max) = match u32::try_from(units) {
+            Ok(units) => column.bounds(units),
+            Err(_) => {
+                let factor = 10i128.checked_pow(units.unsigned_abs());
+                let (min, max) = column.bounds(0);
+                let rescale = |n: i128| factor.map_or(0, |factor| n / factor);
+                (min.map(rescale), max.map(rescale))
+            }
+        };
+        if let Some(min) = min {
+            new = new.max(min);
+        }
+        if let Some(max) = max {
+            new = new.min(max);
+        }
+    }
+    out.extend_from_slice(format_decimal(new, scale).as_bytes());
+    out.extend_from_slice(exponent.as_bytes());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(spec: &str, token: &str, key: Option<&str>, column: Option<NumericType>) -> Option<String> {
+        let mut out = Vec::new();
+        let spec = NoiseSpec::parse(spec).unwrap();
+        noise_into(&mut out, token.as_bytes(), &spec, b"secret", key, column)
+            .then(|| String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_column_types() {
+        assert_eq!(
+            NumericType::parse("tinyint unsigned NOT NULL"),
+            Some(NumericType::Integer { min: 0, max: 255 })
+        );
+        assert_eq!(
+            NumericType::parse("int(11) DEFAULT NULL"),
+            Some(NumericType::Integer {
+                min: -2147483648,
+                max: 2147483647
+            })
+        );
+        assert_eq!(
+            NumericType::parse("decimal(10,2) unsigned NOT NULL"),
+            Some(NumericType::Decimal {
+                precision: 10,
+                scale: 2,
+                unsigned: true
+            })
+        );
+        assert_eq!(NumericType::parse("double"), Some(NumericType::Float { unsigned: false }));
+        assert_eq!(NumericType::parse("varchar(10)"), None);
+        assert_eq!(NumericType::parse("datetime"), None);
+    }
+
+    #[test]
+    fn test_relative_noise() {
+        let out = noise("10%", "52000.00", None, None).unwrap();
+        let (int_part, frac_part) = out.split_once('.').unwrap();
+        assert_eq!(frac_part.len(), 2);
+        let value: f64 = out.parse().unwrap();
+        assert!((46800.0..=57200.0).contains(&value), "{}", out);
+        assert_ne!(int_part, "52000");
+        assert_eq!(noise("10%", "52000.00", None, None).unwrap(), out);
+
+        let out = noise("10%", "-1234", None, None).unwrap();
+        assert!(!out.contains('.'));
+        assert!((-1358..=-1110).contains(&out.parse::<i64>().unwrap()), "{}", out);
+        assert_eq!(noise("10%", "0", None, None).unwrap(), "0");
+        assert_eq!(noise("10%", "abc", None, None), None);
+        assert_eq!(noise("10%", "1e", None, None), None);
+        assert_eq!(noise("10%", "1.5e+x", None, None), None);
+    }
+
+    #[test]
+    fn test_exponent_notation() {
+        let out = noise("10%", "1.5e+20", None, None).unwrap();
+        let (mantissa, exponent) = out.split_once('e').unwrap();
+        assert_eq!(exponent, "+20");
+        assert_eq!(mantissa.split_once('.').unwrap().1.len(), 1);
+        let value: f64 = out.parse().unwrap();
+        assert!((1.3e20..=1.7e20).contains(&value), "{}", out);
+        assert_eq!(noise("10%", "1.5e+20", None, None).unwrap(), out);
+
+        // Absolute noise is in units of the value, not of the mantissa
+        for key in ["a", "b", "c", "d", "e"] {
+            let out = noise("500", "12345.678E-2", Some(key), None).unwrap();
+            assert!(out.ends_with("E-2"), "{}", out);
+            let value: f64 = out.parse().unwrap();
+            assert!((123.45678 - value).abs() <= 500.0, "{}", out);
+        }
+        let double = NumericType::parse("double unsigned").unwrap();
+        assert!(!noise("10%", "-2.5e-300", None, Some(double)).unwrap().starts_with('-'));
+        let int = NumericType::parse("int").unwrap();
+        assert_eq!(noise("10%", "1e3", Some("a"), Some(int)).unwrap().split_once('e').unwrap().1, "3");
+    }
+
+    #[test]
+    fn test_absolute_noise_keeps_sign() {
+        for value in ["3", "250", "-7"] {
+            for key in ["a", "b", "c", "d", "e"] {
+                let out = noise("500", value, Some(key), None).unwrap();
+                let (out, value) = (out.parse::<i64>().unwrap(), value.parse::<i64>().unwrap());
+                assert_eq!(out.signum(), value.signum(), "{} {}", value, out);
+                assert!((out - value).abs() <= 500);
+            }
+        }
+    }
+
+    #[test]
+    fn test_by_key_and_clamping() {
+        // Same key, same factor
+        let a: f64 = noise("20%", "100.0", Some("42"), None).unwrap().parse().unwrap();
+        let b: f64 = noise("20%", "1000.0", Some("42"), None).unwrap().parse().unwrap();
+        assert!((a * 10.0 - b).abs() < 1.0, "{} {}", a, b);
+
+        let tinyint = NumericType::parse("tinyint unsigned").unwrap();
+        for key in ["a", "b", "c", "d", "e", "f"] {
+            let out: i64 = noise("50%", "250", Some(key), Some(tinyint)).unwrap().parse().unwrap();
+            assert!(out <= 255);
+        }
+        let decimal = NumericType::parse("decimal(5,2)").unwrap();
+        for key in ["a", "b", "c", "d", "e", "f"] {
+            let out: f64 = noise("50%", "999.00", Some(key), Some(decimal)).unwrap().parse().unwrap();
+            assert!(out <= 999.99);
+        }
+    }
+
+    #[test]
+    fn test_invalid_specs() {
+        for spec in ["", "%", "-5%", "abc", "0"] {
+            assert!(NoiseSpec::parse(spec).is_err(), "{}", spec);
+        }
+    }
+}
@@ -0,0 +1,678 @@
+use crate::charset::Charset;
+use crate::config::{AnonBase, AnonType};
+use crate::json::{path_locations, JsonPath, PathNode};
+
+/// A value in PHP `serialize()` format, as stored by WordPress and Drupal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhpValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    /// Float, as written (`1.5`, `INF`, `NAN`, ...)
+    Float(String),
+    String(String),
+    Array(Vec<(PhpKey, PhpValue)>),
+    Object {
+        class: String,
+        members: Vec<(PhpKey, PhpValue)>,
+    },
+    /// `r:N;` (same object as) or, with `alias`, `R:N;` (PHP reference to)
+    /// the N-th value of the document, numbered from 1 in document order,
+    /// keys and `R:` references excluded
+    Reference { alias: bool, slot: usize },
+    /// Custom-serialized objects (`C:`) and enums (`E:`), kept as they are
+    Raw(String),
+}
+
+/// Array key or object property name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhpKey {
+    /// Integer key, as written
+    Int(String),
+    Str(String),
+}
+
+impl PhpKey {
+    /// Key as matched by paths: private and protected property names lose
+    /// their `\0Class\0` or `\0*\0` prefix.
+    fn name(&self) -> &str {
+        match self {
+            PhpKey::Int(s) => s,
+            PhpKey::Str(s) if s.starts_with('\0') => {
+                s[1..].find('\0').map_or(s.as_str(), |end| &s[end + 2..])
+            }
+            PhpKey::Str(s) => s,
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// `s:N:` counts bytes of the dump charset: UTF-8 bytes, or characters
+    /// for single-byte charsets
+    count_chars: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, msg: &str) -> Result<T, String> {
+        Err(format!("{} at offset {}", msg, self.pos))
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), String> {
+        if self.input.as_bytes().get(self.pos) == Some(&c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("expected '{}'", c as char))
+        }
+    }
+
+    /// Read up to (and consume) `end`.
+    fn until(&mut self, end: u8) -> Result<&'a str, String> {
+        match self.input.as_bytes()[self.pos..].iter().position(|&b| b == end) {
+            Some(len) => {
+                let s = &self.input[self.pos..self.pos + len];
+                self.pos += len + 1;
+                Ok(s)
+            }
+            None => self.error(&format!("missing '{}'", end as char)),
+        }
+    }
+
+    fn count(&mut self, end: u8) -> Result<usize, String> {
+        let digits = self.until(end)?;
+        digits
+            .parse()
+            .or_else(|_| self.error(&format!("invalid length '{}'", digits)))
+    }
+
+    /// Read `n` bytes (or characters) of string data.
+    fn counted(&mut self, n: usize) -> Result<&'a str, String> {
+        let rest = &self.input[self.pos..];
+        let len = if self.count_chars {
+            rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i)
+        } else {
+            n
+        };
+        match rest.get(..len) {
+            Some(s) if rest[..len].chars().count() == n || !self.count_chars => {
+                self.pos += len;
+                Ok(s)
+            }
+            _ => self.error("string shorter than its length"),
+        }
+    }
+
+    /// `"<n bytes>"`
+    fn quoted(&mut self, n: usize) -> Result<&'a str, String> {
+        self.expect(b'"')?;
+        let s = self.counted(n)?;
+        self.expect(b'"')?;
+        Ok(s)
+    }
+
+    fn parse_value(&mut self) -> Result<PhpValue, String> {
+        let start = self.pos;
+        let Some(&tag) = self.input.as_bytes().get(self.pos) else {
+            return self.error("unexpected end of data");
+        };
+        self.pos += 1;
+        if tag == b'N' {
+            self.expect(b';')?;
+            return Ok(PhpValue::Null);
+        }
+        self.expect(b':')?;
+
+        match tag {
+            b'b' => match self.until(b';')? {
+                "0" => Ok(PhpValue::Bool(false)),
+                "1" => Ok(PhpValue::Bool(true)),
+                _ => self.error("invalid boolean"),
+            },
+            b'i' => {
+                let digits = self.until(b';')?;
+                digits
+                    .parse()
+                    .map(PhpValue::Int)
+                    .or_else(|_| self.error(&format!("invalid integer '{}'", digits)))
+            }
+            b'd' => Ok(PhpValue::Float(self.until(b';')?.to_string())),
+            b's' => {
+                let n = self.count(b':')?;
+                let s = self.quoted(n)?;
+                self.expect(b';')?;
+                Ok(PhpValue::String(s.to_string()))
+            }
+            b'a' => {
+                let n = self.count(b':')?;
+                Ok(PhpValue::Array(self.members(n)?))
+            }
+            b'O' => {
+                let n = self.count(b':')?;
+                let class = self.quoted(n)?.to_string();
+                self.expect(b':')?;
+                let n = self.count(b':')?;
+                let members = self.members(n)?;
+                Ok(PhpValue::Object { class, members })
+            }
+            b'C' => {
+                let n = self.count(b':')?;
+                self.quoted(n)?;
+                self.expect(b':')?;
+                let n = self.count(b':')?;
+                self.expect(b'{')?;
+                self.counted(n)?;
+                self.expect(b'}')?;
+                Ok(PhpValue::Raw(self.input[start..self.pos].to_string()))
+            }
+            b'E' => {
+                let n = self.count(b':')?;
+                self.quoted(n)?;
+                self.expect(b';')?;
+                Ok(PhpValue::Raw(self.input[start..self.pos].to_string()))
+            }
+            b'r' | b'R' => {
+                let slot = self.count(b';')?;
+                Ok(PhpValue::Reference { alias: tag == b'R', slot })
+            }
+            _ => {
+                self.pos = start;
+                self.error(&format!("unknown type '{}'", tag as char))
+            }
+        }
+    }
+
+    /// `{key;value...}` with `n` members
+    fn members(&mut self, n: usize) -> Result<Vec<(PhpKey, PhpValue)>, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        for _ in 0..n {
+            let key = match self.parse_value()? {
+                PhpValue::Int(i) => PhpKey::Int(i.to_string()),
+                PhpValue::String(s) => PhpKey::Str(s),
+                _ => return self.error("invalid array key"),
+            };
+            members.push((key, self.parse_value()?));
+        }
+        self.expect(b'}')?;
+        Ok(members)
+    }
+}
+
+impl PhpValue {
+    /// Parse PHP serialized data. `charset` is the charset of the dump, in
+    /// which `s:N:` lengths are counted.
+    pub fn parse(input: &str, charset: Charset) -> Result<PhpValue, String> {
+        let mut parser = Parser {
+            input,
+            pos: 0,
+            count_chars: !charset.is_utf8(),
+        };
+        let value = parser.parse_value()?;
+        if parser.pos != input.len() {
+            return parser.error("trailing data");
+        }
+        Ok(value)
+    }
+
+    /// Serialize back, with string lengths counted in `charset`.
+    pub fn serialize(&self, charset: Charset) -> String {
+        let mut out = String::new();
+        self.serialize_into(&mut out, !charset.is_utf8());
+        out
+    }
+
+    fn serialize_into(&self, out: &mut String, count_chars: bool) {
+        let len = |s: &str| {
+            if count_chars {
+                s.chars().count()
+            } else {
+                s.len()
+            }
+        };
+        match self {
+            PhpValue::Null => out.push_str("N;"),
+            PhpValue::Bool(b) => out.push_str(if *b { "b:1;" } else { "b:0;" }),
+            PhpValue::Int(i) => out.push_str(&format!("i:{};", i)),
+            PhpValue::Float(f) => out.push_str(&format!("d:{};", f)),
+            PhpValue::String(s) => out.push_str(&format!("s:{}:\"{}\";", len(s), s)),
+            PhpValue::Array(members) => {
+                out.push_str(&format!("a:{}:", members.len()));
+                Self::serialize_members(members, out, count_chars);
+            }
+            PhpValue::Object { class, members } => {
+                out.push_str(&format!("O:{}:\"{}\":{}:", len(class), class, members.len()));
+                Self::serialize_members(members, out, count_chars);
+            }
+            PhpValue::Reference { alias, slot } => {
+                out.push_str(&format!("{}:{};", if *alias { 'R' } else { 'r' }, slot))
+            }
+            PhpValue::Raw(raw) => out.push_str(raw),
+        }
+    }
+
+    fn serialize_members(members: &[(PhpKey, PhpValue)], out: &mut String, count_chars: bool) {
+        out.push('{');
+        for (key, value) in members {
+            match key {
+                PhpKey::Int(i) => out.push_str(&format!("i:{};", i)),
+                PhpKey::Str(s) => PhpValue::String(s.clone()).serialize_into(out, count_chars),
+            }
+            value.serialize_into(out, count_chars);
+        }
+        out.push('}');
+    }
+
+    fn members(&self) -> Option<&Vec<(PhpKey, PhpValue)>> {
+        match self {
+            PhpValue::Array(members) | PhpValue::Object { members, .. } => Some(members),
+            _ => None,
+        }
+    }
+
+    fn members_mut(&mut self) -> Option<&mut Vec<(PhpKey, PhpValue)>> {
+        match self {
+            PhpValue::Array(members) | PhpValue::Object { members, .. } => Some(members),
+            _ => None,
+        }
+    }
+
+    fn at_mut(&mut self, location: &[usize]) -> &mut PhpValue {
+        location.iter().fold(self, |node, &i| {
+            &mut node.members_mut().expect("locations only go through containers")[i].1
+        })
+    }
+
+    /// Number of values numbered by references in this value: itself,
+    /// unless it is an `R:` reference, and the values it contains
+    fn slot_count(&self) -> usize {
+        let own = usize::from(!matches!(self, PhpValue::Reference { alias: true, .. }));
+        own + self.members().map_or(0, |m| m.iter().map(|(_, v)| v.slot_count()).sum())
+    }
+
+    /// Number of the value at `location`
+    fn location_slot(&self, location: &[usize]) -> usize {
+        let mut slot = 1;
+        let mut node = self;
+        for &i in location {
+            let members = node.members().expect("locations only go through containers");
+            slot += 1 + members[..i].iter().map(|(_, v)| v.slot_count()).sum::<usize>();
+            node = &members[i].1;
+        }
+        slot
+    }
+
+    /// Location of the value numbered `slot`
+    fn slot_location(&self, slot: usize) -> Option<Vec<usize>> {
+        let mut location = Vec::new();
+        let mut node = self;
+        let mut first = 1;
+        loop {
+            if first == slot && !matches!(node, PhpValue::Reference { alias: true, .. }) {
+                return Some(location);
+            }
+            let members = node.members()?;
+            first += 1;
+            let (i, child) = members.iter().enumerate().find_map(|(i, (_, v))| {
+                let count = v.slot_count();
+                if slot < first + count {
+                    Some((i, v))
+                } else {
+                    first += count;
+                    None
+                }
+            })?;
+            location.push(i);
+            node = child;
+        }
+    }
+
+    fn collect_references(&self, location: &mut Vec<usize>, found: &mut Vec<(Vec<usize>, usize)>) {
+        if let PhpValue::Reference { slot, .. } = self {
+            found.push((location.clone(), *slot));
+        }
+        for (i, (_, value)) in self.members().into_iter().flatten().enumerate() {
+            location.push(i);
+            value.collect_references(location, found);
+            location.pop();
+        }
+    }
+}
+
+/// The `r:` and `R:` references of a document, and the values they
+/// designate, followed by location while members are removed or replaced,
+/// so that their numbers can be recomputed once the document is edited.
+struct References(Vec<(Vec<usize>, Option<Vec<usize>>)>);
+
+impl References {
+    fn new(root: &PhpValue) -> References {
+        let mut found = Vec::new();
+        root.collect_references(&mut Vec::new(), &mut found);
+        // References to values that do not exist are left as they are
+        References(
+            found
+                .into_iter()
+                .filter_map(|(location, slot)| Some((location, Some(root.slot_location(slot)?))))
+                .collect(),
+        )
+    }
+
+    /// Follow the removal of the member at `removed`
+    fn removed(&mut self, removed: &[usize]) {
+        let Some((&index, parent)) = removed.split_last() else { return };
+        let after = |location: &[usize]| {
+            if location.starts_with(removed) {
+                return None;
+            }
+            let mut location = location.to_vec();
+            if location.len() > parent.len() && location.starts_with(parent) && location[parent.len()] > index {
+                location[parent.len()] -= 1;
+            }
+            Some(location)
+        };
+        self.0.retain_mut(|(location, target)| match after(location) {
+            Some(moved) => {
+                *location = moved;
+                *target = target.as_deref().and_then(after);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Follow the replacement of the value at `replaced` by a scalar
+    fn replaced(&mut self, replaced: &[usize]) {
+        self.0.retain_mut(|(location, target)| {
+            if target.as_ref().is_some_and(|t| t.len() > replaced.len() && t.starts_with(replaced)) {
+                *target = None;
+            }
+            !location.starts_with(replaced)
+        });
+    }
+
+    /// Renumber the references of the edited document. References to a
+    /// value that was removed become `N;`.
+    fn relink(self, root: &mut PhpValue) {
+        for (location, target) in &self.0 {
+            if target.is_none() {
+                *root.at_mut(location) = PhpValue::Null;
+            }
+        }
+        for (location, target) in &self.0 {
+            let Some(target) = target else { continue };
+            let new_slot = root.location_slot(target);
+            if let PhpValue::Reference { slot, .. } = root.at_mut(location) {
+                *slot = new_slot;
+            }
+        }
+    }
+}
+
+impl PathNode for PhpValue {
+    fn is_map(&self) -> bool {
+        self.members().is_some()
+    }
+
+    fn is_list(&self) -> bool {
+        matches!(self, PhpValue::Array(_))
+    }
+
+    fn child_count(&self) -> usize {
+        self.members().map_or(0, Vec::len)
+    }
+
+    fn child_key(&self, i: usize) -> Option<&str> {
+        self.members().map(|m| m[i].0.name())
+    }
+
+    fn child(&self, i: usize) -> &PhpValue {
+        &self.members().expect("scalars have no children")[i].1
+    }
+}
+
+/// Apply a rule to the values matched by `path` (same syntax as JSON
+/// paths; `[n]` is the n-th entry of an array). `anonymize` computes the
+/// anonymized text of a scalar, or `None` to leave it unchanged.
+/// Returns the number of values anonymized or removed.
+pub fn php_anonymize_with(
+    root: &mut PhpValue,
+    path: &JsonPath,
+    config: &AnonBase,
+    anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+) -> usize {
+    let mut locations = path_locations(root, path);
+    let mut references = References::new(root);
+
+    if config.anon_type == AnonType::Remove {
+        // Remove from the end so that the other locations stay valid
+        locations.sort_unstable();
+        locations.dedup();
+        let mut removed = 0;
+        for location in locations.iter().rev() {
+            if let Some((&i, parent)) = location.split_last() {
+                if let Some(members) = root.at_mut(parent).members_mut() {
+                    members.remove(i);
+                    references.removed(location);
+                    removed += 1;
+                }
+            }
+        }
+        references.relink(root);
+        return removed;
+    }
+
+    let mut anonymized = 0;
+    for location in locations {
+        let value = root.at_mut(&location);
+        if let Some(new_value) = anonymize_php_value(value, config, anonymize) {
+            *value = new_value;
+            references.replaced(&location);
+            anonymized += 1;
+        }
+    }
+    references.relink(root);
+    anonymized
+}
+
+fn anonymize_php_value(
+    value: &PhpValue,
+    config: &AnonBase,
+    anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+) -> Option<PhpValue> {
+    let is_fixed = matches!(
+        config.anon_type,
+        AnonType::Fixed | AnonType::FixedQuoted | AnonType::FixedUnquoted
+    );
+    let text = match value {
+        _ if config.anon_type == AnonType::FixedNull => return Some(PhpValue::Null),
+        PhpValue::String(_) if is_fixed => {
+            return Some(PhpValue::String(config.fixed_value.clone()))
+        }
+        _ if is_fixed => return Some(php_literal(&config.fixed_value, value)),
+        PhpValue::String(s) => {
+            let new = anonymize(s.as_bytes())?;
+            return Some(PhpValue::String(String::from_utf8_lossy(&new).into_owned()));
+        }
+        PhpValue::Int(i) => i.to_string(),
+        PhpValue::Float(f) => f.clone(),
+        PhpValue::Bool(b) => (if *b { "1" } else { "0" }).to_string(),
+        PhpValue::Null
+        | PhpValue::Array(_)
+        | PhpValue::Object { .. }
+        | PhpValue::Reference { .. }
+        | PhpValue::Raw(_) => return None,
+    };
+    let new = anonymize(text.as_bytes())?;
+    Some(php_literal(&String::from_utf8_lossy(&new), value))
+}
+
+/// Read `text` as a value of the same type as `like` if possible, else as a string.
+fn php_literal(text: &str, like: &PhpValue) -> PhpValue {
+    match like {
+        PhpValue::Int(_) | PhpValue::Float(_) => {
+            if let Ok(i) = text.parse::<i64>() {
+                return PhpValue::Int(i);
+            }
+            if text.parse::<f64>().is_ok() {
+                return PhpValue::Float(text.to_string());
+            }
+        }
+        PhpValue::Bool(_) if text == "0" || text == "1" => return PhpValue::Bool(text == "1"),
+        _ => {}
+    }
+    match text {
+        "null" => PhpValue::Null,
+        "true" => PhpValue::Bool(true),
+        "false" => PhpValue::Bool(false),
+        _ => PhpValue::String(text.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> PhpValue {
+        PhpValue::parse(input, Charset::Utf8).unwrap()
+    }
+
+    fn anonymize_all(input: &str, path: &str, infos: &AnonBase) -> (String, usize) {
+        let mut value = parse(input);
+        let path = JsonPath::parse(path).unwrap();
+        let count = php_anonymize_with(&mut value, &path, infos, &mut |token| {
+            Some(format!("<{}>", String::from_utf8_lossy(token)).into_bytes())
+        });
+        (value.serialize(Charset::Utf8), count)
+    }
+
+    fn rule(anon_type: AnonType) -> AnonBase {
+        AnonBase {
+            anon_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let inputs = [
+            "N;",
+            "b:1;",
+            "i:-42;",
+            "d:0.1;",
+            r#"s:6:"héllo";"#,
+            r#"a:2:{i:0;s:1:"a";s:3:"key";a:1:{i:5;b:0;}}"#,
+            "O:8:\"stdClass\":2:{s:5:\"email\";s:5:\"a@b.c\";s:4:\"\0*\0p\";N;}",
+            r#"C:11:"ArrayObject":21:{x:i:0;a:0:{};m:a:0:{}}"#,
+            r#"a:2:{i:0;O:1:"A":0:{}i:1;r:2;}"#,
+            r#"E:11:"Suit:Hearts";"#,
+        ];
+        for input in inputs {
+            assert_eq!(parse(input).serialize(Charset::Utf8), input);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for input in [
+            r#"s:10:"short";"#,
+            r#"a:2:{i:0;s:1:"a";}"#,
+            r#"i:1;x"#,
+            r#"x:1;"#,
+            r#"a:1:{d:1.5;N;}"#,
+            "",
+        ] {
+            assert!(PhpValue::parse(input, Charset::Utf8).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_single_byte_charset_lengths() {
+        // In a latin1 dump, 'é' is one byte
+        let value = PhpValue::parse(r#"s:5:"héllo";"#, Charset::Latin1).unwrap();
+        assert_eq!(value, PhpValue::String("héllo".to_string()));
+        assert_eq!(value.serialize(Charset::Latin1), r#"s:5:"héllo";"#);
+        assert_eq!(value.serialize(Charset::Utf8), r#"s:6:"héllo";"#);
+    }
+
+    #[test]
+    fn test_lengths_fixed_after_anonymization() {
+        let input = r#"a:2:{s:5:"email";s:5:"a@b.c";s:4:"name";s:4:"Zoé";}"#;
+        let (out, count) = anonymize_all(input, "*", &rule(AnonType::TextHash));
+        assert_eq!(count, 2);
+        assert_eq!(
+            out,
+            r#"a:2:{s:5:"email";s:7:"<a@b.c>";s:4:"name";s:6:"<Zoé>";}"#
+        );
+    }
+
+    #[test]
+    fn test_paths() {
+        let input = "a:2:{s:7:\"billing\";a:1:{s:5:\"email\";s:1:\"x\";}s:5:\"users\";a:2:{i:0;O:4:\"User\":1:{s:11:\"\0User\0email\";s:1:\"y\";}i:1;s:1:\"z\";}}";
+        let (out, count) = anonymize_all(input, "..email", &rule(AnonType::TextHash));
+        assert_eq!(count, 2);
+        assert_eq!(
+            out,
+            "a:2:{s:7:\"billing\";a:1:{s:5:\"email\";s:3:\"<x>\";}s:5:\"users\";a:2:{i:0;O:4:\"User\":1:{s:11:\"\0User\0email\";s:3:\"<y>\";}i:1;s:1:\"z\";}}"
+        );
+        let (out, _) = anonymize_all(input, "users[1]", &rule(AnonType::TextHash));
+        assert!(out.ends_with(r#"i:1;s:3:"<z>";}}"#));
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let input = r#"a:3:{s:2:"id";i:12;s:6:"active";b:1;s:4:"note";N;}"#;
+        let (out, count) = anonymize_all(input, "*", &rule(AnonType::IntHash));
+        // `<12>` is not a number anymore, the null is left alone
+        assert_eq!(count, 2);
+        assert_eq!(
+            out,
+            r#"a:3:{s:2:"id";s:4:"<12>";s:6:"active";s:3:"<1>";s:4:"note";N;}"#
+        );
+        let fixed = AnonBase {
+            anon_type: AnonType::Fixed,
+            fixed_value: "0".to_string(),
+            ..Default::default()
+        };
+        let (out, _) = anonymize_all(input, "*", &fixed);
+        assert_eq!(out, r#"a:3:{s:2:"id";i:0;s:6:"active";b:0;s:4:"note";s:1:"0";}"#);
+    }
+
+    #[test]
+    fn test_remove_and_null() {
+        let input = r#"a:3:{i:0;s:1:"a";i:1;s:1:"b";s:5:"token";s:1:"t";}"#;
+        let (out, count) = anonymize_all(input, "token", &rule(AnonType::Remove));
+        assert_eq!(count, 1);
+        assert_eq!(out, r#"a:2:{i:0;s:1:"a";i:1;s:1:"b";}"#);
+        let (out, _) = anonymize_all(input, "[]", &rule(AnonType::Remove));
+        assert_eq!(out, "a:0:{}");
+        let (out, _) = anonymize_all(input, "[1]", &rule(AnonType::FixedNull));
+        assert_eq!(out, r#"a:3:{i:0;s:1:"a";i:1;N;s:5:"token";s:1:"t";}"#);
+    }
+
+    #[test]
+    fn test_references_renumbered() {
+        // Values are numbered from 1: the array, "t", the object, "n", `r:3`
+        let input = r#"a:3:{s:5:"token";s:1:"t";s:4:"user";O:4:"User":1:{s:4:"name";s:1:"n";}s:4:"self";r:3;}"#;
+        let (out, _) = anonymize_all(input, "token", &rule(AnonType::Remove));
+        assert_eq!(out, r#"a:2:{s:4:"user";O:4:"User":1:{s:4:"name";s:1:"n";}s:4:"self";r:2;}"#);
+        let (out, _) = anonymize_all(input, "..name", &rule(AnonType::Remove));
+        assert_eq!(out, r#"a:3:{s:5:"token";s:1:"t";s:4:"user";O:4:"User":0:{}s:4:"self";r:3;}"#);
+
+        // `R:` takes no number; a reference to a removed value becomes `N;`,
+        // which takes one
+        let input = r#"a:4:{i:0;s:1:"a";i:1;R:2;i:2;O:1:"A":0:{}i:3;r:3;}"#;
+        let (out, _) = anonymize_all(input, "[0]", &rule(AnonType::Remove));
+        assert_eq!(out, r#"a:3:{i:1;N;i:2;O:1:"A":0:{}i:3;r:3;}"#);
+
+        // Same for the values of a container replaced by a scalar
+        let input = r#"a:3:{s:1:"o";a:1:{i:0;s:1:"v";}s:1:"r";R:3;s:1:"s";r:1;}"#;
+        let fixed = AnonBase {
+            anon_type: AnonType::Fixed,
+            fixed_value: "x".to_string(),
+            ..Default::default()
+        };
+        let (out, _) = anonymize_all(input, "o", &fixed);
+        assert_eq!(out, r#"a:3:{s:1:"o";s:1:"x";s:1:"r";N;s:1:"s";r:1;}"#);
+    }
+}
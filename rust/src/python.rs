@@ -33,7 +33,7 @@ _current_row = {{}}
 _current_table = ""
 
 def get_secret():
-    return {secret_repr}
+    return {secret_repr:?}
 
 def get_row():
     return _current_row
@@ -78,7 +78,7 @@ def escape_sql_string(s):
             result.append(c)
     return ''.join(result)
 "#,
-                secret_repr = format!("{:?}", secret)
+                secret_repr = secret
             );
 
             let utils_code_c = CString::new(utils_code)
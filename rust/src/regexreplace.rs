@@ -0,0 +1,263 @@
+use regex::bytes::{Captures, Regex};
+
+use crate::anonymize::{make_readable_hash_into, mysql_backslash_escape_into, mysql_unescape_bytes};
+use crate::config::MAX_LEN;
+
+/// Hash applied to a capture group in a template
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HashFunc {
+    /// `inthash` for groups made of digits only, `texthash` otherwise
+    Hash,
+    TextHash,
+    IntHash,
+}
+
+/// Capture group referenced by number or by name
+#[derive(Debug, Clone, PartialEq)]
+enum GroupRef {
+    Index(usize),
+    Name(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TemplatePart {
+    Literal(String),
+    Group(GroupRef),
+    /// Hash of a group; without a length, as long as the group (up to 32)
+    Hash {
+        func: HashFunc,
+        group: GroupRef,
+        len: Option<usize>,
+    },
+}
+
+/// `regexreplace 'pattern' 'template'` rule: every match of the pattern is
+/// replaced by the template, where `${1}` or `${name}` copy a capture group,
+/// `${hash:1:5}`, `${texthash:1:5}` and `${inthash:1:5}` hash it (the length
+/// is optional) and `$$` is a literal `$`.
+#[derive(Debug, Clone)]
+pub struct RegexReplace {
+    regex: Regex,
+    template: Vec<TemplatePart>,
+}
+
+impl RegexReplace {
+    /// Compile the pattern and the template, checking that the template only
+    /// references groups of the pattern.
+    pub fn new(pattern: &str, template: &str) -> Result<RegexReplace, String> {
+        let regex = Regex::new(pattern)
+            .map_err(|e| format!("Unable to compile regex '{}': {}", pattern, e))?;
+        let template = parse_template(template)?;
+        for part in &template {
+            let group = match part {
+                TemplatePart::Literal(_) => continue,
+                TemplatePart::Group(group) | TemplatePart::Hash { group, .. } => group,
+            };
+            let exists = match group {
+                GroupRef::Index(i) => *i < regex.captures_len(),
+                GroupRef::Name(name) => regex.capture_names().any(|n| n == Some(name.as_str())),
+            };
+            if !exists {
+                return Err(format!(
+                    "template references a group not defined in regex '{}'",
+                    pattern
+                ));
+            }
+        }
+        Ok(RegexReplace { regex, template })
+    }
+
+    /// Append `token` to `out` with every match replaced by the template.
+    /// The pattern is matched on the unescaped value, so that a match never
+    /// splits an escape sequence, and the result is escaped back for MySQL.
+    pub fn replace_into(&self, out: &mut Vec<u8>, token: &[u8], secret: &[u8]) {
+        let (value, _) = mysql_unescape_bytes(token);
+        let mut replaced = Vec::with_capacity(value.len());
+        let mut last = 0;
+        for caps in self.regex.captures_iter(&value) {
+            let whole = caps.get(0).expect("group 0 always matches");
+            replaced.extend_from_slice(&value[last..whole.start()]);
+            for part in &self.template {
+                match part {
+                    TemplatePart::Literal(text) => replaced.extend_from_slice(text.as_bytes()),
+                    TemplatePart::Group(group) => replaced.extend_from_slice(group_text(&caps, group)),
+                    TemplatePart::Hash { func, group, len } => {
+                        let text = group_text(&caps, group);
+                        let len = len
+                            .unwrap_or_else(|| String::from_utf8_lossy(text).chars().count())
+                            .min(MAX_LEN as usize);
+                        let digits = match func {
+                            HashFunc::Hash => !text.is_empty() && text.iter().all(u8::is_ascii_digit),
+                            HashFunc::IntHash => true,
+                            HashFunc::TextHash => false,
+                        };
+                        if digits {
+                            make_readable_hash_into(&mut replaced, text, secret, len, b'1', b'9');
+                        } else {
+                            make_readable_hash_into(&mut replaced, text, secret, len, b'a', b'z');
+                        }
+                    }
+                }
+            }
+            last = whole.end();
+        }
+        replaced.extend_from_slice(&value[last..]);
+        mysql_backslash_escape_into(out, &replaced);
+    }
+}
+
+/// Text of a group, empty if it did not take part in the match
+fn group_text<'t>(caps: &Captures<'t>, group: &GroupRef) -> &'t [u8] {
+    let m = match group {
+        GroupRef::Index(i) => caps.get(*i),
+        GroupRef::Name(name) => caps.name(name),
+    };
+    m.map_or(&[][..], |m| m.as_bytes())
+}
+
+fn parse_group(text: &str) -> Result<GroupRef, String> {
+    if text.is_empty() {
+        return Err("empty group reference in template".to_string());
+    }
+    if text.bytes().all(|b| b.is_ascii_digit()) {
+        return text
+            .parse()
+            .map(GroupRef::Index)
+            .map_err(|_| format!("invalid group number '{}' in template", text));
+    }
+    if text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(GroupRef::Name(text.to_string()))
+    } else {
+        Err(format!("invalid group name '{}' in template", text))
+    }
+}
+
+fn parse_template(template: &str) -> Result<Vec<TemplatePart>, String> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut rest = template;
+    while let Some(pos) = rest.find('$') {
+        literal.push_str(&rest[..pos]);
+        rest = &rest[pos + 1..];
+        if let Some(after) = rest.strip_prefix('$') {
+            literal.push('$');
+            rest = after;
+            continue;
+        }
+        let Some(body) = rest.strip_prefix('{') else {
+            return Err("'$' in template must start '${...}' or be doubled".to_string());
+        };
+        let end = body.find('}').ok_or("unterminated '${' in template")?;
+        let reference = &body[..end];
+        rest = &body[end + 1..];
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+        }
+
+        let fields: Vec<&str> = reference.split(':').collect();
+        let part = match fields.as_slice() {
+            [group] => TemplatePart::Group(parse_group(group)?),
+            [func, group, len @ ..] if len.len() <= 1 => {
+                let func = match *func {
+                    "hash" => HashFunc::Hash,
+                    "texthash" => HashFunc::TextHash,
+                    "inthash" => HashFunc::IntHash,
+                    _ => return Err(format!("unknown function '{}' in template", func)),
+                };
+                let len = match len.first() {
+                    Some(len) => match len.parse::<usize>() {
+                        Ok(n) if (1..=MAX_LEN as usize).contains(&n) => Some(n),
+                        _ => {
+                            return Err(format!(
+                                "hash length '{}' in template must be between 1 and {}",
+                                len, MAX_LEN
+                            ))
+                        }
+                    },
+                    None => None,
+                };
+                TemplatePart::Hash {
+                    func,
+                    group: parse_group(group)?,
+                    len,
+                }
+            }
+            _ => return Err(format!("invalid reference '${{{}}}' in template", reference)),
+        };
+        parts.push(part);
+    }
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        parts.push(TemplatePart::Literal(literal));
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replace(pattern: &str, template: &str, token: &str) -> String {
+        let mut out = Vec::new();
+        RegexReplace::new(pattern, template)
+            .unwrap()
+            .replace_into(&mut out, token.as_bytes(), b"secret");
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_groups_and_literals() {
+        assert_eq!(
+            replace(r"^CUST-(\d+)-(\d+)-(\w+)$", "CUST-${1}-X-${3}", "CUST-2023-00042-FR"),
+            "CUST-2023-X-FR"
+        );
+        assert_eq!(
+            replace(r"user:(?<id>\d+)", "member:${id}, cost $$1, l''x", "user:12 and user:7"),
+            r"member:12, cost $1, l\'\'x and member:7, cost $1, l\'\'x"
+        );
+        assert_eq!(replace(r"^x(y)?$", "[${1}]", "x"), "[]");
+        assert_eq!(replace(r"\d", "#", "no digits"), "no digits");
+    }
+
+    #[test]
+    fn test_escapes_not_split() {
+        // The value is abc'defg: four characters, then the rest
+        assert_eq!(replace(r"^(.{4}).*$", "${1}", r"abc\'defg"), r"abc\'");
+        assert_eq!(replace(r"^(.{4}).*$", "${1}", "abc''defg"), r"abc\'");
+        assert_eq!(replace(r"\\(\w)", "/${1}", r"C:\\dir\\x"), "C:/dir/x");
+        assert_eq!(replace(r"\n", " ", r"a\nb"), "a b");
+    }
+
+    #[test]
+    fn test_hashed_groups() {
+        let out = replace(r"^(\w+)-(\d+)-(\w+)$", "${1}-${hash:2:5}-${hash:3}", "CUST-00042-FR");
+        let (id, country) = out["CUST-".len()..].split_once('-').unwrap();
+        assert_eq!(id.len(), 5);
+        assert!(id.bytes().all(|b| (b'1'..=b'9').contains(&b)));
+        assert_eq!(country.len(), 2);
+        assert!(country.bytes().all(|b| b.is_ascii_lowercase()));
+
+        // Same group value, same hash, as with the texthash and inthash rules
+        let a = replace(r"(\w+)", "${texthash:1:8}", "john");
+        assert_eq!(a, replace(r"^.*$", "${texthash:0:8}", "john"));
+        assert_ne!(a, replace(r"(\w+)", "${texthash:1:8}", "jane"));
+        assert!(replace(r"(\w+)", "${inthash:1:4}", "john").bytes().all(|b| b.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        for (pattern, template) in [
+            (r"(\d+)", "${2}"),
+            (r"(\d+)", "${name}"),
+            (r"(\d+)", "${md5:1}"),
+            (r"(\d+)", "${hash:1:33}"),
+            (r"(\d+)", "${hash:1:0}"),
+            (r"(\d+)", "${1"),
+            (r"(\d+)", "$1"),
+            (r"(\d+)", "${}"),
+            (r"(\d+", "${1}"),
+        ] {
+            assert!(RegexReplace::new(pattern, template).is_err(), "{} {}", pattern, template);
+        }
+    }
+}
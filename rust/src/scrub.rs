@@ -0,0 +1,252 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::cardhash::luhn_check_digit;
+
+/// Kind of personal data searched in free text by a `scrub { ... }` rule.
+#[derive(Debug, Clone)]
+pub enum Detector {
+    Email,
+    /// Phone numbers: 9 to 15 digits, or 7 to 15 after a leading `+`
+    Phone,
+    Ipv4,
+    Ipv6,
+    /// Payment card numbers of 13 to 19 digits passing the Luhn check
+    Card,
+    /// IBANs passing the mod-97 check
+    Iban,
+    /// `http://`, `https://` and `ftp://` URLs
+    Url,
+    /// Whole matches of a user regex
+    Regex(Regex),
+}
+
+impl PartialEq for Detector {
+    fn eq(&self, other: &Detector) -> bool {
+        match (self, other) {
+            (Detector::Regex(a), Detector::Regex(b)) => a.as_str() == b.as_str(),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl fmt::Display for Detector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Detector::Email => write!(f, "email"),
+            Detector::Phone => write!(f, "phone"),
+            Detector::Ipv4 => write!(f, "ipv4"),
+            Detector::Ipv6 => write!(f, "ipv6"),
+            Detector::Card => write!(f, "card"),
+            Detector::Iban => write!(f, "iban"),
+            Detector::Url => write!(f, "url"),
+            Detector::Regex(re) => write!(f, "regex '{}'", re.as_str()),
+        }
+    }
+}
+
+macro_rules! builtin_regex {
+    ($pattern:expr) => {{
+        static RE: OnceLock<Regex> = OnceLock::new();
+        RE.get_or_init(|| Regex::new($pattern).unwrap())
+    }};
+}
+
+impl Detector {
+    /// Regex finding candidates, which `accept` then validates
+    fn regex(&self) -> &Regex {
+        match self {
+            Detector::Email => builtin_regex!(
+                r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}"
+            ),
+            Detector::Phone => builtin_regex!(r"(?:\+|\(|\b)\d[\d .()-]{5,20}\d\b"),
+            Detector::Ipv4 => builtin_regex!(r"\b\d{1,3}(?:\.\d{1,3}){3}\b"),
+            Detector::Ipv6 => builtin_regex!(r"(?i)(?:[0-9a-f]{1,4})?(?::(?:[0-9a-f]{1,4})?){2,7}"),
+            Detector::Card => builtin_regex!(r"\b\d(?:[ -]?\d){12,18}\b"),
+            Detector::Iban => builtin_regex!(r"\b[A-Z]{2}\d{2}(?: ?[A-Z0-9]){11,30}\b"),
+            Detector::Url => builtin_regex!(r#"\b(?:https?|ftp)://[^\s<>"'`]+"#),
+            Detector::Regex(re) => re,
+        }
+    }
+
+    /// Validate a candidate, possibly trimming it. Built-in detectors only
+    /// accept matches that are not glued to other letters or digits.
+    fn accept(&self, text: &str, range: Range<usize>) -> Option<Range<usize>> {
+        let mut range = range;
+        if let Detector::Url = self {
+            let trimmed = text[range.clone()].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']', '}']);
+            range.end = range.start + trimmed.len();
+        }
+        let candidate = &text[range.clone()];
+        let glued = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if !matches!(self, Detector::Regex(_))
+            && (glued(text[..range.start].chars().next_back()) || glued(text[range.end..].chars().next()))
+        {
+            return None;
+        }
+        let digits = || candidate.bytes().filter(u8::is_ascii_digit).count();
+        let valid = match self {
+            Detector::Phone => {
+                let min = if candidate.starts_with('+') { 7 } else { 9 };
+                (min..=15).contains(&digits())
+                    && candidate.matches('(').count() == candidate.matches(')').count()
+                    && !candidate.contains("  ")
+            }
+            Detector::Ipv4 => candidate.parse::<Ipv4Addr>().is_ok(),
+            Detector::Ipv6 => {
+                candidate.parse::<Ipv6Addr>().is_ok()
+                    && candidate.bytes().any(|b| b.is_ascii_hexdigit())
+                    && !text[..range.start].ends_with(':')
+                    && !text[range.end..].starts_with(':')
+            }
+            Detector::Card => luhn_valid(candidate),
+            Detector::Iban => iban_valid(candidate),
+            Detector::Email | Detector::Url | Detector::Regex(_) => !candidate.is_empty(),
+        };
+        valid.then_some(range)
+    }
+}
+
+/// Luhn checksum of the digits of `number`.
+fn luhn_valid(number: &str) -> bool {
+    let digits: Vec<u8> = number.bytes().filter(u8::is_ascii_digit).collect();
+    digits.split_last().is_some_and(|(&check, payload)| luhn_check_digit(payload) == check)
+}
+
+/// ISO 13616 check: the country and check digits moved to the end, letters
+/// read as 10-35, the number modulo 97 is 1.
+fn iban_valid(iban: &str) -> bool {
+    let compact: Vec<u8> = iban.bytes().filter(|&b| b != b' ').collect();
+    if !(15..=34).contains(&compact.len()) {
+        return false;
+    }
+    let mut rem = 0u32;
+    for &b in compact[4..].iter().chain(&compact[..4]) {
+        let value = match b {
+            b'0'..=b'9' => (b - b'0') as u32,
+            b'A'..=b'Z' => (b - b'A') as u32 + 10,
+            _ => return false,
+        };
+        rem = if value >= 10 {
+            (rem * 100 + value) % 97
+        } else {
+            (rem * 10 + value) % 97
+        };
+    }
+    rem == 1
+}
+
+/// Find personal data in `text`. Returns the byte ranges of the matches,
+/// with the index of the detector that found each, in text order. Matches
+/// do not overlap: the earliest one wins, then the longest, then the one of
+/// the first detector.
+pub fn find_matches<'d>(
+    text: &str,
+    detectors: impl IntoIterator<Item = &'d Detector>,
+) -> Vec<(Range<usize>, usize)> {
+    let mut candidates = Vec::new();
+    for (i, detector) in detectors.into_iter().enumerate() {
+        for m in detector.regex().find_iter(text) {
+            if let Some(range) = detector.accept(text, m.range()) {
+                candidates.push((range, i));
+            }
+        }
+    }
+    candidates.sort_by_key(|(range, i)| (range.start, std::cmp::Reverse(range.end), *i));
+
+    let mut matches: Vec<(Range<usize>, usize)> = Vec::new();
+    for (range, i) in candidates {
+        if matches.last().is_none_or(|(last, _)| range.start >= last.end) {
+            matches.push((range, i));
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(detector: Detector, text: &str) -> Vec<String> {
+        find_matches(text, [&detector])
+            .into_iter()
+            .map(|(range, _)| text[range].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_email_and_url() {
+        assert_eq!(
+            found(Detector::Email, "Mail john.doe+x@mail.example.org, not me@localhost."),
+            ["john.doe+x@mail.example.org"]
+        );
+        assert_eq!(
+            found(Detector::Url, "See https://example.com/a?b=c). Or (ftp://x.org/f.txt."),
+            ["https://example.com/a?b=c", "ftp://x.org/f.txt"]
+        );
+    }
+
+    #[test]
+    fn test_phone() {
+        assert_eq!(
+            found(
+                Detector::Phone,
+                "Call +33 6 12 34 56 78 or (555) 123-4567, order 12345, on 2024-01-15 at 555-1234"
+            ),
+            ["+33 6 12 34 56 78", "(555) 123-4567"]
+        );
+    }
+
+    #[test]
+    fn test_ip_addresses() {
+        assert_eq!(
+            found(Detector::Ipv4, "from 192.168.1.10 and 10.0.0.256, version 1.2.3.4a"),
+            ["192.168.1.10"]
+        );
+        assert_eq!(
+            found(Detector::Ipv6, "from 2001:db8::8a2e:370:7334 and ::1 at 10:30:45, mac 00:1a:2b:3c:4d:5e"),
+            ["2001:db8::8a2e:370:7334", "::1"]
+        );
+    }
+
+    #[test]
+    fn test_card_and_iban() {
+        assert_eq!(
+            found(Detector::Card, "card 4111 1111 1111 1111 or 4111-1111-1111-1112"),
+            ["4111 1111 1111 1111"]
+        );
+        assert_eq!(
+            found(Detector::Iban, "IBAN FR76 3000 6000 0112 3456 7890 189, not FR76 3000 6000 0112 3456 7890 188"),
+            ["FR76 3000 6000 0112 3456 7890 189"]
+        );
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let detectors = [
+            Detector::Email,
+            Detector::Url,
+            Detector::Phone,
+            Detector::Card,
+            Detector::Regex(Regex::new("EMP-[0-9]+").unwrap()),
+        ];
+        let text = "https://x.org/?u=a@b.com a@b.com 4111111111111111 EMP-42";
+        let matches: Vec<_> = find_matches(text, &detectors)
+            .into_iter()
+            .map(|(range, i)| (&text[range], i))
+            .collect();
+        assert_eq!(
+            matches,
+            [
+                ("https://x.org/?u=a@b.com", 1),
+                ("a@b.com", 0),
+                ("4111111111111111", 3),
+                ("EMP-42", 4)
+            ]
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use crate::anonymize::hmac_digest;
+
+/// Default number of bins of a numeric histogram
+pub const DEFAULT_BINS: usize = 20;
+
+/// Settings of a `synthesize` rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct SynthesizeRule {
+    /// `categorical`: numbers are drawn among the values found, not from a histogram
+    pub categorical: bool,
+    /// Number of bins of the histogram of a numeric column (`bins 'N'`)
+    pub bins: usize,
+    /// Column name, part of the draw seed; set with the profile
+    pub column: String,
+    /// Distribution of the column, set by the profiling pass over the dump
+    pub profile: Option<Distribution>,
+}
+
+impl Default for SynthesizeRule {
+    fn default() -> Self {
+        SynthesizeRule {
+            categorical: false,
+            bins: DEFAULT_BINS,
+            column: String::new(),
+            profile: None,
+        }
+    }
+}
+
+/// Value distribution of a column, values are drawn from
+#[derive(Debug, Clone, PartialEq)]
+pub enum Distribution {
+    /// Distinct values, sorted, with their cumulated counts
+    Categorical { values: Vec<Vec<u8>>, cumulative: Vec<u64> },
+    /// Equal-width bins between the smallest and largest values, with their
+    /// cumulated counts. Draws have the largest number of decimals found.
+    Histogram {
+        min: f64,
+        max: f64,
+        cumulative: Vec<u64>,
+        decimals: usize,
+    },
+}
+
+/// Value and number of decimals of a plain decimal number (`-12.50`)
+fn plain_decimal(value: &[u8]) -> Option<(f64, usize)> {
+    let digits = value.strip_prefix(b"-").unwrap_or(value);
+    let (int, frac) = match digits.iter().position(|&b| b == b'.') {
+        Some(dot) => (&digits[..dot], &digits[dot + 1..]),
+        None => (digits, &b""[..]),
+    };
+    if int.is_empty() || !int.iter().all(u8::is_ascii_digit) || !frac.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    if digits.len() > int.len() && frac.is_empty() {
+        return None;
+    }
+    let number = std::str::from_utf8(value).ok()?.parse::<f64>().ok()?;
+    Some((number, frac.len()))
+}
+
+/// Values of a column read by the profiling pass. Only distinct values are
+/// kept, with their number of occurrences.
+#[derive(Debug, Default)]
+pub struct ColumnProfile {
+    counts: HashMap<Vec<u8>, u64>,
+    /// A value was quoted or is not a plain decimal number
+    not_numeric: bool,
+}
+
+impl ColumnProfile {
+    /// Add a non-NULL value, without its quotes. `quoted` tells whether it
+    /// was quoted in the dump.
+    pub fn add(&mut self, value: &[u8], quoted: bool) {
+        if !self.not_numeric && (quoted || plain_decimal(value).is_none()) {
+            self.not_numeric = true;
+        }
+        match self.counts.get_mut(value) {
+            Some(count) => *count += 1,
+            None => {
+                self.counts.insert(value.to_vec(), 1);
+            }
+        }
+    }
+
+    /// Distribution of the values added: a histogram of `bins` bins for
+    /// columns of unquoted plain numbers, unless `categorical` is set, the
+    /// frequencies of the values otherwise.
+    pub fn distribution(&self, categorical: bool, bins: usize) -> Distribution {
+        if !categorical && !self.not_numeric && !self.counts.is_empty() {
+            let numbers: Vec<(f64, usize, u64)> = self
+                .counts
+                .iter()
+                .filter_map(|(v, &count)| plain_decimal(v).map(|(n, d)| (n, d, count)))
+                .collect();
+            let min = numbers.iter().map(|n| n.0).fold(f64::INFINITY, f64::min);
+            let max = numbers.iter().map(|n| n.0).fold(f64::NEG_INFINITY, f64::max);
+            let decimals = numbers.iter().map(|n| n.1).max().unwrap_or(0);
+            let bins = bins.max(1);
+            let mut counts = vec![0u64; bins];
+            for &(number, _, count) in &numbers {
+                counts[bin_of(number, min, max, bins)] += count;
+            }
+            return Distribution::Histogram {
+                min,
+                max,
+                cumulative: cumulate(counts),
+                decimals,
+            };
+        }
+
+        let mut values: Vec<(&Vec<u8>, u64)> = self.counts.iter().map(|(v, &c)| (v, c)).collect();
+        values.sort();
+        Distribution::Categorical {
+            values: values.iter().map(|(v, _)| v.to_vec()).collect(),
+            cumulative: cumulate(values.iter().map(|(_, c)| *c)),
+        }
+    }
+}
+
+fn bin_of(number: f64, min: f64, max: f64, bins: usize) -> usize {
+    if max <= min {
+        return 0;
+    }
+    (((number - min) / (max - min) * bins as f64) as usize).min(bins - 1)
+}
+
+fn cumulate(counts: impl IntoIterator<Item = u64>) -> Vec<u64> {
+    counts
+        .into_iter()
+        .scan(0u64, |total, count| {
+            *total += count;
+            Some(*total)
+        })
+        .collect()
+}
+
+/// Index drawn with a probability proportional to its count
+fn draw_index(cumulative: &[u64], random: u64) -> usize {
+    let total = cumulative.last().copied().unwrap_or(0);
+    let target = ((random as u128 * total as u128) >> 64) as u64;
+    cumulative.partition_point(|&c| c <= target)
+}
+
+/// Replace a value by a draw from the distribution of its column. The draw
+/// is derived from the HMAC of the table, the column, the row and the
+/// value, so the output only changes when the dump or the secret does.
+/// Returns false, writing nothing, when the column has no profile.
+pub fn synthesize_into(
+    out: &mut Vec<u8>,
+    token: &[u8],
+    rule: &SynthesizeRule,
+    secret: &[u8],
+    table: &str,
+    row: i32,
+) -> bool {
+    let Some(profile) = &rule.profile else {
+        return false;
+    };
+    let mut seed = format!("synthesize\0{}\0{}\0{}\0", table, rule.column, row).into_bytes();
+    seed.extend_from_slice(token);
+    let digest = hmac_digest(&seed, secret);
+    let random = |i: usize| u64::from_be_bytes(digest[i * 8..i * 8 + 8].try_into().unwrap());
+
+    match profile {
+        Distribution::Categorical { values, cumulative } => {
+            let Some(value) = values.get(draw_index(cumulative, random(0))) else {
+                return false;
+            };
+            out.extend_from_slice(value);
+        }
+        Distribution::Histogram {
+            min,
+            max,
+            cumulative,
+            decimals,
+        } => {
+            let bins = cumulative.len();
+            let width = (max - min) / bins as f64;
+            let bin = draw_index(cumulative, random(0));
+            // 53 random bits: a uniform position within the bin
+            let position = (random(1) >> 11) as f64 / (1u64 << 53) as f64;
+            let number = (min + (bin as f64 + position) * width).clamp(*min, *max);
+            let text = format!("{:.*}", decimals, number);
+            // Rounding may give -0
+            match text.strip_prefix('-') {
+                Some(unsigned) if unsigned.bytes().all(|b| b == b'0' || b == b'.') => {
+                    out.extend_from_slice(unsigned.as_bytes())
+                }
+                _ => out.extend_from_slice(text.as_bytes()),
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profiled_rule(profile: &ColumnProfile, categorical: bool) -> SynthesizeRule {
+        SynthesizeRule {
+            categorical,
+            column: "c".to_string(),
+            profile: Some(profile.distribution(categorical, DEFAULT_BINS)),
+            ..Default::default()
+        }
+    }
+
+    fn draw(rule: &SynthesizeRule, row: i32) -> String {
+        let mut out = Vec::new();
+        assert!(synthesize_into(&mut out, b"x", rule, b"secret", "t", row));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_plain_decimal() {
+        assert_eq!(plain_decimal(b"42"), Some((42.0, 0)));
+        assert_eq!(plain_decimal(b"-12.50"), Some((-12.5, 2)));
+        for value in [&b""[..], b"-", b"1.", b".5", b"1e5", b"0x1F", b"1-2", b"abc"] {
+            assert_eq!(plain_decimal(value), None, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_categorical() {
+        let mut profile = ColumnProfile::default();
+        for _ in 0..900 {
+            profile.add(b"active", true);
+        }
+        for _ in 0..100 {
+            profile.add(b"closed", true);
+        }
+        let rule = profiled_rule(&profile, false);
+        assert_eq!(
+            rule.profile,
+            Some(Distribution::Categorical {
+                values: vec![b"active".to_vec(), b"closed".to_vec()],
+                cumulative: vec![900, 1000],
+            })
+        );
+        let closed = (0..10000).filter(|&row| draw(&rule, row) == "closed").count();
+        assert!((800..1200).contains(&closed), "{}", closed);
+        assert_eq!(draw(&rule, 7), draw(&rule, 7));
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut profile = ColumnProfile::default();
+        for age in 18..=65 {
+            profile.add(age.to_string().as_bytes(), false);
+        }
+        profile.add(b"90", false);
+        let rule = profiled_rule(&profile, false);
+        let Some(Distribution::Histogram { min, max, ref cumulative, decimals }) = rule.profile else {
+            panic!("{:?}", rule.profile);
+        };
+        assert_eq!((min, max, decimals, cumulative.len()), (18.0, 90.0, 0, DEFAULT_BINS));
+        assert_eq!(cumulative.last(), Some(&49));
+        let draws: Vec<i64> = (0..5000).map(|row| draw(&rule, row).parse().unwrap()).collect();
+        assert!(draws.iter().all(|d| (18..=90).contains(d)));
+        let old = draws.iter().filter(|&&d| d > 68).count();
+        assert!(old < 250, "{}", old);
+        let mean = draws.iter().sum::<i64>() as f64 / draws.len() as f64;
+        assert!((40.0..46.0).contains(&mean), "{}", mean);
+
+        // Decimals are kept, a quoted or non-numeric value makes it categorical
+        let mut profile = ColumnProfile::default();
+        profile.add(b"-1.5", false);
+        profile.add(b"2.25", false);
+        let decimals = draw(&profiled_rule(&profile, false), 3);
+        assert_eq!(decimals.split('.').nth(1).map(str::len), Some(2), "{}", decimals);
+        profile.add(b"3", true);
+        assert!(matches!(profile.distribution(false, 10), Distribution::Categorical { .. }));
+        let mut profile = ColumnProfile::default();
+        profile.add(b"7", false);
+        assert!(matches!(profile.distribution(true, 10), Distribution::Categorical { .. }));
+        assert_eq!(draw(&profiled_rule(&profile, false), 1), "7");
+    }
+
+    #[test]
+    fn test_without_profile() {
+        let mut out = Vec::new();
+        assert!(!synthesize_into(&mut out, b"x", &SynthesizeRule::default(), b"s", "t", 1));
+        let empty = SynthesizeRule {
+            profile: Some(ColumnProfile::default().distribution(false, 10)),
+            ..Default::default()
+        };
+        assert!(!synthesize_into(&mut out, b"x", &empty, b"s", "t", 1));
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,367 @@
+use std::fmt;
+
+/// Part of a URL targeted by a rule of a `url { ... }` block.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UrlComponent {
+    /// Value of the query parameters with this (decoded) name, or all of them for `*`
+    Param(String),
+    Host,
+    /// `user:password` before the host
+    UserInfo,
+    /// n-th path segment, 1-based
+    Segment(usize),
+    Fragment,
+    /// Rule applied to the whole value when it is not a URL
+    Fallback,
+}
+
+impl fmt::Display for UrlComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlComponent::Param(name) => write!(f, "param '{}'", name),
+            UrlComponent::Host => write!(f, "host"),
+            UrlComponent::UserInfo => write!(f, "userinfo"),
+            UrlComponent::Segment(n) => write!(f, "segment {}", n),
+            UrlComponent::Fragment => write!(f, "fragment"),
+            UrlComponent::Fallback => write!(f, "fallback"),
+        }
+    }
+}
+
+/// Characters kept as they are when encoding a component, besides
+/// unreserved ones (RFC 3986).
+const QUERY_SAFE: &str = "!$'()*,;:@/?";
+const PATH_SAFE: &str = "!$&'()*+,;=:@";
+const HOST_SAFE: &str = "!$&'()*+,;=";
+const USERINFO_SAFE: &str = "!$&'()*+,;=:";
+const FRAGMENT_SAFE: &str = "!$&'()*+,;=:@/?";
+
+/// A URL split into its components, each kept as written so that the
+/// parts no rule touches are written back unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Url {
+    /// `scheme://`, `//`, or empty for a path
+    prefix: String,
+    userinfo: Option<String>,
+    host: String,
+    /// `:port`, if any
+    port: String,
+    /// Path split on `/`; a path starting with `/` has an empty first segment
+    segments: Vec<String>,
+    /// Query parameters, as `name` and optional `=value`
+    query: Option<Vec<(String, Option<String>)>>,
+    fragment: Option<String>,
+}
+
+impl Url {
+    /// Parse an absolute (`scheme://...`), protocol-relative (`//...`) URL
+    /// or an absolute path (`/...`), with optional query and fragment.
+    pub fn parse(text: &str) -> Result<Url, String> {
+        if text.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err("whitespace in URL".to_string());
+        }
+        let (rest, fragment) = match text.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment.to_string())),
+            None => (text, None),
+        };
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(parse_query(query))),
+            None => (rest, None),
+        };
+
+        let scheme_len = rest.find("://").filter(|&i| {
+            let scheme = &rest[..i];
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c))
+        });
+        let (prefix, rest) = match scheme_len {
+            Some(i) => rest.split_at(i + 3),
+            None if rest.starts_with("//") => rest.split_at(2),
+            None if rest.starts_with('/') => ("", rest),
+            None => return Err("not a URL".to_string()),
+        };
+
+        let (mut userinfo, mut host, mut port) = (None, String::new(), String::new());
+        let path = if prefix.is_empty() {
+            rest
+        } else {
+            let end = rest.find('/').unwrap_or(rest.len());
+            let (authority, path) = rest.split_at(end);
+            let host_port = match authority.rsplit_once('@') {
+                Some((info, host_port)) => {
+                    userinfo = Some(info.to_string());
+                    host_port
+                }
+                None => authority,
+            };
+            let port_start = if host_port.starts_with('[') {
+                let close = host_port.find(']').ok_or("unterminated IPv6 address")?;
+                close + 1
+            } else {
+                host_port.rfind(':').unwrap_or(host_port.len())
+            };
+            let (h, p) = host_port.split_at(port_start);
+            if h.is_empty() || !(p.is_empty() || p[1..].bytes().all(|b| b.is_ascii_digit())) {
+                return Err("invalid host".to_string());
+            }
+            host = h.to_string();
+            port = p.to_string();
+            path
+        };
+
+        Ok(Url {
+            prefix: prefix.to_string(),
+            userinfo,
+            host,
+            port,
+            segments: if path.is_empty() {
+                Vec::new()
+            } else {
+                path.split('/').map(str::to_string).collect()
+            },
+            query,
+            fragment,
+        })
+    }
+
+    /// Apply a rule to a component. `anonymize` computes the new decoded bytes
+    /// from the decoded ones, `None` removing the component where possible.
+    /// Returns the number of values changed or removed.
+    pub fn apply(
+        &mut self,
+        component: &UrlComponent,
+        anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+    ) -> usize {
+        match component {
+            UrlComponent::Param(name) => {
+                let Some(query) = &mut self.query else {
+                    return 0;
+                };
+                let mut count = 0;
+                query.retain_mut(|(raw_name, value)| {
+                    if name != "*" && percent_decode(raw_name, true) != name.as_bytes() {
+                        return true;
+                    }
+                    count += 1;
+                    let decoded = value.as_deref().map_or(Vec::new(), |v| percent_decode(v, true));
+                    match anonymize(&decoded) {
+                        Some(new) => {
+                            *value = Some(percent_encode(&new, QUERY_SAFE));
+                            true
+                        }
+                        None => false,
+                    }
+                });
+                count
+            }
+            UrlComponent::Host if !self.host.is_empty() => {
+                let bracketed = self.host.starts_with('[');
+                let inner = if bracketed {
+                    &self.host[1..self.host.len() - 1]
+                } else {
+                    &self.host
+                };
+                let Some(new) = anonymize(&percent_decode(inner, false)) else {
+                    return 0;
+                };
+                self.host = if bracketed && new.contains(&b':') {
+                    format!("[{}]", String::from_utf8_lossy(&new))
+                } else {
+                    percent_encode(&new, HOST_SAFE)
+                };
+                1
+            }
+            UrlComponent::UserInfo => {
+                let Some(info) = &self.userinfo else {
+                    return 0;
+                };
+                self.userinfo = anonymize(&percent_decode(info, false))
+                    .map(|new| percent_encode(&new, USERINFO_SAFE));
+                1
+            }
+            UrlComponent::Segment(n) => {
+                // A path starting with '/' has an empty segment 0
+                match self.segments.get(*n) {
+                    Some(segment) if !segment.is_empty() => {
+                        match anonymize(&percent_decode(segment, false)) {
+                            Some(new) => self.segments[*n] = percent_encode(&new, PATH_SAFE),
+                            None => {
+                                self.segments.remove(*n);
+                            }
+                        }
+                        1
+                    }
+                    _ => 0,
+                }
+            }
+            UrlComponent::Fragment => {
+                let Some(fragment) = &self.fragment else {
+                    return 0;
+                };
+                self.fragment = anonymize(&percent_decode(fragment, false))
+                    .map(|new| percent_encode(&new, FRAGMENT_SAFE));
+                1
+            }
+            UrlComponent::Host | UrlComponent::Fallback => 0,
+        }
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.prefix)?;
+        if let Some(info) = &self.userinfo {
+            write!(f, "{}@", info)?;
+        }
+        write!(f, "{}{}{}", self.host, self.port, self.segments.join("/"))?;
+        if let Some(query) = &self.query {
+            write!(f, "?")?;
+            for (i, (name, value)) in query.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "&")?;
+                }
+                write!(f, "{}", name)?;
+                if let Some(value) = value {
+                    write!(f, "={}", value)?;
+                }
+            }
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(f, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(String, Option<String>)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    query
+        .split('&')
+        .map(|param| match param.split_once('=') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (param.to_string(), None),
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes and, in query strings, `+` as a space. The decoded
+/// bytes need not be UTF-8.
+fn percent_decode(text: &str, plus_as_space: bool) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) if plus_as_space => out.push(b' '),
+            (b, _) => out.push(b),
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Encode every byte but unreserved characters and those in `safe`.
+fn percent_encode(bytes: &[u8], safe: &str) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    for &b in bytes {
+        if b.is_ascii_alphanumeric() || b"-._~".contains(&b) || safe.as_bytes().contains(&b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(url: &str, component: UrlComponent, new: Option<&str>) -> (String, usize) {
+        let mut parsed = Url::parse(url).unwrap();
+        let count = parsed.apply(&component, &mut |_| new.map(|n| n.as_bytes().to_vec()));
+        (parsed.to_string(), count)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for url in [
+            "https://user:pw@example.com:8080/a/b%20c/?x=1&y&z=a+b#frag",
+            "http://[::1]:80",
+            "//cdn.example.com/x.js",
+            "/callback?code=abc",
+            "https://example.com?",
+        ] {
+            assert_eq!(Url::parse(url).unwrap().to_string(), url);
+        }
+    }
+
+    #[test]
+    fn test_not_urls() {
+        for text in ["", "john@example.com", "example.com/x", "https://", "http://h:x/", "/a b"] {
+            assert!(Url::parse(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_params_decoded_and_encoded() {
+        let mut url = Url::parse("https://a.com/?e%6dail=jo+hn%40x.com&token=t&email=b").unwrap();
+        let mut seen = Vec::new();
+        let count = url.apply(&UrlComponent::Param("email".to_string()), &mut |v| {
+            seen.push(String::from_utf8_lossy(v).into_owned());
+            Some("é x&y".as_bytes().to_vec())
+        });
+        assert_eq!(count, 2);
+        assert_eq!(seen, ["jo hn@x.com", "b"]);
+        assert_eq!(
+            url.to_string(),
+            "https://a.com/?e%6dail=%C3%A9%20x%26y&token=t&email=%C3%A9%20x%26y"
+        );
+
+        let (out, count) = apply("/x?token=t&a=1&token=u", UrlComponent::Param("token".to_string()), None);
+        assert_eq!(count, 2);
+        assert_eq!(out, "/x?a=1");
+        let (out, _) = apply("/x?a=1&b", UrlComponent::Param("*".to_string()), Some(""));
+        assert_eq!(out, "/x?a=&b=");
+    }
+
+    #[test]
+    fn test_non_utf8_bytes_kept() {
+        let mut url = Url::parse("/x?name=Ren%E9&b=%FF").unwrap();
+        let mut seen = Vec::new();
+        url.apply(&UrlComponent::Param("*".to_string()), &mut |v| {
+            seen.push(v.to_vec());
+            Some(v.to_vec())
+        });
+        assert_eq!(seen, [b"Ren\xe9".to_vec(), b"\xff".to_vec()]);
+        assert_eq!(url.to_string(), "/x?name=Ren%E9&b=%FF");
+    }
+
+    #[test]
+    fn test_components() {
+        let url = "https://bob:pw@h.example.com/users/42/profile#sec";
+        let (out, _) = apply(url, UrlComponent::Host, Some("example.com"));
+        assert_eq!(out, "https://bob:pw@example.com/users/42/profile#sec");
+        let (out, _) = apply(url, UrlComponent::UserInfo, None);
+        assert_eq!(out, "https://h.example.com/users/42/profile#sec");
+        let (out, _) = apply(url, UrlComponent::Segment(2), Some("7"));
+        assert_eq!(out, "https://bob:pw@h.example.com/users/7/profile#sec");
+        let (out, count) = apply(url, UrlComponent::Segment(4), Some("7"));
+        assert_eq!((out.as_str(), count), (url, 0));
+        let (out, _) = apply(url, UrlComponent::Fragment, None);
+        assert_eq!(out, "https://bob:pw@h.example.com/users/42/profile");
+    }
+}
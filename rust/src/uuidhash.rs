@@ -0,0 +1,167 @@
+use crate::anonymize::{hmac_digest, mysql_backslash_escape_into, mysql_unescape_bytes, QuoteMode};
+
+/// How a UUID is written in the dump
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UuidForm {
+    /// `123e4567-e89b-...`, or 32 hex digits without hyphens, possibly
+    /// uppercase or between braces
+    Text { hyphens: bool, upper: bool, braces: bool },
+    /// `0x123E4567E89B...` (`BINARY(16)` dumped with `--hex-blob`)
+    Hex,
+    /// `_binary '...'` or `'...'` holding the 16 bytes (`BINARY(16)`)
+    Binary { prefix: bool },
+}
+
+fn parse_hex(hex: &[u8]) -> Option<[u8; 16]> {
+    if hex.len() != 32 {
+        return None;
+    }
+    let mut bytes = [0u8; 16];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+fn parse_uuid(token: &[u8]) -> Option<([u8; 16], UuidForm)> {
+    if let Some(hex) = token.strip_prefix(b"0x").or_else(|| token.strip_prefix(b"0X")) {
+        return Some((parse_hex(hex)?, UuidForm::Hex));
+    }
+
+    let (quoted, prefix) = match token.strip_prefix(b"_binary ") {
+        Some(rest) => (rest, true),
+        None => (token, false),
+    };
+    if quoted.len() >= 2 && quoted[0] == b'\'' && quoted[quoted.len() - 1] == b'\'' {
+        let (bytes, _) = mysql_unescape_bytes(&quoted[1..quoted.len() - 1]);
+        return Some((bytes.try_into().ok()?, UuidForm::Binary { prefix }));
+    }
+
+    let (text, braces) = match token.strip_prefix(b"{").and_then(|t| t.strip_suffix(b"}")) {
+        Some(inner) => (inner, true),
+        None => (token, false),
+    };
+    let hyphens = text.len() == 36;
+    let hex: Vec<u8> = if hyphens {
+        if [8, 13, 18, 23].iter().any(|&i| text[i] != b'-') {
+            return None;
+        }
+        text.iter().copied().filter(|&b| b != b'-').collect()
+    } else {
+        text.to_vec()
+    };
+    let bytes = parse_hex(&hex)?;
+    let upper = hex.iter().any(u8::is_ascii_uppercase) && !hex.iter().any(u8::is_ascii_lowercase);
+    Some((bytes, UuidForm::Text { hyphens, upper, braces }))
+}
+
+/// Replace a UUID by a version 4 UUID derived from the HMAC of its 16 bytes,
+/// written in the same form: hyphenated or plain hex text (keeping the case
+/// and braces), `0x` hex literal or `_binary` string. The nil UUID is kept.
+/// Returns `None`, writing nothing, for values that are not UUIDs.
+pub fn uuidhash_into(out: &mut Vec<u8>, token: &[u8], secret: &[u8]) -> Option<QuoteMode> {
+    let (bytes, form) = parse_uuid(token)?;
+    let mut uuid = bytes;
+    if bytes != [0; 16] {
+        uuid.copy_from_slice(&hmac_digest(&bytes, secret)[..16]);
+        uuid[6] = (uuid[6] & 0x0f) | 0x40;
+        uuid[8] = (uuid[8] & 0x3f) | 0x80;
+    }
+
+    match form {
+        UuidForm::Text { hyphens, upper, braces } => {
+            if braces {
+                out.push(b'{');
+            }
+            for (i, b) in uuid.iter().enumerate() {
+                if hyphens && matches!(i, 4 | 6 | 8 | 10) {
+                    out.push(b'-');
+                }
+                let hex = if upper { format!("{:02X}", b) } else { format!("{:02x}", b) };
+                out.extend_from_slice(hex.as_bytes());
+            }
+            if braces {
+                out.push(b'}');
+            }
+            Some(QuoteMode::AsInput)
+        }
+        UuidForm::Hex => {
+            out.extend_from_slice(b"0x");
+            for b in uuid {
+                out.extend_from_slice(format!("{:02X}", b).as_bytes());
+            }
+            Some(QuoteMode::ForceFalse)
+        }
+        UuidForm::Binary { prefix } => {
+            if prefix {
+                out.extend_from_slice(b"_binary ");
+            }
+            out.push(b'\'');
+            mysql_backslash_escape_into(out, &uuid);
+            out.push(b'\'');
+            Some(QuoteMode::ForceFalse)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uuidhash(token: &[u8]) -> Option<(Vec<u8>, QuoteMode)> {
+        let mut out = Vec::new();
+        let quoting = uuidhash_into(&mut out, token, b"secret")?;
+        Some((out, quoting))
+    }
+
+    fn text(token: &str) -> String {
+        String::from_utf8(uuidhash(token.as_bytes()).unwrap().0).unwrap()
+    }
+
+    #[test]
+    fn test_text_forms() {
+        let uuid = text("123e4567-e89b-12d3-a456-426614174000");
+        assert_eq!(uuid.len(), 36);
+        assert_ne!(uuid, "123e4567-e89b-12d3-a456-426614174000");
+        assert_eq!(&uuid[14..15], "4");
+        assert!("89ab".contains(&uuid[19..20]));
+        assert!(!uuid.bytes().any(|b| b.is_ascii_uppercase()));
+
+        // Same UUID in other forms, same result in that form
+        assert_eq!(text("123E4567-E89B-12D3-A456-426614174000"), uuid.to_uppercase());
+        assert_eq!(text("123e4567e89b12d3a456426614174000"), uuid.replace('-', ""));
+        assert_eq!(text("{123e4567-e89b-12d3-a456-426614174000}"), format!("{{{}}}", uuid));
+        assert_eq!(text("00000000-0000-0000-0000-000000000000"), "00000000-0000-0000-0000-000000000000");
+    }
+
+    #[test]
+    fn test_binary_forms() {
+        let uuid = text("123e4567-e89b-12d3-a456-426614174000").replace('-', "").to_uppercase();
+        let (hex, quoting) = uuidhash(b"0x123E4567E89B12D3A456426614174000").unwrap();
+        assert_eq!(quoting, QuoteMode::ForceFalse);
+        assert_eq!(String::from_utf8(hex).unwrap(), format!("0x{}", uuid));
+
+        // NUL, quotes, backslash, newline, return and ^Z are escaped by mysqldump
+        let raw = parse_hex(b"00275C0A0D1A22E89B12D3A456426614").unwrap();
+        let mut token = b"_binary '".to_vec();
+        mysql_backslash_escape_into(&mut token, &raw);
+        token.push(b'\'');
+        assert_eq!(&token, b"_binary '\\0\\'\\\\\\n\\r\\Z\\\"\xe8\x9b\x12\xd3\xa4VBf\x14'");
+        let (binary, quoting) = uuidhash(&token).unwrap();
+        assert_eq!(quoting, QuoteMode::ForceFalse);
+        let (bytes, form) = parse_uuid(&binary).unwrap();
+        assert_eq!(form, UuidForm::Binary { prefix: true });
+        let (expected, _) = uuidhash(b"0x00275C0A0D1A22E89B12D3A456426614").unwrap();
+        assert_eq!(parse_uuid(&expected).unwrap().0, bytes);
+
+        let (quoted, _) = uuidhash(b"'\\0\\'\\\\\\n\\r\\Z\\\"\xe8\x9b\x12\xd3\xa4VBf\x14'").unwrap();
+        assert_eq!(parse_uuid(&quoted).unwrap(), (bytes, UuidForm::Binary { prefix: false }));
+    }
+
+    #[test]
+    fn test_not_uuids() {
+        for token in [&b"not a uuid"[..], b"123e4567-e89b-12d3-a456-42661417400", b"0x1234", b"'short'", b"123e4567+e89b-12d3-a456-426614174000"] {
+            assert!(uuidhash(token).is_none(), "{}", String::from_utf8_lossy(token));
+        }
+    }
+}
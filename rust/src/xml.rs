@@ -0,0 +1,732 @@
+use crate::anonymize::{mysql_backslash_escape, mysql_unescape};
+use crate::config::{AnonBase, AnonType};
+
+/// What a path step selects.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeTest {
+    /// Child elements with this name, or any element for `*`
+    Element(Option<String>),
+    /// Attributes with this name, or any attribute for `@*`
+    Attribute(Option<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub test: NodeTest,
+    /// Set for `//`: the step applies at any depth.
+    pub descendant: bool,
+    /// `[n]`: only the n-th match (1-based) among the children of each element
+    pub position: Option<usize>,
+}
+
+/// A compiled XML path, a subset of XPath.
+///
+/// Supported syntax: absolute paths made of `/name`, `//name`, `*`, `[n]`
+/// (1-based position), a final `@name` or `@*` for attributes and a final
+/// `text()`, e.g. `/user/email`, `//phone`, `/users/user[2]/@id`. A name
+/// without a prefix also matches prefixed elements (`//Body` matches
+/// `soap:Body`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlPath {
+    pub steps: Vec<Step>,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':')
+}
+
+impl XmlPath {
+    pub fn parse(path: &str) -> Result<XmlPath, String> {
+        if !path.starts_with('/') {
+            return Err("an XML path must start with '/'".to_string());
+        }
+        let mut steps = Vec::new();
+        let mut rest = path;
+        while !rest.is_empty() {
+            let descendant = rest.starts_with("//");
+            rest = rest
+                .strip_prefix(if descendant { "//" } else { "/" })
+                .ok_or_else(|| format!("expected '/' before '{}'", rest))?;
+            if steps
+                .last()
+                .is_some_and(|s: &Step| matches!(s.test, NodeTest::Attribute(_)))
+            {
+                return Err("an attribute must be the last step".to_string());
+            }
+
+            let end = rest.find('/').unwrap_or(rest.len());
+            let (step, after) = rest.split_at(end);
+            rest = after;
+            if step == "text()" {
+                if !rest.is_empty() || descendant || steps.is_empty() {
+                    return Err("text() must follow an element step".to_string());
+                }
+                break;
+            }
+
+            let (test, step): (fn(Option<String>) -> NodeTest, &str) = match step.strip_prefix('@') {
+                Some(name) => (NodeTest::Attribute, name),
+                None => (NodeTest::Element, step),
+            };
+            let (name, position) = match step.split_once('[') {
+                Some((name, predicate)) => {
+                    let digits = predicate
+                        .strip_suffix(']')
+                        .ok_or_else(|| format!("expected ']' in '{}'", step))?;
+                    match digits.parse::<usize>() {
+                        Ok(n) if n > 0 => (name, Some(n)),
+                        _ => return Err(format!("invalid position '{}'", digits)),
+                    }
+                }
+                None => (step, None),
+            };
+            let name = match name {
+                "*" => None,
+                "" => return Err("missing name in path".to_string()),
+                _ if name.chars().all(is_name_char) => Some(name.to_string()),
+                _ => return Err(format!("invalid name '{}'", name)),
+            };
+            steps.push(Step {
+                test: test(name),
+                descendant,
+                position,
+            });
+        }
+        if steps.is_empty() {
+            return Err("empty path".to_string());
+        }
+        Ok(XmlPath { steps })
+    }
+}
+
+/// Check a qualified name against the name of a path step.
+fn name_matches(pattern: &Option<String>, name: &str) -> bool {
+    match pattern {
+        None => true,
+        Some(p) if p.contains(':') => p == name,
+        Some(p) => name.rsplit(':').next() == Some(p.as_str()),
+    }
+}
+
+/// An element of the parsed document. Offsets are in the unescaped text.
+#[derive(Debug)]
+struct Element {
+    name: String,
+    /// `<` of the start tag
+    start: usize,
+    /// After the end tag (or `/>`)
+    end: usize,
+    attrs: Vec<Attr>,
+    /// Between the start and end tags, `None` for `<empty/>`
+    content: Option<(usize, usize)>,
+    children: Vec<Element>,
+    /// The content is a single CDATA section
+    cdata: bool,
+}
+
+#[derive(Debug)]
+struct Attr {
+    name: String,
+    /// Whitespace before the attribute name
+    start: usize,
+    value_start: usize,
+    value_end: usize,
+    quote: char,
+}
+
+struct XmlParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    fn error<T>(&self, msg: &str) -> Result<T, String> {
+        Err(format!("{} at offset {}", msg, self.pos))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skip past `end`.
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(i) => {
+                self.pos += i + end.len();
+                Ok(())
+            }
+            None => self.error(&format!("missing '{}'", end)),
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let len = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+        if len == 0 {
+            return self.error("expected a name");
+        }
+        self.pos += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), String> {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            Ok(())
+        } else {
+            self.error(&format!("expected '{}'", s))
+        }
+    }
+
+    /// Skip comments, processing instructions and DOCTYPE. Returns false if
+    /// there was nothing to skip.
+    fn skip_misc(&mut self) -> Result<bool, String> {
+        let rest = self.rest();
+        if rest.starts_with("<!--") {
+            self.skip_past("-->")?;
+        } else if rest.starts_with("<?") {
+            self.skip_past("?>")?;
+        } else if rest.starts_with("<!DOCTYPE") {
+            // Skip the internal subset, if any
+            let end = rest.find('>').unwrap_or(rest.len());
+            if rest[..end].contains('[') {
+                self.skip_past("]")?;
+            }
+            self.skip_past(">")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn document(&mut self) -> Result<Element, String> {
+        let mut root = None;
+        loop {
+            self.skip_whitespace();
+            if self.pos == self.text.len() {
+                break;
+            }
+            if self.skip_misc()? {
+                continue;
+            }
+            if root.is_some() || !self.rest().starts_with('<') {
+                return self.error("content after the root element");
+            }
+            root = Some(self.element()?);
+        }
+        let Some(root) = root else {
+            return self.error("no root element");
+        };
+        // The document node, parent of the root element
+        Ok(Element {
+            name: String::new(),
+            start: 0,
+            end: self.text.len(),
+            attrs: Vec::new(),
+            content: None,
+            children: vec![root],
+            cdata: false,
+        })
+    }
+
+    fn element(&mut self) -> Result<Element, String> {
+        let start = self.pos;
+        self.expect("<")?;
+        let name = self.name()?;
+        let mut attrs = Vec::new();
+        loop {
+            let attr_start = self.pos;
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(Element {
+                    name,
+                    start,
+                    end: self.pos,
+                    attrs,
+                    content: None,
+                    children: Vec::new(),
+                    cdata: false,
+                });
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            if attr_start == self.pos {
+                return self.error("expected whitespace");
+            }
+            let attr_name = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(q @ ('"' | '\'')) => q,
+                _ => return self.error("expected a quoted attribute value"),
+            };
+            self.pos += 1;
+            let value_start = self.pos;
+            let len = self.rest().find(quote);
+            match len {
+                Some(len) if !self.rest()[..len].contains('<') => self.pos += len + 1,
+                _ => return self.error("unterminated attribute value"),
+            }
+            attrs.push(Attr {
+                name: attr_name,
+                start: attr_start,
+                value_start,
+                value_end: self.pos - 1,
+                quote,
+            });
+        }
+
+        let content_start = self.pos;
+        let mut children = Vec::new();
+        let mut sections = 0;
+        let mut cdata_sections = 0;
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                let content_end = self.pos;
+                self.pos += 2;
+                if self.name()? != name {
+                    return self.error(&format!("mismatched end tag for '{}'", name));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(Element {
+                    name,
+                    start,
+                    end: self.pos,
+                    attrs,
+                    content: Some((content_start, content_end)),
+                    children,
+                    cdata: sections == 1 && cdata_sections == 1,
+                });
+            }
+            if rest.starts_with("<![CDATA[") {
+                self.skip_past("]]>")?;
+                sections += 1;
+                cdata_sections += 1;
+            } else if self.skip_misc()? {
+                sections += 1;
+            } else if rest.starts_with('<') {
+                children.push(self.element()?);
+                sections += 1;
+            } else if rest.is_empty() {
+                return self.error(&format!("missing end tag for '{}'", name));
+            } else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                self.pos += len;
+                sections += 1;
+            }
+        }
+    }
+}
+
+/// Decode the character data of an element or attribute: entities and
+/// CDATA sections. Comments and processing instructions are dropped.
+fn decode_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").unwrap_or(after.len());
+            out.push_str(&after[..end]);
+            rest = after.get(end + 3..).unwrap_or("");
+        } else if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+        } else if let Some(after) = rest.strip_prefix("<?") {
+            rest = after.find("?>").map_or("", |end| &after[end + 2..]);
+        } else if c == '&' {
+            let decoded = rest.find(';').and_then(|end| {
+                let ch = match &rest[1..end] {
+                    "lt" => '<',
+                    "gt" => '>',
+                    "amp" => '&',
+                    "quot" => '"',
+                    "apos" => '\'',
+                    e => match e.strip_prefix("#x").or_else(|| e.strip_prefix("#X")) {
+                        Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?,
+                        None => char::from_u32(e.strip_prefix('#')?.parse().ok()?)?,
+                    },
+                };
+                Some((ch, end + 1))
+            });
+            match decoded {
+                Some((ch, len)) => {
+                    out.push(ch);
+                    rest = &rest[len..];
+                }
+                // Unknown entity: keep it as it is
+                None => {
+                    out.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// Escape character data; `quote` is the delimiter of an attribute value.
+fn escape_text(text: &str, quote: Option<char>) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' if quote == Some('"') => out.push_str("&quot;"),
+            '\'' if quote == Some('\'') => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Node matched by a path.
+enum Target<'a> {
+    Element(&'a Element),
+    Attribute(&'a Element, usize),
+}
+
+impl Target<'_> {
+    fn start(&self) -> usize {
+        match self {
+            Target::Element(e) => e.start,
+            Target::Attribute(e, i) => e.attrs[*i].start,
+        }
+    }
+}
+
+/// Elements in `elements` and, for `descendant`, all their descendants.
+fn with_descendants<'a>(elements: &[&'a Element], descendant: bool) -> Vec<&'a Element> {
+    let mut out = Vec::new();
+    fn walk<'a>(e: &'a Element, out: &mut Vec<&'a Element>) {
+        out.push(e);
+        for child in &e.children {
+            walk(child, out);
+        }
+    }
+    for e in elements {
+        if descendant {
+            walk(e, &mut out);
+        } else {
+            out.push(e);
+        }
+    }
+    // The document node starts where the root element does: keep it first
+    out.sort_by_key(|e| (e.start, !e.name.is_empty()));
+    out.dedup_by(|a, b| std::ptr::eq(*a, *b));
+    out
+}
+
+fn select<'a>(document: &'a Element, path: &XmlPath) -> Vec<Target<'a>> {
+    let mut context = vec![document];
+    for step in &path.steps {
+        let parents = with_descendants(&context, step.descendant);
+        let mut targets = Vec::new();
+        for parent in parents {
+            let matched: Vec<Target> = match &step.test {
+                NodeTest::Element(name) => parent
+                    .children
+                    .iter()
+                    .filter(|e| name_matches(name, &e.name))
+                    .map(Target::Element)
+                    .collect(),
+                NodeTest::Attribute(name) => (0..parent.attrs.len())
+                    .filter(|&i| name_matches(name, &parent.attrs[i].name))
+                    .map(|i| Target::Attribute(parent, i))
+                    .collect(),
+            };
+            match step.position {
+                Some(n) => targets.extend(matched.into_iter().nth(n - 1)),
+                None => targets.extend(matched),
+            }
+        }
+        if matches!(step.test, NodeTest::Attribute(_)) {
+            return targets;
+        }
+        context = targets
+            .into_iter()
+            .filter_map(|t| match t {
+                Target::Element(e) => Some(e),
+                Target::Attribute(..) => None,
+            })
+            .collect();
+        context.sort_by_key(|e| (e.start, !e.name.is_empty()));
+        context.dedup_by(|a, b| std::ptr::eq(*a, *b));
+    }
+    context.into_iter().map(Target::Element).collect()
+}
+
+/// Replacement of `xml[start..end]` by `with` (unescaped XML text).
+struct Edit {
+    start: usize,
+    end: usize,
+    with: String,
+}
+
+/// An XML document anonymized in place.
+///
+/// The document is kept as its SQL-escaped text, as found in the dump. Rules
+/// only rewrite the text nodes and attributes they target: markup,
+/// whitespace, comments and entities of everything else are left
+/// byte-for-byte identical.
+pub struct XmlDocument {
+    text: String,
+}
+
+impl XmlDocument {
+    /// Wrap the SQL-escaped text of an XML value (without its quotes).
+    /// Returns an error if it is not a well-formed XML document.
+    pub fn parse(escaped: &str) -> Result<XmlDocument, String> {
+        let doc = XmlDocument {
+            text: escaped.to_string(),
+        };
+        let (xml, _) = mysql_unescape(&doc.text);
+        XmlParser { text: &xml, pos: 0 }.document()?;
+        Ok(doc)
+    }
+
+    /// Current escaped text of the document.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Apply one rule to the elements and attributes matched by `path`, with
+    /// `anonymize` computing the anonymized text of a value. Only elements
+    /// holding text (no child elements) are anonymized; `remove` drops
+    /// elements and attributes. Returns the number of nodes anonymized or
+    /// removed.
+    pub fn anonymize_with(
+        &mut self,
+        path: &XmlPath,
+        config: &AnonBase,
+        anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+    ) -> usize {
+        let (xml, offsets) = mysql_unescape(&self.text);
+        let Ok(document) = (XmlParser { text: &xml, pos: 0 }).document() else {
+            return 0;
+        };
+        let mut targets = select(&document, path);
+        targets.sort_by_key(Target::start);
+
+        let mut edits = Vec::new();
+        for target in targets {
+            let edit = match target {
+                Target::Element(e) if config.anon_type == AnonType::Remove => {
+                    if e.name.is_empty() {
+                        continue;
+                    }
+                    Edit {
+                        start: e.start,
+                        end: e.end,
+                        with: String::new(),
+                    }
+                }
+                Target::Attribute(e, i) if config.anon_type == AnonType::Remove => {
+                    let attr = &e.attrs[i];
+                    Edit {
+                        start: attr.start,
+                        end: attr.value_end + 1,
+                        with: String::new(),
+                    }
+                }
+                Target::Element(e) => {
+                    let Some((start, end)) = e.content else {
+                        continue;
+                    };
+                    if !e.children.is_empty() || start == end {
+                        continue;
+                    }
+                    let value = decode_text(&xml[start..end]);
+                    let Some(new) = anonymize_text(&value, config, anonymize) else {
+                        continue;
+                    };
+                    let with = if e.cdata && !new.contains("]]>") {
+                        format!("<![CDATA[{}]]>", new)
+                    } else {
+                        escape_text(&new, None)
+                    };
+                    Edit { start, end, with }
+                }
+                Target::Attribute(e, i) => {
+                    let attr = &e.attrs[i];
+                    let value = decode_text(&xml[attr.value_start..attr.value_end]);
+                    let Some(new) = anonymize_text(&value, config, anonymize) else {
+                        continue;
+                    };
+                    Edit {
+                        start: attr.value_start,
+                        end: attr.value_end,
+                        with: escape_text(&new, Some(attr.quote)),
+                    }
+                }
+            };
+            edits.push(edit);
+        }
+
+        let count = edits.len();
+        self.apply(edits, &offsets);
+        count
+    }
+
+    /// Splice the edits into the escaped text. An edit inside a range already
+    /// edited (e.g. an attribute of a removed element) is dropped.
+    fn apply(&mut self, edits: Vec<Edit>, offsets: &[usize]) {
+        if edits.is_empty() {
+            return;
+        }
+        let mut out = String::with_capacity(self.text.len());
+        let mut copied = 0;
+        for edit in edits {
+            let start = offsets[edit.start];
+            let end = offsets[edit.end];
+            if start < copied {
+                continue;
+            }
+            out.push_str(&self.text[copied..start]);
+            out.push_str(&mysql_backslash_escape(&edit.with));
+            copied = end;
+        }
+        out.push_str(&self.text[copied..]);
+        self.text = out;
+    }
+}
+
+fn anonymize_text(
+    value: &str,
+    config: &AnonBase,
+    anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+) -> Option<String> {
+    match config.anon_type {
+        AnonType::Fixed | AnonType::FixedQuoted | AnonType::FixedUnquoted => {
+            Some(config.fixed_value.clone())
+        }
+        _ => anonymize(value.as_bytes()).map(|v| String::from_utf8_lossy(&v).into_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anonymize(escaped: &str, path: &str, anon_type: AnonType) -> (String, usize) {
+        let mut doc = XmlDocument::parse(escaped).unwrap();
+        let infos = AnonBase {
+            anon_type,
+            ..Default::default()
+        };
+        let path = XmlPath::parse(path).unwrap();
+        let count = doc.anonymize_with(&path, &infos, &mut |token| {
+            Some(format!("<{}>", String::from_utf8_lossy(token)).into_bytes())
+        });
+        (doc.as_str().to_string(), count)
+    }
+
+    #[test]
+    fn test_parse_paths() {
+        let path = XmlPath::parse("//user[2]/@id").unwrap();
+        assert_eq!(
+            path.steps,
+            vec![
+                Step {
+                    test: NodeTest::Element(Some("user".to_string())),
+                    descendant: true,
+                    position: Some(2),
+                },
+                Step {
+                    test: NodeTest::Attribute(Some("id".to_string())),
+                    descendant: false,
+                    position: None,
+                },
+            ]
+        );
+        assert_eq!(
+            XmlPath::parse("/a/*/text()").unwrap().steps[1].test,
+            NodeTest::Element(None)
+        );
+        for invalid in ["user", "/", "/a/@b/c", "/a[0]", "/a[x]", "/a b", "/text()", "/a//text()"] {
+            assert!(XmlPath::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        for invalid in ["", "text", "<a>", "<a></b>", "<a><b></a></b>", "<a/><b/>", "<a x=1/>"] {
+            assert!(XmlDocument::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_untouched_markup_is_kept() {
+        let input = "<?xml version=\\\"1.0\\\"?>\\n<!-- c -->\\n<user id=\\'7\\'>\\n  <email>a@b.c</email>\\n  <name>J &amp; J</name>\\n</user>";
+        let (out, count) = anonymize(input, "/user/email", AnonType::TextHash);
+        assert_eq!(count, 1);
+        assert_eq!(out, input.replace("a@b.c", "&lt;a@b.c&gt;"));
+
+        let (out, _) = anonymize(input, "/user/name", AnonType::TextHash);
+        assert_eq!(out, input.replace("J &amp; J", "&lt;J &amp; J&gt;"));
+    }
+
+    #[test]
+    fn test_attributes_and_descendants() {
+        let input = r#"<s:Envelope><s:Body><a phone="1" x='2'/><b><phone>3</phone><phone>4</phone></b></s:Body></s:Envelope>"#;
+        let (out, count) = anonymize(input, "//phone", AnonType::TextHash);
+        assert_eq!(count, 2);
+        assert!(out.contains("<phone>&lt;3&gt;</phone><phone>&lt;4&gt;</phone>"));
+
+        let (out, count) = anonymize(input, "//@*", AnonType::TextHash);
+        assert_eq!(count, 2);
+        assert!(out.contains(r#"<a phone="&lt;1&gt;" x='&lt;2&gt;'/>"#));
+
+        let (out, _) = anonymize(input, "/Envelope/Body/b/phone[2]/text()", AnonType::TextHash);
+        assert!(out.contains("<phone>3</phone><phone>&lt;4&gt;</phone>"));
+    }
+
+    #[test]
+    fn test_cdata_kept() {
+        let (out, _) = anonymize("<a><![CDATA[x & y]]></a>", "/a", AnonType::TextHash);
+        assert_eq!(out, "<a><![CDATA[<x & y>]]></a>");
+    }
+
+    #[test]
+    fn test_containers_and_empty_elements_left_alone() {
+        let input = "<a><b><c>1</c></b><d/><e></e></a>";
+        let (out, count) = anonymize(input, "/a/*", AnonType::TextHash);
+        assert_eq!(count, 0);
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn test_remove() {
+        let input = "<a k=\\\"v\\\" t=\\\"x\\\"><token>t</token><b>1</b></a>";
+        let (out, count) = anonymize(input, "//token", AnonType::Remove);
+        assert_eq!(count, 1);
+        assert_eq!(out, "<a k=\\\"v\\\" t=\\\"x\\\"><b>1</b></a>");
+        let (out, _) = anonymize(input, "/a/@t", AnonType::Remove);
+        assert_eq!(out, "<a k=\\\"v\\\"><token>t</token><b>1</b></a>");
+    }
+
+    #[test]
+    fn test_sql_escaped_replacement() {
+        let mut doc = XmlDocument::parse("<a>x</a>").unwrap();
+        let infos = AnonBase {
+            anon_type: AnonType::Fixed,
+            fixed_value: "l'\"a\"\n".to_string(),
+            ..Default::default()
+        };
+        doc.anonymize_with(&XmlPath::parse("/a").unwrap(), &infos, &mut |_| None);
+        assert_eq!(doc.as_str(), "<a>l\\'\\\"a\\\"\\n</a>");
+    }
+}
@@ -0,0 +1,967 @@
+use crate::anonymize::{mysql_backslash_escape, mysql_unescape};
+use crate::config::{AnonBase, AnonType};
+use crate::json::{path_locations, JsonPath, PathNode};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Style {
+    Plain,
+    SingleQuoted,
+    DoubleQuoted,
+    /// `|` or `>` block scalar
+    Block,
+}
+
+#[derive(Debug)]
+enum NodeKind {
+    Scalar { style: Style, value: String },
+    Map(Vec<Child>),
+    Seq(Vec<Child>),
+    /// Flow collections and aliases, kept as they are
+    Opaque,
+}
+
+/// A node of the parsed document. Offsets are in the unescaped text.
+#[derive(Debug)]
+struct YamlNode {
+    kind: NodeKind,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug)]
+struct Child {
+    /// Mapping key, `None` for sequence items
+    key: Option<String>,
+    /// Start of the key, or of the `-` of a sequence item
+    key_start: usize,
+    node: YamlNode,
+}
+
+impl YamlNode {
+    fn children(&self) -> &[Child] {
+        match &self.kind {
+            NodeKind::Map(children) | NodeKind::Seq(children) => children,
+            _ => &[],
+        }
+    }
+
+    fn at(&self, location: &[usize]) -> &YamlNode {
+        location
+            .iter()
+            .fold(self, |node, &i| &node.children()[i].node)
+    }
+}
+
+impl PathNode for YamlNode {
+    fn is_map(&self) -> bool {
+        matches!(self.kind, NodeKind::Map(_))
+    }
+
+    fn is_list(&self) -> bool {
+        matches!(self.kind, NodeKind::Seq(_))
+    }
+
+    fn child_count(&self) -> usize {
+        self.children().len()
+    }
+
+    /// Ruby symbol keys (`:name:`) are matched by their name.
+    fn child_key(&self, i: usize) -> Option<&str> {
+        let key = self.children()[i].key.as_deref()?;
+        Some(key.strip_prefix(':').filter(|k| !k.is_empty()).unwrap_or(key))
+    }
+
+    fn child(&self, i: usize) -> &YamlNode {
+        &self.children()[i].node
+    }
+}
+
+/// Where a node is found, which decides what may follow.
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    Root,
+    MapValue,
+    SeqItem,
+}
+
+/// Parser for block YAML as written by Ruby and Python: block mappings and
+/// sequences, plain, quoted and block scalars, tags, anchors and comments.
+/// Flow collections and aliases are read as opaque values.
+struct YamlParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> YamlParser<'a> {
+    fn error<T>(&self, msg: &str) -> Result<T, String> {
+        Err(format!("{} at offset {}", msg, self.pos))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn line_end(&self, pos: usize) -> usize {
+        self.text[pos..].find('\n').map_or(self.text.len(), |i| pos + i)
+    }
+
+    fn column(&self, pos: usize) -> usize {
+        pos - self.text[..pos].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn indent(&self, line: usize) -> usize {
+        self.text[line..].len() - self.text[line..].trim_start_matches(' ').len()
+    }
+
+    fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    /// True if only whitespace or a comment follow `pos` on its line.
+    fn rest_of_line_blank(&self, pos: usize) -> bool {
+        let line = &self.text[pos..self.line_end(pos)];
+        let content = line.trim_start_matches([' ', '\t']);
+        // A comment starts after whitespace or at the start of a line
+        content.is_empty()
+            || (content.starts_with('#')
+                && (content.len() < line.len()
+                    || pos == 0
+                    || matches!(self.text.as_bytes()[pos - 1], b' ' | b'\t' | b'\n')))
+    }
+
+    /// Start of the first line from the line after `pos` that is neither
+    /// blank nor a comment.
+    fn next_content_line(&self, pos: usize) -> Option<usize> {
+        let mut line = self.line_end(pos) + 1;
+        while line <= self.text.len() {
+            let content = self.text[line..self.line_end(line)].trim_start_matches([' ', '\t']);
+            if !content.is_empty() && !content.starts_with('#') {
+                return Some(line);
+            }
+            line = self.line_end(line) + 1;
+        }
+        None
+    }
+
+    fn is_dash(&self, pos: usize) -> bool {
+        let rest = &self.text[pos..];
+        rest.starts_with('-')
+            && matches!(rest.as_bytes().get(1), None | Some(b' ' | b'\n' | b'\t'))
+    }
+
+    fn is_document_marker(&self, line: usize) -> bool {
+        let rest = &self.text[line..];
+        (rest.starts_with("---") || rest.starts_with("..."))
+            && matches!(rest.as_bytes().get(3), None | Some(b' ' | b'\n' | b'\t'))
+    }
+
+    fn document(&mut self) -> Result<YamlNode, String> {
+        // First content line, after directives
+        let mut line = 0;
+        loop {
+            let content = self.text[line..self.line_end(line)].trim_start_matches([' ', '\t']);
+            if !content.is_empty() && !content.starts_with('#') && !content.starts_with('%') {
+                break;
+            }
+            if self.line_end(line) == self.text.len() {
+                return self.error("empty document");
+            }
+            line = self.line_end(line) + 1;
+        }
+
+        self.pos = line;
+        if self.text[line..].starts_with("---") && self.is_document_marker(line) {
+            self.pos += 3;
+        }
+        let root = self.node(-1, Context::Root)?;
+        self.end_of_node()?;
+
+        if let Some(line) = self.next_content_line(self.pos) {
+            let end = self.line_end(line);
+            if !(self.text[line..end].trim_end() == "..."
+                && self.next_content_line(line).is_none())
+            {
+                self.pos = line;
+                return self.error("unexpected content after the document");
+            }
+        }
+        Ok(root)
+    }
+
+    /// Check that nothing but a comment follows a node on its line.
+    fn end_of_node(&self) -> Result<(), String> {
+        if self.rest_of_line_blank(self.pos) {
+            Ok(())
+        } else {
+            self.error("unexpected content")
+        }
+    }
+
+    /// Parse the node at `pos` (after `---`, `key:` or `-`). Lines of a
+    /// block value must be indented more than `parent_indent`.
+    fn node(&mut self, parent_indent: isize, context: Context) -> Result<YamlNode, String> {
+        self.skip_spaces();
+        // Tags and anchors
+        while self.rest().starts_with(['!', '&']) {
+            let len = self.rest().find([' ', '\n']).unwrap_or(self.rest().len());
+            self.pos += len;
+            self.skip_spaces();
+        }
+
+        if !self.rest_of_line_blank(self.pos) {
+            return self.inline_node(parent_indent, context);
+        }
+
+        // The value is on the next lines, or empty
+        let empty = YamlNode {
+            kind: NodeKind::Scalar {
+                style: Style::Plain,
+                value: String::new(),
+            },
+            start: self.pos,
+            end: self.pos,
+        };
+        let Some(line) = self.next_content_line(self.pos) else {
+            return Ok(empty);
+        };
+        let indent = self.indent(line);
+        let nested = indent as isize > parent_indent
+            || (context == Context::MapValue
+                && indent as isize == parent_indent
+                && self.is_dash(line + indent));
+        if !nested || (indent == 0 && self.is_document_marker(line)) {
+            return Ok(empty);
+        }
+        self.pos = line + indent;
+        self.inline_node(indent as isize - 1, Context::Root)
+    }
+
+    /// Parse a node starting at `pos`, on the current line.
+    fn inline_node(&mut self, parent_indent: isize, context: Context) -> Result<YamlNode, String> {
+        let start = self.pos;
+        let collections = context != Context::MapValue;
+        if collections && self.is_dash(start) {
+            return self.sequence(self.column(start));
+        }
+        if collections && self.mapping_key(start).is_some() {
+            return self.mapping(self.column(start));
+        }
+
+        let (kind, end) = match self.rest().as_bytes()[0] {
+            b'|' | b'>' => self.block_scalar(parent_indent)?,
+            b'"' => {
+                let end = self.double_quoted_end(start)?;
+                let value = decode_double_quoted(&self.text[start + 1..end - 1]);
+                (
+                    NodeKind::Scalar {
+                        style: Style::DoubleQuoted,
+                        value,
+                    },
+                    end,
+                )
+            }
+            b'\'' => {
+                let end = self.single_quoted_end(start)?;
+                let value = fold_lines(&self.text[start + 1..end - 1]).replace("''", "'");
+                (
+                    NodeKind::Scalar {
+                        style: Style::SingleQuoted,
+                        value,
+                    },
+                    end,
+                )
+            }
+            b'[' | b'{' => (NodeKind::Opaque, self.flow_end(start)?),
+            b'*' => {
+                let len = self.rest().find([' ', '\n']).unwrap_or(self.rest().len());
+                (NodeKind::Opaque, start + len)
+            }
+            _ => {
+                let end = self.plain_end(start);
+                let value = self.text[start..end].to_string();
+                (
+                    NodeKind::Scalar {
+                        style: Style::Plain,
+                        value,
+                    },
+                    end,
+                )
+            }
+        };
+        self.pos = end;
+        Ok(YamlNode { kind, start, end })
+    }
+
+    /// End of a plain scalar: end of line, comment or `: `.
+    fn plain_end(&self, start: usize) -> usize {
+        let line = &self.text[start..self.line_end(start)];
+        let bytes = line.as_bytes();
+        let mut end = line.len();
+        for i in 0..bytes.len() {
+            let next = bytes.get(i + 1);
+            if (bytes[i] == b'#' && i > 0 && matches!(bytes[i - 1], b' ' | b'\t'))
+                || (bytes[i] == b':' && matches!(next, None | Some(b' ' | b'\t')))
+            {
+                end = i;
+                break;
+            }
+        }
+        start + line[..end].trim_end().len()
+    }
+
+    /// If a mapping key starts at `pos`, return it with the position after its `:`.
+    fn mapping_key(&self, pos: usize) -> Option<(String, usize)> {
+        let (key, after) = match self.text.as_bytes()[pos] {
+            b'"' => {
+                let end = self.double_quoted_end(pos).ok()?;
+                (decode_double_quoted(&self.text[pos + 1..end - 1]), end)
+            }
+            b'\'' => {
+                let end = self.single_quoted_end(pos).ok()?;
+                (self.text[pos + 1..end - 1].replace("''", "'"), end)
+            }
+            b'[' | b'{' | b'#' | b'?' | b'|' | b'>' | b'*' | b'&' | b'!' | b'%' | b'@' | b'`' => {
+                return None
+            }
+            _ => {
+                let end = self.plain_end(pos);
+                (self.text[pos..end].to_string(), end)
+            }
+        };
+        let rest = &self.text[after..];
+        let colon = after + rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        let tail = &self.text.as_bytes()[colon..];
+        if tail.first() == Some(&b':')
+            && matches!(tail.get(1), None | Some(b' ' | b'\t' | b'\n'))
+            && !key.is_empty()
+        {
+            Some((key, colon + 1))
+        } else {
+            None
+        }
+    }
+
+    fn mapping(&mut self, indent: usize) -> Result<YamlNode, String> {
+        let start = self.pos;
+        let mut children = Vec::new();
+        loop {
+            let key_start = self.pos;
+            let Some((key, after)) = self.mapping_key(key_start) else {
+                return self.error("expected a mapping key");
+            };
+            self.pos = after;
+            let node = self.node(indent as isize, Context::MapValue)?;
+            self.pos = node.end;
+            self.end_of_node()?;
+            children.push(Child {
+                key: Some(key),
+                key_start,
+                node,
+            });
+
+            match self.next_content_line(self.pos) {
+                Some(line) if self.indent(line) == indent => {
+                    if self.is_dash(line + indent) || (indent == 0 && self.is_document_marker(line)) {
+                        break;
+                    }
+                    self.pos = line + indent;
+                }
+                Some(line) if self.indent(line) > indent => {
+                    self.pos = line;
+                    return self.error("unexpected indentation");
+                }
+                _ => break,
+            }
+        }
+        let end = children.last().map_or(start, |c| c.node.end);
+        self.pos = end;
+        Ok(YamlNode {
+            kind: NodeKind::Map(children),
+            start,
+            end,
+        })
+    }
+
+    fn sequence(&mut self, indent: usize) -> Result<YamlNode, String> {
+        let start = self.pos;
+        let mut children = Vec::new();
+        loop {
+            let key_start = self.pos;
+            self.pos += 1;
+            let node = self.node(indent as isize, Context::SeqItem)?;
+            self.pos = node.end;
+            self.end_of_node()?;
+            children.push(Child {
+                key: None,
+                key_start,
+                node,
+            });
+
+            match self.next_content_line(self.pos) {
+                Some(line) if self.indent(line) == indent && self.is_dash(line + indent) => {
+                    self.pos = line + indent;
+                }
+                Some(line) if self.indent(line) > indent => {
+                    self.pos = line;
+                    return self.error("unexpected indentation");
+                }
+                _ => break,
+            }
+        }
+        let end = children.last().map_or(start, |c| c.node.end);
+        self.pos = end;
+        Ok(YamlNode {
+            kind: NodeKind::Seq(children),
+            start,
+            end,
+        })
+    }
+
+    /// `|` or `>` block scalar: the header and the lines indented more than
+    /// the parent.
+    fn block_scalar(&mut self, parent_indent: isize) -> Result<(NodeKind, usize), String> {
+        let start = self.pos;
+        let header_end = self.line_end(start);
+        let header = self.text[start..header_end].split('#').next().unwrap_or("").trim();
+        let folded = header.starts_with('>');
+        let strip = header.contains('-');
+
+        let mut end = header_end;
+        let mut lines = Vec::new();
+        let mut block_indent = None;
+        let mut line = header_end + 1;
+        while line <= self.text.len() {
+            let line_end = self.line_end(line);
+            let content = &self.text[line..line_end];
+            if content.trim().is_empty() {
+                lines.push("");
+            } else {
+                let indent = self.indent(line);
+                if indent as isize <= parent_indent || block_indent.is_some_and(|b| indent < b) {
+                    break;
+                }
+                let block = *block_indent.get_or_insert(indent);
+                lines.push(&content[block..]);
+                end = line_end;
+            }
+            line = line_end + 1;
+        }
+        // Trailing blank lines are not part of the scalar
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+
+        let mut value = if folded {
+            let mut value = String::new();
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    value.push(if line.is_empty() || lines[i - 1].is_empty() { '\n' } else { ' ' });
+                }
+                value.push_str(line);
+            }
+            value
+        } else {
+            lines.join("\n")
+        };
+        if !strip && !lines.is_empty() {
+            value.push('\n');
+        }
+        Ok((
+            NodeKind::Scalar {
+                style: Style::Block,
+                value,
+            },
+            end,
+        ))
+    }
+
+    fn double_quoted_end(&self, start: usize) -> Result<usize, String> {
+        let bytes = self.text.as_bytes();
+        let mut i = start + 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => return Ok(i + 1),
+                _ => i += 1,
+            }
+        }
+        self.error("unterminated double-quoted scalar")
+    }
+
+    fn single_quoted_end(&self, start: usize) -> Result<usize, String> {
+        let bytes = self.text.as_bytes();
+        let mut i = start + 1;
+        while i < bytes.len() {
+            if bytes[i] == b'\'' {
+                if bytes.get(i + 1) == Some(&b'\'') {
+                    i += 2;
+                    continue;
+                }
+                return Ok(i + 1);
+            }
+            i += 1;
+        }
+        self.error("unterminated single-quoted scalar")
+    }
+
+    /// End of a flow collection, after its closing bracket.
+    fn flow_end(&self, start: usize) -> Result<usize, String> {
+        let bytes = self.text.as_bytes();
+        let mut depth = 0;
+        let mut i = start;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'[' | b'{' => depth += 1,
+                b']' | b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(i + 1);
+                    }
+                }
+                b'"' => {
+                    i = self.double_quoted_end(i)?;
+                    continue;
+                }
+                b'\'' => {
+                    i = self.single_quoted_end(i)?;
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        self.error("unterminated flow collection")
+    }
+}
+
+/// Fold the line breaks of a quoted scalar: a break and the indentation
+/// around it become a space, empty lines become line feeds.
+fn fold_lines(text: &str) -> String {
+    if !text.contains('\n') {
+        return text.to_string();
+    }
+    let lines: Vec<&str> = text.split('\n').collect();
+    let mut out = String::new();
+    let mut breaks = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let mut line = *line;
+        if i > 0 {
+            line = line.trim_start_matches([' ', '\t']);
+        }
+        if i + 1 < lines.len() {
+            line = line.trim_end_matches([' ', '\t']);
+        }
+        if i > 0 && line.is_empty() && i + 1 < lines.len() {
+            breaks += 1;
+            continue;
+        }
+        if i > 0 {
+            if breaks == 0 {
+                out.push(' ');
+            }
+            out.extend(std::iter::repeat_n('\n', breaks));
+            breaks = 0;
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+fn decode_double_quoted(text: &str) -> String {
+    let folded = fold_lines(text);
+    let mut out = String::with_capacity(folded.len());
+    let mut chars = folded.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        let hex = |chars: &mut std::str::Chars, n: usize| {
+            let digits: String = chars.by_ref().take(n).collect();
+            u32::from_str_radix(&digits, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .unwrap_or(char::REPLACEMENT_CHARACTER)
+        };
+        match chars.next() {
+            Some('0') => out.push('\0'),
+            Some('a') => out.push('\x07'),
+            Some('b') => out.push('\x08'),
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('v') => out.push('\x0b'),
+            Some('f') => out.push('\x0c'),
+            Some('r') => out.push('\r'),
+            Some('e') => out.push('\x1b'),
+            Some('N') => out.push('\u{85}'),
+            Some('_') => out.push('\u{a0}'),
+            Some('L') => out.push('\u{2028}'),
+            Some('P') => out.push('\u{2029}'),
+            Some('x') => out.push(hex(&mut chars, 2)),
+            Some('u') => out.push(hex(&mut chars, 4)),
+            Some('U') => out.push(hex(&mut chars, 8)),
+            // Escaped line break, folded to a space above
+            Some(' ') => {}
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn encode_double_quoted(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if (c as u32) < 0x20 || c == '\x7f' => out.push_str(&format!("\\x{:02x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Type a plain scalar resolves to (YAML 1.1, as read by Ruby).
+#[derive(Debug, PartialEq)]
+enum PlainKind {
+    Null,
+    Bool,
+    Int,
+    Float,
+    Str,
+}
+
+fn plain_kind(text: &str) -> PlainKind {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => return PlainKind::Null,
+        "true" | "True" | "TRUE" | "false" | "False" | "FALSE" | "yes" | "Yes" | "YES" | "no"
+        | "No" | "NO" | "on" | "On" | "ON" | "off" | "Off" | "OFF" => return PlainKind::Bool,
+        ".inf" | "-.inf" | "+.inf" | ".Inf" | ".INF" | ".nan" | ".NaN" | ".NAN" => {
+            return PlainKind::Float
+        }
+        _ => {}
+    }
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        PlainKind::Int
+    } else if digits.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && text.parse::<f64>().is_ok()
+    {
+        PlainKind::Float
+    } else {
+        PlainKind::Str
+    }
+}
+
+/// True if `text` can be written as a plain scalar and read back unchanged.
+fn is_plain_safe(text: &str) -> bool {
+    let Some(first) = text.chars().next() else {
+        return false;
+    };
+    let indicator = "-?:,[]{}#&*!|>'\"%@`".contains(first);
+    !(indicator && !(first == '-' && text[1..].starts_with(|c: char| c.is_ascii_digit())))
+        && text.trim() == text
+        && !text.contains(": ")
+        && !text.contains(" #")
+        && !text.ends_with(':')
+        && !text.chars().any(|c| c.is_control())
+}
+
+/// Write `value` in place of a scalar written in `style`, keeping the style
+/// and, for plain scalars, the type when possible.
+fn format_scalar(value: &str, style: Style, original: &str) -> String {
+    match style {
+        Style::Plain
+            if is_plain_safe(value)
+                && (plain_kind(value) == plain_kind(original)
+                    || plain_kind(original) == PlainKind::Null && plain_kind(value) == PlainKind::Str) =>
+        {
+            value.to_string()
+        }
+        Style::SingleQuoted if !value.chars().any(|c| c.is_control()) => {
+            format!("'{}'", value.replace('\'', "''"))
+        }
+        _ => encode_double_quoted(value),
+    }
+}
+
+/// Replacement of `yaml[start..end]` by `with` (unescaped YAML text).
+struct Edit {
+    start: usize,
+    end: usize,
+    with: String,
+}
+
+/// A YAML document anonymized in place.
+///
+/// The document is kept as its SQL-escaped text, as found in the dump. Rules
+/// only rewrite the scalars they target: indentation, comments, tags and
+/// quoting of everything else are left byte-for-byte identical.
+pub struct YamlDocument {
+    text: String,
+}
+
+impl YamlDocument {
+    /// Wrap the SQL-escaped text of a YAML value (without its quotes).
+    /// Returns an error if it is not a YAML document this parser reads.
+    pub fn parse(escaped: &str) -> Result<YamlDocument, String> {
+        let (yaml, _) = mysql_unescape(escaped);
+        YamlParser {
+            text: &yaml,
+            pos: 0,
+        }
+        .document()?;
+        Ok(YamlDocument {
+            text: escaped.to_string(),
+        })
+    }
+
+    /// Current escaped text of the document.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Apply one rule to the values matched by `path` (same syntax as JSON
+    /// paths), with `anonymize` computing the anonymized text of a scalar.
+    /// Returns the number of values anonymized or removed.
+    pub fn anonymize_with(
+        &mut self,
+        path: &JsonPath,
+        config: &AnonBase,
+        anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+    ) -> usize {
+        let (yaml, offsets) = mysql_unescape(&self.text);
+        let Ok(root) = (YamlParser {
+            text: &yaml,
+            pos: 0,
+        })
+        .document() else {
+            return 0;
+        };
+        let locations = path_locations(&root, path);
+
+        let mut edits = Vec::new();
+        let count = if config.anon_type == AnonType::Remove {
+            removal_edits(&root, &locations, &mut edits)
+        } else {
+            for location in &locations {
+                let node = root.at(location);
+                let Some(mut with) = anonymize_node(node, &yaml, config, anonymize) else {
+                    continue;
+                };
+                // An empty value directly follows its `:` or `-`
+                if node.start == node.end && !yaml[..node.start].ends_with([' ', '\t']) {
+                    with.insert(0, ' ');
+                }
+                edits.push(Edit {
+                    start: node.start,
+                    end: node.end,
+                    with,
+                });
+            }
+            edits.len()
+        };
+
+        edits.sort_by_key(|e| e.start);
+        let mut out = String::with_capacity(self.text.len());
+        let mut copied = 0;
+        for edit in edits {
+            let start = offsets[edit.start];
+            let end = offsets[edit.end];
+            if start < copied {
+                continue;
+            }
+            out.push_str(&self.text[copied..start]);
+            out.push_str(&mysql_backslash_escape(&edit.with));
+            copied = end;
+        }
+        out.push_str(&self.text[copied..]);
+        self.text = out;
+        count
+    }
+}
+
+/// New text of a matched node, or `None` to leave it unchanged.
+fn anonymize_node(
+    node: &YamlNode,
+    yaml: &str,
+    config: &AnonBase,
+    anonymize: &mut dyn FnMut(&[u8]) -> Option<Vec<u8>>,
+) -> Option<String> {
+    let original = &yaml[node.start..node.end];
+    let style = match &node.kind {
+        NodeKind::Scalar { style, .. } => *style,
+        _ => Style::Plain,
+    };
+    match config.anon_type {
+        AnonType::FixedNull => return Some("null".to_string()),
+        AnonType::Fixed | AnonType::FixedQuoted | AnonType::FixedUnquoted => {
+            return Some(format_scalar(&config.fixed_value, style, original))
+        }
+        _ => {}
+    }
+    let NodeKind::Scalar { style, value } = &node.kind else {
+        return None;
+    };
+    if *style == Style::Plain && plain_kind(value) == PlainKind::Null {
+        return None;
+    }
+    let new = anonymize(value.as_bytes())?;
+    Some(format_scalar(&String::from_utf8_lossy(&new), *style, original))
+}
+
+/// Build the edits removing the entries or items at `locations`. Returns the
+/// number of values removed.
+fn removal_edits(root: &YamlNode, locations: &[Vec<usize>], edits: &mut Vec<Edit>) -> usize {
+    // Group the removed children by parent
+    let mut groups: Vec<(&[usize], Vec<usize>)> = Vec::new();
+    for location in locations {
+        let Some((&i, parent)) = location.split_last() else {
+            // The root itself cannot be removed
+            continue;
+        };
+        match groups.iter_mut().find(|(p, _)| *p == parent) {
+            Some((_, removed)) => removed.push(i),
+            None => groups.push((parent, vec![i])),
+        }
+    }
+
+    let mut count = 0;
+    for (parent, mut removed) in groups {
+        removed.sort_unstable();
+        removed.dedup();
+        count += removed.len();
+        let parent = root.at(parent);
+        let children = parent.children();
+        let last_kept = (0..children.len()).rev().find(|i| removed.binary_search(i).is_err());
+        for &i in &removed {
+            let (start, end, with) = match last_kept {
+                // Nothing left: write an empty collection
+                None if i == 0 => {
+                    let empty = if parent.is_list() { "[]" } else { "{}" };
+                    (parent.start, parent.end, empty.to_string())
+                }
+                None => continue,
+                // Before a kept child: up to the start of the next one
+                Some(kept) if i < kept => {
+                    (children[i].key_start, children[i + 1].key_start, String::new())
+                }
+                // After the last kept child: from the end of the previous one
+                Some(_) => (children[i - 1].node.end, children[i].node.end, String::new()),
+            };
+            edits.push(Edit { start, end, with });
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> Result<YamlNode, String> {
+        YamlParser { text: yaml, pos: 0 }.document()
+    }
+
+    fn anonymize(escaped: &str, path: &str, anon_type: AnonType) -> (String, usize) {
+        let infos = AnonBase {
+            anon_type,
+            ..Default::default()
+        };
+        let mut doc = YamlDocument::parse(escaped).unwrap();
+        let path = JsonPath::parse(path).unwrap();
+        let count = doc.anonymize_with(&path, &infos, &mut |token| {
+            Some(format!("<{}>", String::from_utf8_lossy(token)).into_bytes())
+        });
+        (doc.as_str().to_string(), count)
+    }
+
+    fn value(node: &YamlNode) -> &str {
+        match &node.kind {
+            NodeKind::Scalar { value, .. } => value,
+            _ => panic!("not a scalar: {:?}", node),
+        }
+    }
+
+    #[test]
+    fn test_parse_structure() {
+        let yaml = "--- !ruby/hash:ActiveSupport::HashWithIndifferentAccess\n# comment\nuser:\n  name: Zoé  # trailing\n  'quoted key': \"a\\tb\"\n  tags:\n  - one\n  - two: 2\n    three: '3'''\nlist: [1, 2]\nnote: |\n  line 1\n  line 2\nempty:\n";
+        let root = parse(yaml).unwrap();
+        let user = root.child(0);
+        assert_eq!(root.child_key(0), Some("user"));
+        assert_eq!(value(user.child(0)), "Zoé");
+        assert_eq!(user.child_key(1), Some("quoted key"));
+        assert_eq!(value(user.child(1)), "a\tb");
+        let tags = user.child(2);
+        assert!(tags.is_list());
+        assert_eq!(value(tags.child(0)), "one");
+        assert_eq!(value(tags.child(1).child(1)), "3'");
+        assert!(matches!(root.child(1).kind, NodeKind::Opaque));
+        assert_eq!(value(root.child(2)), "line 1\nline 2\n");
+        assert_eq!(value(root.child(3)), "");
+
+        let root = parse(":author_name: x\n").unwrap();
+        assert_eq!(root.child_key(0), Some("author_name"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for yaml in ["", "a: 1\n  b: 2", "a: \"x", "- a\nb: 1", "a: b: c", "a: 1\n---\nb: 2"] {
+            assert!(parse(yaml).is_err(), "{:?}", yaml);
+        }
+    }
+
+    #[test]
+    fn test_untouched_text_is_kept() {
+        let input = "---\\nuser:\\n  email: a@b.c   # work\\n  name: \\'Zo\\'\\'é\\'\\n  bio: \\\"x\\\"\\n";
+        let (out, count) = anonymize(input, "..email", AnonType::TextHash);
+        assert_eq!(count, 1);
+        assert_eq!(out, input.replace("a@b.c", "<a@b.c>"));
+
+        let (out, _) = anonymize(input, "user.name", AnonType::TextHash);
+        assert_eq!(out, input.replace("\\'Zo\\'\\'é\\'", "\\'<Zo\\'\\'é>\\'"));
+    }
+
+    #[test]
+    fn test_types_kept() {
+        let input = "id: 12\nactive: yes\ntoken: ~\nname: plain";
+        let infos = AnonBase {
+            anon_type: AnonType::IntHash,
+            ..Default::default()
+        };
+        let mut doc = YamlDocument::parse(input).unwrap();
+        let count = doc.anonymize_with(&JsonPath::parse("*").unwrap(), &infos, &mut |_| {
+            Some(b"42".to_vec())
+        });
+        // Null values are left alone, a number in a string stays a string
+        assert_eq!(count, 3);
+        assert_eq!(doc.as_str(), "id: 42\nactive: \\\"42\\\"\ntoken: ~\nname: \\\"42\\\"");
+    }
+
+    #[test]
+    fn test_block_scalar_and_fixed_null() {
+        let (out, _) = anonymize("a: |\n  x\n  y\nb: 1\n", "a", AnonType::TextHash);
+        assert_eq!(out, "a: \\\"<x\\\\ny\\\\n>\\\"\nb: 1\n");
+        let (out, _) = anonymize("a:\nb:\n  c: 1\n", "*", AnonType::FixedNull);
+        assert_eq!(out, "a: null\nb:\n  null\n");
+    }
+
+    #[test]
+    fn test_remove() {
+        let input = "a: 1\nlist:\n- name: x\n  token: t\n  mail: m\n- name: y\nc: 3";
+        let (out, count) = anonymize(input, "list[].name", AnonType::Remove);
+        assert_eq!(count, 2);
+        assert_eq!(out, "a: 1\nlist:\n- token: t\n  mail: m\n- {}\nc: 3");
+        let (out, _) = anonymize(input, "c", AnonType::Remove);
+        assert_eq!(out, "a: 1\nlist:\n- name: x\n  token: t\n  mail: m\n- name: y");
+        let (out, _) = anonymize(input, "list[0].mail", AnonType::Remove);
+        assert_eq!(out, "a: 1\nlist:\n- name: x\n  token: t\n- name: y\nc: 3");
+    }
+}
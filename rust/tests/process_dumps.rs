@@ -0,0 +1,440 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use myanon::config::{Config, Parser};
+use myanon::dump::{profile_dump, DumpProcessor, Reporter};
+use myanon::encrypt::{decrypt_config, decrypt_value, Encoding, EncryptRule};
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("tests")
+}
+
+/// Config and dump of a test of the top-level tests directory
+fn load(name: &str) -> (Config, Vec<u8>) {
+    let path = tests_dir().join(format!("{}.conf", name));
+    let input = fs::read_to_string(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+    let config = Parser::new(&input)
+        .parse()
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+    let path = tests_dir().join(format!("{}.sql", name));
+    let dump = fs::read(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+    (config, dump)
+}
+
+fn anonymize_with(config: &mut Config, dump: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut processor = DumpProcessor::new(config).unwrap();
+    processor.process(dump, &mut out).unwrap();
+    out
+}
+
+/// Run the binary on `tests/<name>.sql` with `tests/<name>.conf`, as `make
+/// check` does, and compare its output with `tests/<name>_anon.sql`.
+fn check_golden(name: &str, args: &[&str]) {
+    let dump = fs::File::open(tests_dir().join(format!("{}.sql", name))).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_myanon"))
+        .current_dir(tests_dir().join(".."))
+        .arg("-f")
+        .arg(format!("tests/{}.conf", name))
+        .args(args)
+        .stdin(dump)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}: {}", name, String::from_utf8_lossy(&output.stderr));
+    let expected = fs::read(tests_dir().join(format!("{}_anon.sql", name))).unwrap();
+    assert!(output.stdout == expected, "{}: output differs from {}_anon.sql", name, name);
+}
+
+#[test]
+fn test_golden_dumps() {
+    // Tests of the C version, `TULIST` of Makefile.am
+    let tests = [
+        "test1",
+        "test1-insert-ignore",
+        "null-example",
+        "appendkey",
+        "prependkey",
+        "issue29",
+        "issue52",
+        "issue55",
+        "issue_semicolon_truncate",
+        "test_substring",
+        "appendindex",
+        "prependindex",
+        "test_regex",
+        "complex",
+    ];
+    for name in tests {
+        check_golden(name, &[]);
+    }
+}
+
+#[test]
+fn test_latin1_from_set_names() {
+    check_golden("charset_set_names", &[]);
+}
+
+#[test]
+fn test_latin1_from_table_charset() {
+    check_golden("charset_table", &[]);
+}
+
+#[test]
+fn test_set_names_wins_over_table_charset() {
+    check_golden("charset_utf8_names", &[]);
+}
+
+#[test]
+fn test_charset_override() {
+    check_golden("charset_override", &["-c", "latin1"]);
+}
+
+#[test]
+fn test_latin1_fixed_value_is_reencoded() {
+    check_golden("charset_fixed", &[]);
+}
+
+#[test]
+fn test_latin1_binary_literals_not_transcoded() {
+    check_golden("charset_binary", &[]);
+    // Same bytes as in a utf8 dump
+    check_golden("uuidhash", &["-c", "latin1"]);
+}
+
+#[test]
+fn test_json_non_string_values() {
+    check_golden("json_values", &[]);
+    let (mut config, dump) = load("json_values");
+    anonymize_with(&mut config, &dump);
+    let json = &config.tables[0].fields[0].json;
+    assert_eq!(json[0].infos.nbhits, 2);
+    assert_eq!(json[1].infos.nbhits, 1);
+    assert_eq!(json[2].infos.nbhits, 0);
+}
+
+#[test]
+fn test_json_field_rules() {
+    check_golden("json_rules", &[]);
+}
+
+#[test]
+fn test_json_preserve_keeps_untouched_bytes() {
+    check_golden("json_preserve", &[]);
+}
+
+#[test]
+fn test_json_embedded_in_string() {
+    check_golden("json_embedded", &[]);
+}
+
+#[test]
+fn test_json_embedded_in_string_preserved() {
+    check_golden("json_embedded_preserve", &[]);
+}
+
+#[test]
+fn test_php_serialized() {
+    check_golden("phpserialized", &[]);
+    let (mut config, dump) = load("phpserialized");
+    anonymize_with(&mut config, &dump);
+    let rules = &config.tables[0].fields[0].json;
+    assert_eq!(rules[0].infos.nbhits, 2);
+    assert_eq!(rules[1].infos.nbhits, 2);
+    assert_eq!(rules[2].infos.nbhits, 0);
+}
+
+#[test]
+fn test_php_serialized_latin1_lengths() {
+    check_golden("phpserialized_latin1", &[]);
+}
+
+#[test]
+fn test_xml_rules() {
+    check_golden("xml", &[]);
+    let (mut config, dump) = load("xml");
+    anonymize_with(&mut config, &dump);
+    let rules = &config.tables[0].fields[0].json;
+    assert_eq!(rules[0].infos.nbhits, 1);
+    assert_eq!(rules[1].infos.nbhits, 1);
+    assert_eq!(rules[2].infos.nbhits, 1);
+    assert_eq!(rules[3].infos.nbhits, 0);
+}
+
+#[test]
+fn test_yaml_rules() {
+    check_golden("yaml", &[]);
+    let (mut config, dump) = load("yaml");
+    anonymize_with(&mut config, &dump);
+    let rules = &config.tables[0].fields[0].json;
+    assert!(rules.iter().all(|r| r.infos.nbhits == 1));
+}
+
+#[test]
+fn test_url_rules() {
+    check_golden("url", &[]);
+    let (mut config, dump) = load("url");
+    anonymize_with(&mut config, &dump);
+    let hits: Vec<u64> = config.tables[0].fields[0].url.iter().map(|u| u.infos.nbhits).collect();
+    assert_eq!(hits, [1, 1, 1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn test_scrub_rules() {
+    check_golden("scrub", &[]);
+    let (mut config, dump) = load("scrub");
+    anonymize_with(&mut config, &dump);
+    let hits: Vec<u64> = config.tables[0].fields[0].scrub.iter().map(|r| r.infos.nbhits).collect();
+    assert_eq!(hits, [2, 1, 1, 1]);
+}
+
+#[test]
+fn test_regexreplace_rule() {
+    check_golden("regexreplace", &[]);
+}
+
+#[test]
+fn test_iphash_forms() {
+    check_golden("iphash", &[]);
+    let (mut config, dump) = load("iphash");
+    let out = String::from_utf8(anonymize_with(&mut config, &dump)).unwrap();
+    let values = out.rsplit("VALUES ").next().unwrap();
+    let re = regex::Regex::new(
+        r"^\((\d+),0x([0-9A-F]{8}),'([0-9.]+)'\),\(\d+,0x0A000001,'10\.0\.0\.1'\),\(\d+,NULL,'2001:db8::1'\);\n$",
+    )
+    .unwrap();
+    let caps = re.captures(values).unwrap_or_else(|| panic!("{}", values));
+    // Same address in its three forms, same pseudonym
+    let addr: std::net::Ipv4Addr = caps[3].parse().unwrap();
+    assert_ne!(addr.to_string(), "93.184.216.34");
+    assert_eq!(caps[1], u32::from(addr).to_string());
+    assert_eq!(caps[2], format!("{:08X}", u32::from(addr)));
+}
+
+#[test]
+fn test_cardhash_rule() {
+    check_golden("cardhash", &[]);
+}
+
+#[test]
+fn test_idhash_rule() {
+    check_golden("idhash", &[]);
+}
+
+#[test]
+fn test_noise_rule() {
+    check_golden("noise", &[]);
+    let (mut config, dump) = load("noise");
+    let out = String::from_utf8(anonymize_with(&mut config, &dump)).unwrap();
+    let values = out.rsplit("VALUES ").next().unwrap();
+    let re = regex::Regex::new(
+        r"^\(1,(\d+\.\d\d),(\d+),(\d+)\),\(2,(\d+\.\d\d),(-\d+),NULL\),\(3,0\.00,0,(\d+)\);\n$",
+    )
+    .unwrap();
+    let caps = re.captures(values).unwrap_or_else(|| panic!("{}", values));
+    // Same salary, same noise
+    assert_eq!(caps[1], caps[4]);
+    let salary: f64 = caps[1].parse().unwrap();
+    assert!((46800.0..=57200.0).contains(&salary) && salary != 52000.0);
+    let bonus: i64 = caps[2].parse().unwrap();
+    assert!((1100..=1300).contains(&bonus));
+    let bonus: i64 = caps[5].parse().unwrap();
+    assert!((-400..0).contains(&bonus));
+    // Clamped to the tinyint unsigned range
+    let age: u32 = caps[3].parse().unwrap();
+    assert!((241..=255).contains(&age));
+    let age: u32 = caps[6].parse().unwrap();
+    assert!((38..=42).contains(&age));
+}
+
+#[test]
+fn test_generalize_rules() {
+    check_golden("generalize", &[]);
+}
+
+#[test]
+fn test_report_after_generalize() {
+    check_golden("report", &[]);
+    let (mut config, dump) = load("report");
+    let reports = |dump: &[u8], config: &Config| {
+        let mut reporter = Reporter::new(config);
+        reporter.process(dump).unwrap();
+        reporter.report()
+    };
+    let before = reports(&dump, &config);
+    assert_eq!((before[0].k, before[0].unique_rows), (Some(1), 4));
+
+    let out = anonymize_with(&mut config, &dump);
+    let after = reports(&out, &config);
+    assert_eq!((after[0].k, after[0].unique_rows, after[0].classes), (Some(2), 0, 2));
+    assert_eq!(after[0].l_diversity, [("diagnosis".to_string(), Some(1))]);
+}
+
+#[test]
+fn test_uuidhash_forms() {
+    check_golden("uuidhash", &[]);
+    let (mut config, dump) = load("uuidhash");
+    let out = anonymize_with(&mut config, &dump);
+    let values = &out[out.windows(7).rposition(|w| w == b"VALUES ").unwrap() + 7..];
+    let re = regex::bytes::Regex::new(
+        r"(?s-u)^\('([0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12})',_binary '(.+)',0x([0-9A-F]{32}),'([0-9A-F]{32})'\),\('n/a',NULL,NULL,NULL\);\n$",
+    )
+    .unwrap();
+    let caps = re.captures(values).unwrap_or_else(|| panic!("{}", String::from_utf8_lossy(values)));
+    // Same UUID in its four forms, same pseudonym
+    let text = String::from_utf8(caps[1].to_vec()).unwrap().replace('-', "").to_uppercase();
+    assert_ne!(text, "123E4567E89B12D3A456426614174000");
+    assert_eq!(caps[3], *text.as_bytes());
+    assert_eq!(caps[4], *text.as_bytes());
+    let (binary, _) = myanon::anonymize::mysql_unescape_bytes(&caps[2]);
+    let hex: String = binary.iter().map(|b| format!("{:02X}", b)).collect();
+    assert_eq!(hex, text);
+}
+
+#[test]
+fn test_emailhash_options() {
+    check_golden("emailhash", &[]);
+    let (mut config, dump) = load("emailhash");
+    let out = String::from_utf8(anonymize_with(&mut config, &dump)).unwrap();
+    let re = regex::Regex::new(
+        r"VALUES \(1,'([a-z]{10}@example\.com)'\),\(2,'[a-z]{2}\.[a-z]{6}@example\.org'\);\n(?s:.*)VALUES \(1,'([a-z]{10}@example\.com)'\);\n$",
+    )
+    .unwrap();
+    let caps = re.captures(&out).unwrap_or_else(|| panic!("{}", out));
+    // Case, tag and spaces do not break the join
+    assert_eq!(caps[1], caps[2]);
+}
+
+#[test]
+fn test_idmap_keeps_joins() {
+    check_golden("idmap", &[]);
+    let (mut config, dump) = load("idmap");
+    let out = String::from_utf8(anonymize_with(&mut config, &dump)).unwrap();
+    let re = regex::Regex::new(
+        r"VALUES \((\d+),(-?\d+)\),\((\d+),(-?\d+)\),\((\d+),(-?\d+)\);\n(?s:.*)VALUES \(1,(\d+)\),\(2,(\d+)\),\(3,NULL\);\n$",
+    )
+    .unwrap();
+    let caps = re.captures(&out).unwrap_or_else(|| panic!("{}", out));
+    let n = |i: usize| caps[i].parse::<i64>().unwrap();
+    assert_ne!((n(1), n(3), n(5)), (1, 2, 3));
+    assert!(n(1) != n(3) && n(3) != n(5) && n(1) != n(5));
+    // Foreign keys follow the primary keys
+    assert_eq!((n(7), n(8)), (n(5), n(1)));
+    // Without a range, the tinyint column range is used
+    let levels = [n(2), n(4), n(6)];
+    assert!(levels.iter().all(|l| (-128..=127).contains(l)), "{:?}", levels);
+    assert!(levels[0] != levels[1] && levels[1] != levels[2] && levels[0] != levels[2]);
+}
+
+#[test]
+fn test_mask_rule() {
+    check_golden("mask", &[]);
+}
+
+#[test]
+fn test_encrypt_round_trip() {
+    check_golden("encrypt", &[]);
+    let (mut config, dump) = load("encrypt");
+    let out = anonymize_with(&mut config, &dump);
+    let text = String::from_utf8(out.clone()).unwrap();
+    let re = regex::Regex::new(
+        r"VALUES \(1,'([A-Za-z0-9+/=]{60})','([0-9a-f]{46})'\),\(2,'([A-Za-z0-9+/=]{60})',NULL\),\(3,'([A-Za-z0-9+/=]{24})','[0-9a-f]{40}'\);\n$",
+    )
+    .unwrap();
+    let caps = re.captures(&text).unwrap_or_else(|| panic!("{}", text));
+    // Deterministic: equal values stay equal
+    assert_eq!(caps[1], caps[3]);
+
+    let rule = config.tables[0].fields[0].infos.encrypt.clone().unwrap();
+    assert_eq!(decrypt_value(&caps[1], &rule.key, Encoding::Base64).unwrap(), b"FR7630006000011234567890189");
+    assert_eq!(decrypt_value(&caps[2], &rule.key, Encoding::Hex).unwrap(), b"O'Brien");
+
+    // Decrypting both columns gives the original dump back
+    let mut decrypt = decrypt_config("invoices", "iban", rule.clone());
+    let out = anonymize_with(&mut decrypt, &out);
+    let mut decrypt = decrypt_config("invoices", "name", EncryptRule { encoding: Encoding::Hex, ..rule });
+    assert_eq!(anonymize_with(&mut decrypt, &out), dump);
+}
+
+#[test]
+fn test_encrypt_width_exceeded() {
+    let input = fs::read_to_string(tests_dir().join("encrypt.conf")).unwrap();
+    let mut config = Parser::new(&input.replace("width '64'", "width '48'")).parse().unwrap();
+    let dump = fs::read(tests_dir().join("encrypt.sql")).unwrap();
+    let mut processor = DumpProcessor::new(&mut config).unwrap();
+    let err = processor.process(&dump[..], &mut Vec::new()).unwrap_err();
+    assert!(
+        err.contains("Table invoices: encrypted value of field iban at line 6 has 60 characters, more than width 48 (values up to 20 bytes fit)"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_shuffle_rule() {
+    check_golden("shuffle", &[]);
+    let (mut config, dump) = load("shuffle");
+    let out = String::from_utf8(anonymize_with(&mut config, &dump)).unwrap();
+    let (mut config, _) = load("shuffle");
+    assert_eq!(out, String::from_utf8(anonymize_with(&mut config, &dump)).unwrap());
+    assert!(out.ends_with("UNLOCK TABLES;\nCREATE TABLE `other` (\n  `id` int NOT NULL\n) ENGINE=InnoDB;\nINSERT INTO `other` VALUES (1);\n"), "{}", out);
+    assert_eq!(out.matches("INSERT INTO `people` VALUES").count(), 4);
+
+    let re = regex::Regex::new(r"\((\d+),(?:NULL,NULL|'City (\d+)','(\d+)'),'(Job \d+|O\\'Brien)'\)").unwrap();
+    let (mut cities, mut jobs, mut moved) = (Vec::new(), Vec::new(), 0);
+    for row in re.captures_iter(&out) {
+        let city = row.get(2).map(|m| m.as_str().parse::<u32>().unwrap());
+        // City and zip move together
+        assert_eq!(city, row.get(3).map(|m| m.as_str().parse().unwrap()));
+        if city != Some(row[1].parse().unwrap()) {
+            moved += 1;
+        }
+        cities.push(city);
+        jobs.push(row[4].to_string());
+    }
+    assert!(moved > 20, "{}", moved);
+    // Same values, in other rows
+    cities.sort();
+    let mut expected: Vec<_> = (0..30).map(Some).chain([None]).collect();
+    expected.sort();
+    assert_eq!(cities, expected);
+    jobs.sort();
+    let mut expected: Vec<_> = (0..30).map(|i| format!("Job {}", i)).chain(["O\\'Brien".to_string()]).collect();
+    expected.sort();
+    assert_eq!(jobs, expected);
+}
+
+#[test]
+fn test_synthesize_rule() {
+    check_golden("synthesize", &[]);
+    let (mut config, dump) = load("synthesize");
+    let err = DumpProcessor::new(&mut config).err().unwrap();
+    assert!(err.contains("users:status - synthesize rule without profile"), "{}", err);
+
+    profile_dump(&mut config, &dump[..]).unwrap();
+    let out = String::from_utf8(anonymize_with(&mut config, &dump)).unwrap();
+    let (mut config, _) = load("synthesize");
+    profile_dump(&mut config, &dump[..]).unwrap();
+    assert_eq!(out, String::from_utf8(anonymize_with(&mut config, &dump)).unwrap());
+    assert!(out.ends_with("INSERT INTO `users` VALUES (2000,NULL,NULL,NULL);\n"), "{}", out);
+
+    let re = regex::Regex::new(r"\((\d+),'(active|closed)',(\d+),(-?\d+\.\d\d)\)").unwrap();
+    let (mut rows, mut closed, mut ages, mut changed) = (0, 0, 0, 0);
+    for row in re.captures_iter(&out) {
+        rows += 1;
+        let i: i64 = row[1].parse().unwrap();
+        closed += (&row[2] == "closed") as usize;
+        let age: i64 = row[3].parse().unwrap();
+        assert!((20..=59).contains(&age), "{}", age);
+        ages += age;
+        changed += (age != 20 + i % 40) as usize;
+        let balance: f64 = row[4].parse().unwrap();
+        assert!((0.5..=1999.5).contains(&balance), "{}", balance);
+    }
+    assert_eq!(rows, 2000);
+    // Same distribution, other values
+    assert!((140..260).contains(&closed), "{}", closed);
+    let mean = ages as f64 / rows as f64;
+    assert!((37.5..41.5).contains(&mean), "{}", mean);
+    assert!(changed > 1800, "{}", changed);
+}
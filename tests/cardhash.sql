@@ -0,0 +1,6 @@
+CREATE TABLE `payments` (
+  `id` int NOT NULL,
+  `card` varchar(32) DEFAULT NULL,
+  `pan` varchar(32) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `payments` VALUES (1,'4111111111111111','378282246310005'),(2,'4111 1111-1111 1111','N/A'),(3,'5555-5555-5555-4444',NULL);
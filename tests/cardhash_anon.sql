@@ -0,0 +1,6 @@
+CREATE TABLE `payments` (
+  `id` int NOT NULL,
+  `card` varchar(32) DEFAULT NULL,
+  `pan` varchar(32) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `payments` VALUES (1,'4111113100602639','378282248172072'),(2,'4111 1131-0060 2639','N/A'),(3,'5555-5509-8028-4220',NULL);
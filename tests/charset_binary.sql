@@ -0,0 +1,8 @@
+/*!40101 SET NAMES latin1 */;
+CREATE TABLE `sessions` (
+  `id` char(36) NOT NULL,
+  `bin` binary(16) DEFAULT NULL,
+  `hex` binary(16) DEFAULT NULL,
+  `plain` varchar(32) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `sessions` VALUES ('123e4567-e89b-12d3-a456-426614174000',_binary '>EgӤVBf@\0',0x123E4567E89B12D3A456426614174000,'123E4567E89B12D3A456426614174000'),('n/a',NULL,NULL,NULL);
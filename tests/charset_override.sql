@@ -0,0 +1,6 @@
+/*!50503 SET NAMES utf8mb4 */;
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'lve');
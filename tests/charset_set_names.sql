@@ -0,0 +1,6 @@
+/*!40101 SET NAMES latin1 */;
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB DEFAULT CHARSET=latin1;
+INSERT INTO `people` VALUES (1,'lve');
@@ -0,0 +1,10 @@
+CREATE TABLE `users` (
+  `id` int NOT NULL,
+  `email` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `users` VALUES (1,'Alice@Corp.com'),(2,'qa.tester@example.org');
+CREATE TABLE `orders` (
+  `id` int NOT NULL,
+  `customer_email` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `orders` VALUES (1,'alice+shop@corp.com ');
@@ -0,0 +1,6 @@
+CREATE TABLE `invoices` (
+  `id` int NOT NULL,
+  `iban` varchar(64) DEFAULT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `invoices` VALUES (1,'FR7630006000011234567890189','O\'Brien'),(2,'FR7630006000011234567890189',NULL),(3,'','Zoë');
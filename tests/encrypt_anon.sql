@@ -0,0 +1,6 @@
+CREATE TABLE `invoices` (
+  `id` int NOT NULL,
+  `iban` varchar(64) DEFAULT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `invoices` VALUES (1,'yLAJcXu54gJxJY+SIJ/4tssHdMgR8FVXWuXAkGeRdCBWXmlUhFaYyCX60Q==','b7c1d38943ad515656408fde87328a96fdee4db6ecb325'),(2,'yLAJcXu54gJxJY+SIJ/4tssHdMgR8FVXWuXAkGeRdCBWXmlUhFaYyCX60Q==',NULL),(3,'mY6DJmNTDl5PRdyWZ4GTDA==','d650aea764185006c5a5786f2fed673ee9010408');
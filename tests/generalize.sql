@@ -0,0 +1,9 @@
+CREATE TABLE `patients` (
+  `age` tinyint unsigned DEFAULT NULL,
+  `birth_date` date DEFAULT NULL,
+  `visit` datetime NOT NULL,
+  `zip` varchar(10) DEFAULT NULL,
+  `lat` decimal(9,6) DEFAULT NULL,
+  `salary` int DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `patients` VALUES (37,'1985-07-14','2024-05-17 10:22:01','75011',48.856613,52499),(NULL,NULL,'2024-11-02 08:00:00','SW1A 1AA',-2.352222,52500);
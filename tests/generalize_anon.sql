@@ -0,0 +1,9 @@
+CREATE TABLE `patients` (
+  `age` tinyint unsigned DEFAULT NULL,
+  `birth_date` date DEFAULT NULL,
+  `visit` datetime NOT NULL,
+  `zip` varchar(10) DEFAULT NULL,
+  `lat` decimal(9,6) DEFAULT NULL,
+  `salary` int DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `patients` VALUES (30,'1985-01-01','2024-04-01 00:00:00','75000',48.860000,50000),(NULL,NULL,'2024-10-01 00:00:00','SW000000',-2.350000,55000);
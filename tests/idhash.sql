@@ -0,0 +1,8 @@
+CREATE TABLE `citizens` (
+  `id` int NOT NULL,
+  `ssn` varchar(16) DEFAULT NULL,
+  `nir` varchar(32) DEFAULT NULL,
+  `nino` varchar(16) DEFAULT NULL,
+  `dni` varchar(16) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `citizens` VALUES (1,'078-05-1120','1 85 05 78 006 084 36','AB123456C','12345678Z'),(2,'078051120','185057800608436','ab 12 34 56 c','X1234567L'),(3,NULL,'unknown','','Y-1234567-X');
@@ -0,0 +1,8 @@
+CREATE TABLE `citizens` (
+  `id` int NOT NULL,
+  `ssn` varchar(16) DEFAULT NULL,
+  `nir` varchar(32) DEFAULT NULL,
+  `nino` varchar(16) DEFAULT NULL,
+  `dni` varchar(16) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `citizens` VALUES (1,'567-60-9679','2 93 01 36 672 359 58','RP732944D','02480258F'),(2,'567609679','293013667235958','RP 73 29 44 D','Z0284463G'),(3,NULL,'178066456516678','','Y-7448588-Y');
@@ -0,0 +1,10 @@
+CREATE TABLE `users` (
+  `id` int unsigned NOT NULL,
+  `level` tinyint NOT NULL
+) ENGINE=InnoDB;
+INSERT INTO `users` VALUES (1,-128),(2,0),(3,127);
+CREATE TABLE `orders` (
+  `id` int NOT NULL,
+  `user_id` bigint DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `orders` VALUES (1,3),(2,1),(3,NULL);
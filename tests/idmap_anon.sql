@@ -0,0 +1,10 @@
+CREATE TABLE `users` (
+  `id` int unsigned NOT NULL,
+  `level` tinyint NOT NULL
+) ENGINE=InnoDB;
+INSERT INTO `users` VALUES (2058829578,73),(2484105145,-4),(2539873611,-124);
+CREATE TABLE `orders` (
+  `id` int NOT NULL,
+  `user_id` bigint DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `orders` VALUES (1,2539873611),(2,2058829578),(3,NULL);
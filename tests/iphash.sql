@@ -0,0 +1,6 @@
+CREATE TABLE `logins` (
+  `ip` int unsigned NOT NULL,
+  `ip6` varbinary(16) DEFAULT NULL,
+  `ip_text` varchar(45) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `logins` VALUES (1572395042,0x5DB8D822,'93.184.216.34'),(167772161,0x0A000001,'10.0.0.1'),(1,NULL,'2001:db8::1');
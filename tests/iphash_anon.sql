@@ -0,0 +1,6 @@
+CREATE TABLE `logins` (
+  `ip` int unsigned NOT NULL,
+  `ip6` varbinary(16) DEFAULT NULL,
+  `ip_text` varchar(45) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `logins` VALUES (2722551715,0xA246D7A3,'162.70.215.163'),(3364765569,0x0A000001,'10.0.0.1'),(3338667905,NULL,'2001:db8::1');
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'{\"id\": 1, \"payload\": \"{\\\"email\\\": \\\"x@example.com\\\", \\\"meta\\\": \\\"{\\\\\\\"k\\\\\\\": 2}\\\"}\"}');
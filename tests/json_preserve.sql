@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'{\n  \"price\": 1.10,\n  \"email\": \"a@b.c\",\n  \"token\": \"t\",\n  \"city\": \"Kraków\"\n}');
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (42,'{\"ref\":\"x\",\"row\":\"y\",\"codes\":\"ab,cd\",\"settings\":{\"reset_token\":\"t\",\"hint\":\"h\"}}');
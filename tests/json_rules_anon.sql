@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (42,'{\"ref\":\"user-42\",\"row\":\"1-row\",\"codes\":\"a,c\",\"settings\":{\"hint\":null}}');
@@ -0,0 +1,8 @@
+CREATE TABLE `customers` (
+  `id` int NOT NULL,
+  `card` varchar(19) DEFAULT NULL,
+  `email` varchar(255) DEFAULT NULL,
+  `phone` varchar(20) DEFAULT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `customers` VALUES (1,'4111-1111-1111-1234','alice@example.com','+33 6 12 34 56 12','O\'Brien'),(2,NULL,'','12','Zoë');
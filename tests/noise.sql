@@ -0,0 +1,7 @@
+CREATE TABLE `employees` (
+  `id` int NOT NULL,
+  `salary` decimal(8,2) unsigned NOT NULL,
+  `bonus` int DEFAULT NULL,
+  `age` tinyint unsigned DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `employees` VALUES (1,52000.00,1200,254),(2,52000.00,-300,NULL),(3,0.00,0,40);
@@ -0,0 +1,7 @@
+CREATE TABLE `employees` (
+  `id` int NOT NULL,
+  `salary` decimal(8,2) unsigned NOT NULL,
+  `bonus` int DEFAULT NULL,
+  `age` tinyint unsigned DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `employees` VALUES (1,47699.55,1163,246),(2,47699.55,-234,NULL),(3,0.00,0,41);
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'a:3:{s:5:\"email\";s:5:\"a@b.c\";s:5:\"token\";s:1:\"t\";s:5:\"prefs\";O:8:\"stdClass\":1:{s:5:\"email\";s:3:\"b@c\";}}'),(2,'not serialized'),(3,'a:3:{s:5:\"token\";s:1:\"t\";s:4:\"user\";O:4:\"User\":1:{s:5:\"email\";s:3:\"c@d\";}s:6:\"author\";r:3;}');
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'a:2:{s:5:\"email\";s:16:\"zoé@example.com\";s:5:\"prefs\";O:8:\"stdClass\":1:{s:5:\"email\";s:16:\"zoé@example.com\";}}'),(2,'not serialized'),(3,'a:2:{s:4:\"user\";O:4:\"User\":1:{s:5:\"email\";s:16:\"zoé@example.com\";}s:6:\"author\";r:2;}');
@@ -0,0 +1,7 @@
+CREATE TABLE `patients` (
+  `id` int NOT NULL,
+  `zip` varchar(5) DEFAULT NULL,
+  `age` int DEFAULT NULL,
+  `diagnosis` varchar(20) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `patients` VALUES (1,'75011',34,'flu'),(2,'75018',37,'asthma'),(3,'69002',52,'flu'),(4,'69007',58,'flu');
@@ -0,0 +1,7 @@
+CREATE TABLE `patients` (
+  `id` int NOT NULL,
+  `zip` varchar(5) DEFAULT NULL,
+  `age` int DEFAULT NULL,
+  `diagnosis` varchar(20) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `patients` VALUES (1,'75000',30,'flu'),(2,'75000',30,'asthma'),(3,'69000',50,'flu'),(4,'69000',50,'flu');
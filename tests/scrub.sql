@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'Mail john@x.com, card 5555-5555-5555-4444, EMP-123\'s order 12'),(2,'Nothing here'),(4,'Login from 10.0.0.1'),(5,'Ren wrote to a@b.com'),(3,NULL);
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'Mail ejssysnpud@example.com, card 4111 1111 1111 1111, EMP-0\'s order 12'),(2,'Nothing here'),(4,'Login from \"ip\\v4\"'),(5,'Ren wrote to sjubcloado@example.com'),(3,NULL);
@@ -0,0 +1,16 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `city` varchar(64) DEFAULT NULL,
+  `zip` varchar(5) DEFAULT NULL,
+  `job` varchar(64) DEFAULT NULL
+) ENGINE=InnoDB;
+LOCK TABLES `people` WRITE;
+INSERT INTO `people` VALUES (0,'City 0','00000','Job 0'),(1,'City 1','00001','Job 1'),(2,'City 2','00002','Job 2'),(3,'City 3','00003','Job 3'),(4,'City 4','00004','Job 4'),(5,'City 5','00005','Job 5'),(6,'City 6','00006','Job 6'),(7,'City 7','00007','Job 7'),(8,'City 8','00008','Job 8'),(9,'City 9','00009','Job 9');
+INSERT INTO `people` VALUES (10,'City 10','00010','Job 10'),(11,'City 11','00011','Job 11'),(12,'City 12','00012','Job 12'),(13,'City 13','00013','Job 13'),(14,'City 14','00014','Job 14'),(15,'City 15','00015','Job 15'),(16,'City 16','00016','Job 16'),(17,'City 17','00017','Job 17'),(18,'City 18','00018','Job 18'),(19,'City 19','00019','Job 19');
+INSERT INTO `people` VALUES (20,'City 20','00020','Job 20'),(21,'City 21','00021','Job 21'),(22,'City 22','00022','Job 22'),(23,'City 23','00023','Job 23'),(24,'City 24','00024','Job 24'),(25,'City 25','00025','Job 25'),(26,'City 26','00026','Job 26'),(27,'City 27','00027','Job 27'),(28,'City 28','00028','Job 28'),(29,'City 29','00029','Job 29');
+INSERT INTO `people` VALUES (30,NULL,NULL,'O\'Brien');
+UNLOCK TABLES;
+CREATE TABLE `other` (
+  `id` int NOT NULL
+) ENGINE=InnoDB;
+INSERT INTO `other` VALUES (1);
@@ -0,0 +1,16 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `city` varchar(64) DEFAULT NULL,
+  `zip` varchar(5) DEFAULT NULL,
+  `job` varchar(64) DEFAULT NULL
+) ENGINE=InnoDB;
+LOCK TABLES `people` WRITE;
+INSERT INTO `people` VALUES (0,'City 29','00029','Job 6'),(1,'City 3','00003','Job 23'),(2,'City 14','00014','Job 28'),(3,'City 15','00015','Job 3'),(4,'City 25','00025','Job 9'),(5,'City 13','00013','Job 16'),(6,'City 6','00006','Job 27'),(7,'City 26','00026','Job 4'),(8,'City 4','00004','Job 24'),(9,'City 8','00008','Job 0');
+INSERT INTO `people` VALUES (10,'City 19','00019','O\'Brien'),(11,'City 28','00028','Job 22'),(12,'City 17','00017','Job 29'),(13,'City 2','00002','Job 12'),(14,'City 7','00007','Job 10'),(15,'City 1','00001','Job 20'),(16,'City 21','00021','Job 1'),(17,'City 5','00005','Job 26'),(18,'City 11','00011','Job 19'),(19,'City 0','00000','Job 18');
+INSERT INTO `people` VALUES (20,'City 12','00012','Job 11'),(21,'City 23','00023','Job 5'),(22,'City 22','00022','Job 15'),(23,'City 18','00018','Job 8'),(24,'City 9','00009','Job 13'),(25,NULL,NULL,'Job 21'),(26,'City 24','00024','Job 2'),(27,'City 16','00016','Job 7'),(28,'City 10','00010','Job 25'),(29,'City 27','00027','Job 14');
+INSERT INTO `people` VALUES (30,'City 20','00020','Job 17');
+UNLOCK TABLES;
+CREATE TABLE `other` (
+  `id` int NOT NULL
+) ENGINE=InnoDB;
+INSERT INTO `other` VALUES (1);
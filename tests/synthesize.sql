@@ -0,0 +1,27 @@
+CREATE TABLE `users` (
+  `id` int NOT NULL,
+  `status` varchar(16) DEFAULT NULL,
+  `age` int DEFAULT NULL,
+  `balance` decimal(8,2) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `users` VALUES (0,'closed',20,0.50),(1,'active',21,1.50),(2,'active',22,2.50),(3,'active',23,3.50),(4,'active',24,4.50),(5,'active',25,5.50),(6,'active',26,6.50),(7,'active',27,7.50),(8,'active',28,8.50),(9,'active',29,9.50),(10,'closed',30,10.50),(11,'active',31,11.50),(12,'active',32,12.50),(13,'active',33,13.50),(14,'active',34,14.50),(15,'active',35,15.50),(16,'active',36,16.50),(17,'active',37,17.50),(18,'active',38,18.50),(19,'active',39,19.50),(20,'closed',40,20.50),(21,'active',41,21.50),(22,'active',42,22.50),(23,'active',43,23.50),(24,'active',44,24.50),(25,'active',45,25.50),(26,'active',46,26.50),(27,'active',47,27.50),(28,'active',48,28.50),(29,'active',49,29.50),(30,'closed',50,30.50),(31,'active',51,31.50),(32,'active',52,32.50),(33,'active',53,33.50),(34,'active',54,34.50),(35,'active',55,35.50),(36,'active',56,36.50),(37,'active',57,37.50),(38,'active',58,38.50),(39,'active',59,39.50),(40,'closed',20,40.50),(41,'active',21,41.50),(42,'active',22,42.50),(43,'active',23,43.50),(44,'active',24,44.50),(45,'active',25,45.50),(46,'active',26,46.50),(47,'active',27,47.50),(48,'active',28,48.50),(49,'active',29,49.50),(50,'closed',30,50.50),(51,'active',31,51.50),(52,'active',32,52.50),(53,'active',33,53.50),(54,'active',34,54.50),(55,'active',35,55.50),(56,'active',36,56.50),(57,'active',37,57.50),(58,'active',38,58.50),(59,'active',39,59.50),(60,'closed',40,60.50),(61,'active',41,61.50),(62,'active',42,62.50),(63,'active',43,63.50),(64,'active',44,64.50),(65,'active',45,65.50),(66,'active',46,66.50),(67,'active',47,67.50),(68,'active',48,68.50),(69,'active',49,69.50),(70,'closed',50,70.50),(71,'active',51,71.50),(72,'active',52,72.50),(73,'active',53,73.50),(74,'active',54,74.50),(75,'active',55,75.50),(76,'active',56,76.50),(77,'active',57,77.50),(78,'active',58,78.50),(79,'active',59,79.50),(80,'closed',20,80.50),(81,'active',21,81.50),(82,'active',22,82.50),(83,'active',23,83.50),(84,'active',24,84.50),(85,'active',25,85.50),(86,'active',26,86.50),(87,'active',27,87.50),(88,'active',28,88.50),(89,'active',29,89.50),(90,'closed',30,90.50),(91,'active',31,91.50),(92,'active',32,92.50),(93,'active',33,93.50),(94,'active',34,94.50),(95,'active',35,95.50),(96,'active',36,96.50),(97,'active',37,97.50),(98,'active',38,98.50),(99,'active',39,99.50);
+INSERT INTO `users` VALUES (100,'closed',40,100.50),(101,'active',41,101.50),(102,'active',42,102.50),(103,'active',43,103.50),(104,'active',44,104.50),(105,'active',45,105.50),(106,'active',46,106.50),(107,'active',47,107.50),(108,'active',48,108.50),(109,'active',49,109.50),(110,'closed',50,110.50),(111,'active',51,111.50),(112,'active',52,112.50),(113,'active',53,113.50),(114,'active',54,114.50),(115,'active',55,115.50),(116,'active',56,116.50),(117,'active',57,117.50),(118,'active',58,118.50),(119,'active',59,119.50),(120,'closed',20,120.50),(121,'active',21,121.50),(122,'active',22,122.50),(123,'active',23,123.50),(124,'active',24,124.50),(125,'active',25,125.50),(126,'active',26,126.50),(127,'active',27,127.50),(128,'active',28,128.50),(129,'active',29,129.50),(130,'closed',30,130.50),(131,'active',31,131.50),(132,'active',32,132.50),(133,'active',33,133.50),(134,'active',34,134.50),(135,'active',35,135.50),(136,'active',36,136.50),(137,'active',37,137.50),(138,'active',38,138.50),(139,'active',39,139.50),(140,'closed',40,140.50),(141,'active',41,141.50),(142,'active',42,142.50),(143,'active',43,143.50),(144,'active',44,144.50),(145,'active',45,145.50),(146,'active',46,146.50),(147,'active',47,147.50),(148,'active',48,148.50),(149,'active',49,149.50),(150,'closed',50,150.50),(151,'active',51,151.50),(152,'active',52,152.50),(153,'active',53,153.50),(154,'active',54,154.50),(155,'active',55,155.50),(156,'active',56,156.50),(157,'active',57,157.50),(158,'active',58,158.50),(159,'active',59,159.50),(160,'closed',20,160.50),(161,'active',21,161.50),(162,'active',22,162.50),(163,'active',23,163.50),(164,'active',24,164.50),(165,'active',25,165.50),(166,'active',26,166.50),(167,'active',27,167.50),(168,'active',28,168.50),(169,'active',29,169.50),(170,'closed',30,170.50),(171,'active',31,171.50),(172,'active',32,172.50),(173,'active',33,173.50),(174,'active',34,174.50),(175,'active',35,175.50),(176,'active',36,176.50),(177,'active',37,177.50),(178,'active',38,178.50),(179,'active',39,179.50),(180,'closed',40,180.50),(181,'active',41,181.50),(182,'active',42,182.50),(183,'active',43,183.50),(184,'active',44,184.50),(185,'active',45,185.50),(186,'active',46,186.50),(187,'active',47,187.50),(188,'active',48,188.50),(189,'active',49,189.50),(190,'closed',50,190.50),(191,'active',51,191.50),(192,'active',52,192.50),(193,'active',53,193.50),(194,'active',54,194.50),(195,'active',55,195.50),(196,'active',56,196.50),(197,'active',57,197.50),(198,'active',58,198.50),(199,'active',59,199.50);
+INSERT INTO `users` VALUES (200,'closed',20,200.50),(201,'active',21,201.50),(202,'active',22,202.50),(203,'active',23,203.50),(204,'active',24,204.50),(205,'active',25,205.50),(206,'active',26,206.50),(207,'active',27,207.50),(208,'active',28,208.50),(209,'active',29,209.50),(210,'closed',30,210.50),(211,'active',31,211.50),(212,'active',32,212.50),(213,'active',33,213.50),(214,'active',34,214.50),(215,'active',35,215.50),(216,'active',36,216.50),(217,'active',37,217.50),(218,'active',38,218.50),(219,'active',39,219.50),(220,'closed',40,220.50),(221,'active',41,221.50),(222,'active',42,222.50),(223,'active',43,223.50),(224,'active',44,224.50),(225,'active',45,225.50),(226,'active',46,226.50),(227,'active',47,227.50),(228,'active',48,228.50),(229,'active',49,229.50),(230,'closed',50,230.50),(231,'active',51,231.50),(232,'active',52,232.50),(233,'active',53,233.50),(234,'active',54,234.50),(235,'active',55,235.50),(236,'active',56,236.50),(237,'active',57,237.50),(238,'active',58,238.50),(239,'active',59,239.50),(240,'closed',20,240.50),(241,'active',21,241.50),(242,'active',22,242.50),(243,'active',23,243.50),(244,'active',24,244.50),(245,'active',25,245.50),(246,'active',26,246.50),(247,'active',27,247.50),(248,'active',28,248.50),(249,'active',29,249.50),(250,'closed',30,250.50),(251,'active',31,251.50),(252,'active',32,252.50),(253,'active',33,253.50),(254,'active',34,254.50),(255,'active',35,255.50),(256,'active',36,256.50),(257,'active',37,257.50),(258,'active',38,258.50),(259,'active',39,259.50),(260,'closed',40,260.50),(261,'active',41,261.50),(262,'active',42,262.50),(263,'active',43,263.50),(264,'active',44,264.50),(265,'active',45,265.50),(266,'active',46,266.50),(267,'active',47,267.50),(268,'active',48,268.50),(269,'active',49,269.50),(270,'closed',50,270.50),(271,'active',51,271.50),(272,'active',52,272.50),(273,'active',53,273.50),(274,'active',54,274.50),(275,'active',55,275.50),(276,'active',56,276.50),(277,'active',57,277.50),(278,'active',58,278.50),(279,'active',59,279.50),(280,'closed',20,280.50),(281,'active',21,281.50),(282,'active',22,282.50),(283,'active',23,283.50),(284,'active',24,284.50),(285,'active',25,285.50),(286,'active',26,286.50),(287,'active',27,287.50),(288,'active',28,288.50),(289,'active',29,289.50),(290,'closed',30,290.50),(291,'active',31,291.50),(292,'active',32,292.50),(293,'active',33,293.50),(294,'active',34,294.50),(295,'active',35,295.50),(296,'active',36,296.50),(297,'active',37,297.50),(298,'active',38,298.50),(299,'active',39,299.50);
+INSERT INTO `users` VALUES (300,'closed',40,300.50),(301,'active',41,301.50),(302,'active',42,302.50),(303,'active',43,303.50),(304,'active',44,304.50),(305,'active',45,305.50),(306,'active',46,306.50),(307,'active',47,307.50),(308,'active',48,308.50),(309,'active',49,309.50),(310,'closed',50,310.50),(311,'active',51,311.50),(312,'active',52,312.50),(313,'active',53,313.50),(314,'active',54,314.50),(315,'active',55,315.50),(316,'active',56,316.50),(317,'active',57,317.50),(318,'active',58,318.50),(319,'active',59,319.50),(320,'closed',20,320.50),(321,'active',21,321.50),(322,'active',22,322.50),(323,'active',23,323.50),(324,'active',24,324.50),(325,'active',25,325.50),(326,'active',26,326.50),(327,'active',27,327.50),(328,'active',28,328.50),(329,'active',29,329.50),(330,'closed',30,330.50),(331,'active',31,331.50),(332,'active',32,332.50),(333,'active',33,333.50),(334,'active',34,334.50),(335,'active',35,335.50),(336,'active',36,336.50),(337,'active',37,337.50),(338,'active',38,338.50),(339,'active',39,339.50),(340,'closed',40,340.50),(341,'active',41,341.50),(342,'active',42,342.50),(343,'active',43,343.50),(344,'active',44,344.50),(345,'active',45,345.50),(346,'active',46,346.50),(347,'active',47,347.50),(348,'active',48,348.50),(349,'active',49,349.50),(350,'closed',50,350.50),(351,'active',51,351.50),(352,'active',52,352.50),(353,'active',53,353.50),(354,'active',54,354.50),(355,'active',55,355.50),(356,'active',56,356.50),(357,'active',57,357.50),(358,'active',58,358.50),(359,'active',59,359.50),(360,'closed',20,360.50),(361,'active',21,361.50),(362,'active',22,362.50),(363,'active',23,363.50),(364,'active',24,364.50),(365,'active',25,365.50),(366,'active',26,366.50),(367,'active',27,367.50),(368,'active',28,368.50),(369,'active',29,369.50),(370,'closed',30,370.50),(371,'active',31,371.50),(372,'active',32,372.50),(373,'active',33,373.50),(374,'active',34,374.50),(375,'active',35,375.50),(376,'active',36,376.50),(377,'active',37,377.50),(378,'active',38,378.50),(379,'active',39,379.50),(380,'closed',40,380.50),(381,'active',41,381.50),(382,'active',42,382.50),(383,'active',43,383.50),(384,'active',44,384.50),(385,'active',45,385.50),(386,'active',46,386.50),(387,'active',47,387.50),(388,'active',48,388.50),(389,'active',49,389.50),(390,'closed',50,390.50),(391,'active',51,391.50),(392,'active',52,392.50),(393,'active',53,393.50),(394,'active',54,394.50),(395,'active',55,395.50),(396,'active',56,396.50),(397,'active',57,397.50),(398,'active',58,398.50),(399,'active',59,399.50);
+INSERT INTO `users` VALUES (400,'closed',20,400.50),(401,'active',21,401.50),(402,'active',22,402.50),(403,'active',23,403.50),(404,'active',24,404.50),(405,'active',25,405.50),(406,'active',26,406.50),(407,'active',27,407.50),(408,'active',28,408.50),(409,'active',29,409.50),(410,'closed',30,410.50),(411,'active',31,411.50),(412,'active',32,412.50),(413,'active',33,413.50),(414,'active',34,414.50),(415,'active',35,415.50),(416,'active',36,416.50),(417,'active',37,417.50),(418,'active',38,418.50),(419,'active',39,419.50),(420,'closed',40,420.50),(421,'active',41,421.50),(422,'active',42,422.50),(423,'active',43,423.50),(424,'active',44,424.50),(425,'active',45,425.50),(426,'active',46,426.50),(427,'active',47,427.50),(428,'active',48,428.50),(429,'active',49,429.50),(430,'closed',50,430.50),(431,'active',51,431.50),(432,'active',52,432.50),(433,'active',53,433.50),(434,'active',54,434.50),(435,'active',55,435.50),(436,'active',56,436.50),(437,'active',57,437.50),(438,'active',58,438.50),(439,'active',59,439.50),(440,'closed',20,440.50),(441,'active',21,441.50),(442,'active',22,442.50),(443,'active',23,443.50),(444,'active',24,444.50),(445,'active',25,445.50),(446,'active',26,446.50),(447,'active',27,447.50),(448,'active',28,448.50),(449,'active',29,449.50),(450,'closed',30,450.50),(451,'active',31,451.50),(452,'active',32,452.50),(453,'active',33,453.50),(454,'active',34,454.50),(455,'active',35,455.50),(456,'active',36,456.50),(457,'active',37,457.50),(458,'active',38,458.50),(459,'active',39,459.50),(460,'closed',40,460.50),(461,'active',41,461.50),(462,'active',42,462.50),(463,'active',43,463.50),(464,'active',44,464.50),(465,'active',45,465.50),(466,'active',46,466.50),(467,'active',47,467.50),(468,'active',48,468.50),(469,'active',49,469.50),(470,'closed',50,470.50),(471,'active',51,471.50),(472,'active',52,472.50),(473,'active',53,473.50),(474,'active',54,474.50),(475,'active',55,475.50),(476,'active',56,476.50),(477,'active',57,477.50),(478,'active',58,478.50),(479,'active',59,479.50),(480,'closed',20,480.50),(481,'active',21,481.50),(482,'active',22,482.50),(483,'active',23,483.50),(484,'active',24,484.50),(485,'active',25,485.50),(486,'active',26,486.50),(487,'active',27,487.50),(488,'active',28,488.50),(489,'active',29,489.50),(490,'closed',30,490.50),(491,'active',31,491.50),(492,'active',32,492.50),(493,'active',33,493.50),(494,'active',34,494.50),(495,'active',35,495.50),(496,'active',36,496.50),(497,'active',37,497.50),(498,'active',38,498.50),(499,'active',39,499.50);
+INSERT INTO `users` VALUES (500,'closed',40,500.50),(501,'active',41,501.50),(502,'active',42,502.50),(503,'active',43,503.50),(504,'active',44,504.50),(505,'active',45,505.50),(506,'active',46,506.50),(507,'active',47,507.50),(508,'active',48,508.50),(509,'active',49,509.50),(510,'closed',50,510.50),(511,'active',51,511.50),(512,'active',52,512.50),(513,'active',53,513.50),(514,'active',54,514.50),(515,'active',55,515.50),(516,'active',56,516.50),(517,'active',57,517.50),(518,'active',58,518.50),(519,'active',59,519.50),(520,'closed',20,520.50),(521,'active',21,521.50),(522,'active',22,522.50),(523,'active',23,523.50),(524,'active',24,524.50),(525,'active',25,525.50),(526,'active',26,526.50),(527,'active',27,527.50),(528,'active',28,528.50),(529,'active',29,529.50),(530,'closed',30,530.50),(531,'active',31,531.50),(532,'active',32,532.50),(533,'active',33,533.50),(534,'active',34,534.50),(535,'active',35,535.50),(536,'active',36,536.50),(537,'active',37,537.50),(538,'active',38,538.50),(539,'active',39,539.50),(540,'closed',40,540.50),(541,'active',41,541.50),(542,'active',42,542.50),(543,'active',43,543.50),(544,'active',44,544.50),(545,'active',45,545.50),(546,'active',46,546.50),(547,'active',47,547.50),(548,'active',48,548.50),(549,'active',49,549.50),(550,'closed',50,550.50),(551,'active',51,551.50),(552,'active',52,552.50),(553,'active',53,553.50),(554,'active',54,554.50),(555,'active',55,555.50),(556,'active',56,556.50),(557,'active',57,557.50),(558,'active',58,558.50),(559,'active',59,559.50),(560,'closed',20,560.50),(561,'active',21,561.50),(562,'active',22,562.50),(563,'active',23,563.50),(564,'active',24,564.50),(565,'active',25,565.50),(566,'active',26,566.50),(567,'active',27,567.50),(568,'active',28,568.50),(569,'active',29,569.50),(570,'closed',30,570.50),(571,'active',31,571.50),(572,'active',32,572.50),(573,'active',33,573.50),(574,'active',34,574.50),(575,'active',35,575.50),(576,'active',36,576.50),(577,'active',37,577.50),(578,'active',38,578.50),(579,'active',39,579.50),(580,'closed',40,580.50),(581,'active',41,581.50),(582,'active',42,582.50),(583,'active',43,583.50),(584,'active',44,584.50),(585,'active',45,585.50),(586,'active',46,586.50),(587,'active',47,587.50),(588,'active',48,588.50),(589,'active',49,589.50),(590,'closed',50,590.50),(591,'active',51,591.50),(592,'active',52,592.50),(593,'active',53,593.50),(594,'active',54,594.50),(595,'active',55,595.50),(596,'active',56,596.50),(597,'active',57,597.50),(598,'active',58,598.50),(599,'active',59,599.50);
+INSERT INTO `users` VALUES (600,'closed',20,600.50),(601,'active',21,601.50),(602,'active',22,602.50),(603,'active',23,603.50),(604,'active',24,604.50),(605,'active',25,605.50),(606,'active',26,606.50),(607,'active',27,607.50),(608,'active',28,608.50),(609,'active',29,609.50),(610,'closed',30,610.50),(611,'active',31,611.50),(612,'active',32,612.50),(613,'active',33,613.50),(614,'active',34,614.50),(615,'active',35,615.50),(616,'active',36,616.50),(617,'active',37,617.50),(618,'active',38,618.50),(619,'active',39,619.50),(620,'closed',40,620.50),(621,'active',41,621.50),(622,'active',42,622.50),(623,'active',43,623.50),(624,'active',44,624.50),(625,'active',45,625.50),(626,'active',46,626.50),(627,'active',47,627.50),(628,'active',48,628.50),(629,'active',49,629.50),(630,'closed',50,630.50),(631,'active',51,631.50),(632,'active',52,632.50),(633,'active',53,633.50),(634,'active',54,634.50),(635,'active',55,635.50),(636,'active',56,636.50),(637,'active',57,637.50),(638,'active',58,638.50),(639,'active',59,639.50),(640,'closed',20,640.50),(641,'active',21,641.50),(642,'active',22,642.50),(643,'active',23,643.50),(644,'active',24,644.50),(645,'active',25,645.50),(646,'active',26,646.50),(647,'active',27,647.50),(648,'active',28,648.50),(649,'active',29,649.50),(650,'closed',30,650.50),(651,'active',31,651.50),(652,'active',32,652.50),(653,'active',33,653.50),(654,'active',34,654.50),(655,'active',35,655.50),(656,'active',36,656.50),(657,'active',37,657.50),(658,'active',38,658.50),(659,'active',39,659.50),(660,'closed',40,660.50),(661,'active',41,661.50),(662,'active',42,662.50),(663,'active',43,663.50),(664,'active',44,664.50),(665,'active',45,665.50),(666,'active',46,666.50),(667,'active',47,667.50),(668,'active',48,668.50),(669,'active',49,669.50),(670,'closed',50,670.50),(671,'active',51,671.50),(672,'active',52,672.50),(673,'active',53,673.50),(674,'active',54,674.50),(675,'active',55,675.50),(676,'active',56,676.50),(677,'active',57,677.50),(678,'active',58,678.50),(679,'active',59,679.50),(680,'closed',20,680.50),(681,'active',21,681.50),(682,'active',22,682.50),(683,'active',23,683.50),(684,'active',24,684.50),(685,'active',25,685.50),(686,'active',26,686.50),(687,'active',27,687.50),(688,'active',28,688.50),(689,'active',29,689.50),(690,'closed',30,690.50),(691,'active',31,691.50),(692,'active',32,692.50),(693,'active',33,693.50),(694,'active',34,694.50),(695,'active',35,695.50),(696,'active',36,696.50),(697,'active',37,697.50),(698,'active',38,698.50),(699,'active',39,699.50);
+INSERT INTO `users` VALUES (700,'closed',40,700.50),(701,'active',41,701.50),(702,'active',42,702.50),(703,'active',43,703.50),(704,'active',44,704.50),(705,'active',45,705.50),(706,'active',46,706.50),(707,'active',47,707.50),(708,'active',48,708.50),(709,'active',49,709.50),(710,'closed',50,710.50),(711,'active',51,711.50),(712,'active',52,712.50),(713,'active',53,713.50),(714,'active',54,714.50),(715,'active',55,715.50),(716,'active',56,716.50),(717,'active',57,717.50),(718,'active',58,718.50),(719,'active',59,719.50),(720,'closed',20,720.50),(721,'active',21,721.50),(722,'active',22,722.50),(723,'active',23,723.50),(724,'active',24,724.50),(725,'active',25,725.50),(726,'active',26,726.50),(727,'active',27,727.50),(728,'active',28,728.50),(729,'active',29,729.50),(730,'closed',30,730.50),(731,'active',31,731.50),(732,'active',32,732.50),(733,'active',33,733.50),(734,'active',34,734.50),(735,'active',35,735.50),(736,'active',36,736.50),(737,'active',37,737.50),(738,'active',38,738.50),(739,'active',39,739.50),(740,'closed',40,740.50),(741,'active',41,741.50),(742,'active',42,742.50),(743,'active',43,743.50),(744,'active',44,744.50),(745,'active',45,745.50),(746,'active',46,746.50),(747,'active',47,747.50),(748,'active',48,748.50),(749,'active',49,749.50),(750,'closed',50,750.50),(751,'active',51,751.50),(752,'active',52,752.50),(753,'active',53,753.50),(754,'active',54,754.50),(755,'active',55,755.50),(756,'active',56,756.50),(757,'active',57,757.50),(758,'active',58,758.50),(759,'active',59,759.50),(760,'closed',20,760.50),(761,'active',21,761.50),(762,'active',22,762.50),(763,'active',23,763.50),(764,'active',24,764.50),(765,'active',25,765.50),(766,'active',26,766.50),(767,'active',27,767.50),(768,'active',28,768.50),(769,'active',29,769.50),(770,'closed',30,770.50),(771,'active',31,771.50),(772,'active',32,772.50),(773,'active',33,773.50),(774,'active',34,774.50),(775,'active',35,775.50),(776,'active',36,776.50),(777,'active',37,777.50),(778,'active',38,778.50),(779,'active',39,779.50),(780,'closed',40,780.50),(781,'active',41,781.50),(782,'active',42,782.50),(783,'active',43,783.50),(784,'active',44,784.50),(785,'active',45,785.50),(786,'active',46,786.50),(787,'active',47,787.50),(788,'active',48,788.50),(789,'active',49,789.50),(790,'closed',50,790.50),(791,'active',51,791.50),(792,'active',52,792.50),(793,'active',53,793.50),(794,'active',54,794.50),(795,'active',55,795.50),(796,'active',56,796.50),(797,'active',57,797.50),(798,'active',58,798.50),(799,'active',59,799.50);
+INSERT INTO `users` VALUES (800,'closed',20,800.50),(801,'active',21,801.50),(802,'active',22,802.50),(803,'active',23,803.50),(804,'active',24,804.50),(805,'active',25,805.50),(806,'active',26,806.50),(807,'active',27,807.50),(808,'active',28,808.50),(809,'active',29,809.50),(810,'closed',30,810.50),(811,'active',31,811.50),(812,'active',32,812.50),(813,'active',33,813.50),(814,'active',34,814.50),(815,'active',35,815.50),(816,'active',36,816.50),(817,'active',37,817.50),(818,'active',38,818.50),(819,'active',39,819.50),(820,'closed',40,820.50),(821,'active',41,821.50),(822,'active',42,822.50),(823,'active',43,823.50),(824,'active',44,824.50),(825,'active',45,825.50),(826,'active',46,826.50),(827,'active',47,827.50),(828,'active',48,828.50),(829,'active',49,829.50),(830,'closed',50,830.50),(831,'active',51,831.50),(832,'active',52,832.50),(833,'active',53,833.50),(834,'active',54,834.50),(835,'active',55,835.50),(836,'active',56,836.50),(837,'active',57,837.50),(838,'active',58,838.50),(839,'active',59,839.50),(840,'closed',20,840.50),(841,'active',21,841.50),(842,'active',22,842.50),(843,'active',23,843.50),(844,'active',24,844.50),(845,'active',25,845.50),(846,'active',26,846.50),(847,'active',27,847.50),(848,'active',28,848.50),(849,'active',29,849.50),(850,'closed',30,850.50),(851,'active',31,851.50),(852,'active',32,852.50),(853,'active',33,853.50),(854,'active',34,854.50),(855,'active',35,855.50),(856,'active',36,856.50),(857,'active',37,857.50),(858,'active',38,858.50),(859,'active',39,859.50),(860,'closed',40,860.50),(861,'active',41,861.50),(862,'active',42,862.50),(863,'active',43,863.50),(864,'active',44,864.50),(865,'active',45,865.50),(866,'active',46,866.50),(867,'active',47,867.50),(868,'active',48,868.50),(869,'active',49,869.50),(870,'closed',50,870.50),(871,'active',51,871.50),(872,'active',52,872.50),(873,'active',53,873.50),(874,'active',54,874.50),(875,'active',55,875.50),(876,'active',56,876.50),(877,'active',57,877.50),(878,'active',58,878.50),(879,'active',59,879.50),(880,'closed',20,880.50),(881,'active',21,881.50),(882,'active',22,882.50),(883,'active',23,883.50),(884,'active',24,884.50),(885,'active',25,885.50),(886,'active',26,886.50),(887,'active',27,887.50),(888,'active',28,888.50),(889,'active',29,889.50),(890,'closed',30,890.50),(891,'active',31,891.50),(892,'active',32,892.50),(893,'active',33,893.50),(894,'active',34,894.50),(895,'active',35,895.50),(896,'active',36,896.50),(897,'active',37,897.50),(898,'active',38,898.50),(899,'active',39,899.50);
+INSERT INTO `users` VALUES (900,'closed',40,900.50),(901,'active',41,901.50),(902,'active',42,902.50),(903,'active',43,903.50),(904,'active',44,904.50),(905,'active',45,905.50),(906,'active',46,906.50),(907,'active',47,907.50),(908,'active',48,908.50),(909,'active',49,909.50),(910,'closed',50,910.50),(911,'active',51,911.50),(912,'active',52,912.50),(913,'active',53,913.50),(914,'active',54,914.50),(915,'active',55,915.50),(916,'active',56,916.50),(917,'active',57,917.50),(918,'active',58,918.50),(919,'active',59,919.50),(920,'closed',20,920.50),(921,'active',21,921.50),(922,'active',22,922.50),(923,'active',23,923.50),(924,'active',24,924.50),(925,'active',25,925.50),(926,'active',26,926.50),(927,'active',27,927.50),(928,'active',28,928.50),(929,'active',29,929.50),(930,'closed',30,930.50),(931,'active',31,931.50),(932,'active',32,932.50),(933,'active',33,933.50),(934,'active',34,934.50),(935,'active',35,935.50),(936,'active',36,936.50),(937,'active',37,937.50),(938,'active',38,938.50),(939,'active',39,939.50),(940,'closed',40,940.50),(941,'active',41,941.50),(942,'active',42,942.50),(943,'active',43,943.50),(944,'active',44,944.50),(945,'active',45,945.50),(946,'active',46,946.50),(947,'active',47,947.50),(948,'active',48,948.50),(949,'active',49,949.50),(950,'closed',50,950.50),(951,'active',51,951.50),(952,'active',52,952.50),(953,'active',53,953.50),(954,'active',54,954.50),(955,'active',55,955.50),(956,'active',56,956.50),(957,'active',57,957.50),(958,'active',58,958.50),(959,'active',59,959.50),(960,'closed',20,960.50),(961,'active',21,961.50),(962,'active',22,962.50),(963,'active',23,963.50),(964,'active',24,964.50),(965,'active',25,965.50),(966,'active',26,966.50),(967,'active',27,967.50),(968,'active',28,968.50),(969,'active',29,969.50),(970,'closed',30,970.50),(971,'active',31,971.50),(972,'active',32,972.50),(973,'active',33,973.50),(974,'active',34,974.50),(975,'active',35,975.50),(976,'active',36,976.50),(977,'active',37,977.50),(978,'active',38,978.50),(979,'active',39,979.50),(980,'closed',40,980.50),(981,'active',41,981.50),(982,'active',42,982.50),(983,'active',43,983.50),(984,'active',44,984.50),(985,'active',45,985.50),(986,'active',46,986.50),(987,'active',47,987.50),(988,'active',48,988.50),(989,'active',49,989.50),(990,'closed',50,990.50),(991,'active',51,991.50),(992,'active',52,992.50),(993,'active',53,993.50),(994,'active',54,994.50),(995,'active',55,995.50),(996,'active',56,996.50),(997,'active',57,997.50),(998,'active',58,998.50),(999,'active',59,999.50);
+INSERT INTO `users` VALUES (1000,'closed',20,1000.50),(1001,'active',21,1001.50),(1002,'active',22,1002.50),(1003,'active',23,1003.50),(1004,'active',24,1004.50),(1005,'active',25,1005.50),(1006,'active',26,1006.50),(1007,'active',27,1007.50),(1008,'active',28,1008.50),(1009,'active',29,1009.50),(1010,'closed',30,1010.50),(1011,'active',31,1011.50),(1012,'active',32,1012.50),(1013,'active',33,1013.50),(1014,'active',34,1014.50),(1015,'active',35,1015.50),(1016,'active',36,1016.50),(1017,'active',37,1017.50),(1018,'active',38,1018.50),(1019,'active',39,1019.50),(1020,'closed',40,1020.50),(1021,'active',41,1021.50),(1022,'active',42,1022.50),(1023,'active',43,1023.50),(1024,'active',44,1024.50),(1025,'active',45,1025.50),(1026,'active',46,1026.50),(1027,'active',47,1027.50),(1028,'active',48,1028.50),(1029,'active',49,1029.50),(1030,'closed',50,1030.50),(1031,'active',51,1031.50),(1032,'active',52,1032.50),(1033,'active',53,1033.50),(1034,'active',54,1034.50),(1035,'active',55,1035.50),(1036,'active',56,1036.50),(1037,'active',57,1037.50),(1038,'active',58,1038.50),(1039,'active',59,1039.50),(1040,'closed',20,1040.50),(1041,'active',21,1041.50),(1042,'active',22,1042.50),(1043,'active',23,1043.50),(1044,'active',24,1044.50),(1045,'active',25,1045.50),(1046,'active',26,1046.50),(1047,'active',27,1047.50),(1048,'active',28,1048.50),(1049,'active',29,1049.50),(1050,'closed',30,1050.50),(1051,'active',31,1051.50),(1052,'active',32,1052.50),(1053,'active',33,1053.50),(1054,'active',34,1054.50),(1055,'active',35,1055.50),(1056,'active',36,1056.50),(1057,'active',37,1057.50),(1058,'active',38,1058.50),(1059,'active',39,1059.50),(1060,'closed',40,1060.50),(1061,'active',41,1061.50),(1062,'active',42,1062.50),(1063,'active',43,1063.50),(1064,'active',44,1064.50),(1065,'active',45,1065.50),(1066,'active',46,1066.50),(1067,'active',47,1067.50),(1068,'active',48,1068.50),(1069,'active',49,1069.50),(1070,'closed',50,1070.50),(1071,'active',51,1071.50),(1072,'active',52,1072.50),(1073,'active',53,1073.50),(1074,'active',54,1074.50),(1075,'active',55,1075.50),(1076,'active',56,1076.50),(1077,'active',57,1077.50),(1078,'active',58,1078.50),(1079,'active',59,1079.50),(1080,'closed',20,1080.50),(1081,'active',21,1081.50),(1082,'active',22,1082.50),(1083,'active',23,1083.50),(1084,'active',24,1084.50),(1085,'active',25,1085.50),(1086,'active',26,1086.50),(1087,'active',27,1087.50),(1088,'active',28,1088.50),(1089,'active',29,1089.50),(1090,'closed',30,1090.50),(1091,'active',31,1091.50),(1092,'active',32,1092.50),(1093,'active',33,1093.50),(1094,'active',34,1094.50),(1095,'active',35,1095.50),(1096,'active',36,1096.50),(1097,'active',37,1097.50),(1098,'active',38,1098.50),(1099,'active',39,1099.50);
+INSERT INTO `users` VALUES (1100,'closed',40,1100.50),(1101,'active',41,1101.50),(1102,'active',42,1102.50),(1103,'active',43,1103.50),(1104,'active',44,1104.50),(1105,'active',45,1105.50),(1106,'active',46,1106.50),(1107,'active',47,1107.50),(1108,'active',48,1108.50),(1109,'active',49,1109.50),(1110,'closed',50,1110.50),(1111,'active',51,1111.50),(1112,'active',52,1112.50),(1113,'active',53,1113.50),(1114,'active',54,1114.50),(1115,'active',55,1115.50),(1116,'active',56,1116.50),(1117,'active',57,1117.50),(1118,'active',58,1118.50),(1119,'active',59,1119.50),(1120,'closed',20,1120.50),(1121,'active',21,1121.50),(1122,'active',22,1122.50),(1123,'active',23,1123.50),(1124,'active',24,1124.50),(1125,'active',25,1125.50),(1126,'active',26,1126.50),(1127,'active',27,1127.50),(1128,'active',28,1128.50),(1129,'active',29,1129.50),(1130,'closed',30,1130.50),(1131,'active',31,1131.50),(1132,'active',32,1132.50),(1133,'active',33,1133.50),(1134,'active',34,1134.50),(1135,'active',35,1135.50),(1136,'active',36,1136.50),(1137,'active',37,1137.50),(1138,'active',38,1138.50),(1139,'active',39,1139.50),(1140,'closed',40,1140.50),(1141,'active',41,1141.50),(1142,'active',42,1142.50),(1143,'active',43,1143.50),(1144,'active',44,1144.50),(1145,'active',45,1145.50),(1146,'active',46,1146.50),(1147,'active',47,1147.50),(1148,'active',48,1148.50),(1149,'active',49,1149.50),(1150,'closed',50,1150.50),(1151,'active',51,1151.50),(1152,'active',52,1152.50),(1153,'active',53,1153.50),(1154,'active',54,1154.50),(1155,'active',55,1155.50),(1156,'active',56,1156.50),(1157,'active',57,1157.50),(1158,'active',58,1158.50),(1159,'active',59,1159.50),(1160,'closed',20,1160.50),(1161,'active',21,1161.50),(1162,'active',22,1162.50),(1163,'active',23,1163.50),(1164,'active',24,1164.50),(1165,'active',25,1165.50),(1166,'active',26,1166.50),(1167,'active',27,1167.50),(1168,'active',28,1168.50),(1169,'active',29,1169.50),(1170,'closed',30,1170.50),(1171,'active',31,1171.50),(1172,'active',32,1172.50),(1173,'active',33,1173.50),(1174,'active',34,1174.50),(1175,'active',35,1175.50),(1176,'active',36,1176.50),(1177,'active',37,1177.50),(1178,'active',38,1178.50),(1179,'active',39,1179.50),(1180,'closed',40,1180.50),(1181,'active',41,1181.50),(1182,'active',42,1182.50),(1183,'active',43,1183.50),(1184,'active',44,1184.50),(1185,'active',45,1185.50),(1186,'active',46,1186.50),(1187,'active',47,1187.50),(1188,'active',48,1188.50),(1189,'active',49,1189.50),(1190,'closed',50,1190.50),(1191,'active',51,1191.50),(1192,'active',52,1192.50),(1193,'active',53,1193.50),(1194,'active',54,1194.50),(1195,'active',55,1195.50),(1196,'active',56,1196.50),(1197,'active',57,1197.50),(1198,'active',58,1198.50),(1199,'active',59,1199.50);
+INSERT INTO `users` VALUES (1200,'closed',20,1200.50),(1201,'active',21,1201.50),(1202,'active',22,1202.50),(1203,'active',23,1203.50),(1204,'active',24,1204.50),(1205,'active',25,1205.50),(1206,'active',26,1206.50),(1207,'active',27,1207.50),(1208,'active',28,1208.50),(1209,'active',29,1209.50),(1210,'closed',30,1210.50),(1211,'active',31,1211.50),(1212,'active',32,1212.50),(1213,'active',33,1213.50),(1214,'active',34,1214.50),(1215,'active',35,1215.50),(1216,'active',36,1216.50),(1217,'active',37,1217.50),(1218,'active',38,1218.50),(1219,'active',39,1219.50),(1220,'closed',40,1220.50),(1221,'active',41,1221.50),(1222,'active',42,1222.50),(1223,'active',43,1223.50),(1224,'active',44,1224.50),(1225,'active',45,1225.50),(1226,'active',46,1226.50),(1227,'active',47,1227.50),(1228,'active',48,1228.50),(1229,'active',49,1229.50),(1230,'closed',50,1230.50),(1231,'active',51,1231.50),(1232,'active',52,1232.50),(1233,'active',53,1233.50),(1234,'active',54,1234.50),(1235,'active',55,1235.50),(1236,'active',56,1236.50),(1237,'active',57,1237.50),(1238,'active',58,1238.50),(1239,'active',59,1239.50),(1240,'closed',20,1240.50),(1241,'active',21,1241.50),(1242,'active',22,1242.50),(1243,'active',23,1243.50),(1244,'active',24,1244.50),(1245,'active',25,1245.50),(1246,'active',26,1246.50),(1247,'active',27,1247.50),(1248,'active',28,1248.50),(1249,'active',29,1249.50),(1250,'closed',30,1250.50),(1251,'active',31,1251.50),(1252,'active',32,1252.50),(1253,'active',33,1253.50),(1254,'active',34,1254.50),(1255,'active',35,1255.50),(1256,'active',36,1256.50),(1257,'active',37,1257.50),(1258,'active',38,1258.50),(1259,'active',39,1259.50),(1260,'closed',40,1260.50),(1261,'active',41,1261.50),(1262,'active',42,1262.50),(1263,'active',43,1263.50),(1264,'active',44,1264.50),(1265,'active',45,1265.50),(1266,'active',46,1266.50),(1267,'active',47,1267.50),(1268,'active',48,1268.50),(1269,'active',49,1269.50),(1270,'closed',50,1270.50),(1271,'active',51,1271.50),(1272,'active',52,1272.50),(1273,'active',53,1273.50),(1274,'active',54,1274.50),(1275,'active',55,1275.50),(1276,'active',56,1276.50),(1277,'active',57,1277.50),(1278,'active',58,1278.50),(1279,'active',59,1279.50),(1280,'closed',20,1280.50),(1281,'active',21,1281.50),(1282,'active',22,1282.50),(1283,'active',23,1283.50),(1284,'active',24,1284.50),(1285,'active',25,1285.50),(1286,'active',26,1286.50),(1287,'active',27,1287.50),(1288,'active',28,1288.50),(1289,'active',29,1289.50),(1290,'closed',30,1290.50),(1291,'active',31,1291.50),(1292,'active',32,1292.50),(1293,'active',33,1293.50),(1294,'active',34,1294.50),(1295,'active',35,1295.50),(1296,'active',36,1296.50),(1297,'active',37,1297.50),(1298,'active',38,1298.50),(1299,'active',39,1299.50);
+INSERT INTO `users` VALUES (1300,'closed',40,1300.50),(1301,'active',41,1301.50),(1302,'active',42,1302.50),(1303,'active',43,1303.50),(1304,'active',44,1304.50),(1305,'active',45,1305.50),(1306,'active',46,1306.50),(1307,'active',47,1307.50),(1308,'active',48,1308.50),(1309,'active',49,1309.50),(1310,'closed',50,1310.50),(1311,'active',51,1311.50),(1312,'active',52,1312.50),(1313,'active',53,1313.50),(1314,'active',54,1314.50),(1315,'active',55,1315.50),(1316,'active',56,1316.50),(1317,'active',57,1317.50),(1318,'active',58,1318.50),(1319,'active',59,1319.50),(1320,'closed',20,1320.50),(1321,'active',21,1321.50),(1322,'active',22,1322.50),(1323,'active',23,1323.50),(1324,'active',24,1324.50),(1325,'active',25,1325.50),(1326,'active',26,1326.50),(1327,'active',27,1327.50),(1328,'active',28,1328.50),(1329,'active',29,1329.50),(1330,'closed',30,1330.50),(1331,'active',31,1331.50),(1332,'active',32,1332.50),(1333,'active',33,1333.50),(1334,'active',34,1334.50),(1335,'active',35,1335.50),(1336,'active',36,1336.50),(1337,'active',37,1337.50),(1338,'active',38,1338.50),(1339,'active',39,1339.50),(1340,'closed',40,1340.50),(1341,'active',41,1341.50),(1342,'active',42,1342.50),(1343,'active',43,1343.50),(1344,'active',44,1344.50),(1345,'active',45,1345.50),(1346,'active',46,1346.50),(1347,'active',47,1347.50),(1348,'active',48,1348.50),(1349,'active',49,1349.50),(1350,'closed',50,1350.50),(1351,'active',51,1351.50),(1352,'active',52,1352.50),(1353,'active',53,1353.50),(1354,'active',54,1354.50),(1355,'active',55,1355.50),(1356,'active',56,1356.50),(1357,'active',57,1357.50),(1358,'active',58,1358.50),(1359,'active',59,1359.50),(1360,'closed',20,1360.50),(1361,'active',21,1361.50),(1362,'active',22,1362.50),(1363,'active',23,1363.50),(1364,'active',24,1364.50),(1365,'active',25,1365.50),(1366,'active',26,1366.50),(1367,'active',27,1367.50),(1368,'active',28,1368.50),(1369,'active',29,1369.50),(1370,'closed',30,1370.50),(1371,'active',31,1371.50),(1372,'active',32,1372.50),(1373,'active',33,1373.50),(1374,'active',34,1374.50),(1375,'active',35,1375.50),(1376,'active',36,1376.50),(1377,'active',37,1377.50),(1378,'active',38,1378.50),(1379,'active',39,1379.50),(1380,'closed',40,1380.50),(1381,'active',41,1381.50),(1382,'active',42,1382.50),(1383,'active',43,1383.50),(1384,'active',44,1384.50),(1385,'active',45,1385.50),(1386,'active',46,1386.50),(1387,'active',47,1387.50),(1388,'active',48,1388.50),(1389,'active',49,1389.50),(1390,'closed',50,1390.50),(1391,'active',51,1391.50),(1392,'active',52,1392.50),(1393,'active',53,1393.50),(1394,'active',54,1394.50),(1395,'active',55,1395.50),(1396,'active',56,1396.50),(1397,'active',57,1397.50),(1398,'active',58,1398.50),(1399,'active',59,1399.50);
+INSERT INTO `users` VALUES (1400,'closed',20,1400.50),(1401,'active',21,1401.50),(1402,'active',22,1402.50),(1403,'active',23,1403.50),(1404,'active',24,1404.50),(1405,'active',25,1405.50),(1406,'active',26,1406.50),(1407,'active',27,1407.50),(1408,'active',28,1408.50),(1409,'active',29,1409.50),(1410,'closed',30,1410.50),(1411,'active',31,1411.50),(1412,'active',32,1412.50),(1413,'active',33,1413.50),(1414,'active',34,1414.50),(1415,'active',35,1415.50),(1416,'active',36,1416.50),(1417,'active',37,1417.50),(1418,'active',38,1418.50),(1419,'active',39,1419.50),(1420,'closed',40,1420.50),(1421,'active',41,1421.50),(1422,'active',42,1422.50),(1423,'active',43,1423.50),(1424,'active',44,1424.50),(1425,'active',45,1425.50),(1426,'active',46,1426.50),(1427,'active',47,1427.50),(1428,'active',48,1428.50),(1429,'active',49,1429.50),(1430,'closed',50,1430.50),(1431,'active',51,1431.50),(1432,'active',52,1432.50),(1433,'active',53,1433.50),(1434,'active',54,1434.50),(1435,'active',55,1435.50),(1436,'active',56,1436.50),(1437,'active',57,1437.50),(1438,'active',58,1438.50),(1439,'active',59,1439.50),(1440,'closed',20,1440.50),(1441,'active',21,1441.50),(1442,'active',22,1442.50),(1443,'active',23,1443.50),(1444,'active',24,1444.50),(1445,'active',25,1445.50),(1446,'active',26,1446.50),(1447,'active',27,1447.50),(1448,'active',28,1448.50),(1449,'active',29,1449.50),(1450,'closed',30,1450.50),(1451,'active',31,1451.50),(1452,'active',32,1452.50),(1453,'active',33,1453.50),(1454,'active',34,1454.50),(1455,'active',35,1455.50),(1456,'active',36,1456.50),(1457,'active',37,1457.50),(1458,'active',38,1458.50),(1459,'active',39,1459.50),(1460,'closed',40,1460.50),(1461,'active',41,1461.50),(1462,'active',42,1462.50),(1463,'active',43,1463.50),(1464,'active',44,1464.50),(1465,'active',45,1465.50),(1466,'active',46,1466.50),(1467,'active',47,1467.50),(1468,'active',48,1468.50),(1469,'active',49,1469.50),(1470,'closed',50,1470.50),(1471,'active',51,1471.50),(1472,'active',52,1472.50),(1473,'active',53,1473.50),(1474,'active',54,1474.50),(1475,'active',55,1475.50),(1476,'active',56,1476.50),(1477,'active',57,1477.50),(1478,'active',58,1478.50),(1479,'active',59,1479.50),(1480,'closed',20,1480.50),(1481,'active',21,1481.50),(1482,'active',22,1482.50),(1483,'active',23,1483.50),(1484,'active',24,1484.50),(1485,'active',25,1485.50),(1486,'active',26,1486.50),(1487,'active',27,1487.50),(1488,'active',28,1488.50),(1489,'active',29,1489.50),(1490,'closed',30,1490.50),(1491,'active',31,1491.50),(1492,'active',32,1492.50),(1493,'active',33,1493.50),(1494,'active',34,1494.50),(1495,'active',35,1495.50),(1496,'active',36,1496.50),(1497,'active',37,1497.50),(1498,'active',38,1498.50),(1499,'active',39,1499.50);
+INSERT INTO `users` VALUES (1500,'closed',40,1500.50),(1501,'active',41,1501.50),(1502,'active',42,1502.50),(1503,'active',43,1503.50),(1504,'active',44,1504.50),(1505,'active',45,1505.50),(1506,'active',46,1506.50),(1507,'active',47,1507.50),(1508,'active',48,1508.50),(1509,'active',49,1509.50),(1510,'closed',50,1510.50),(1511,'active',51,1511.50),(1512,'active',52,1512.50),(1513,'active',53,1513.50),(1514,'active',54,1514.50),(1515,'active',55,1515.50),(1516,'active',56,1516.50),(1517,'active',57,1517.50),(1518,'active',58,1518.50),(1519,'active',59,1519.50),(1520,'closed',20,1520.50),(1521,'active',21,1521.50),(1522,'active',22,1522.50),(1523,'active',23,1523.50),(1524,'active',24,1524.50),(1525,'active',25,1525.50),(1526,'active',26,1526.50),(1527,'active',27,1527.50),(1528,'active',28,1528.50),(1529,'active',29,1529.50),(1530,'closed',30,1530.50),(1531,'active',31,1531.50),(1532,'active',32,1532.50),(1533,'active',33,1533.50),(1534,'active',34,1534.50),(1535,'active',35,1535.50),(1536,'active',36,1536.50),(1537,'active',37,1537.50),(1538,'active',38,1538.50),(1539,'active',39,1539.50),(1540,'closed',40,1540.50),(1541,'active',41,1541.50),(1542,'active',42,1542.50),(1543,'active',43,1543.50),(1544,'active',44,1544.50),(1545,'active',45,1545.50),(1546,'active',46,1546.50),(1547,'active',47,1547.50),(1548,'active',48,1548.50),(1549,'active',49,1549.50),(1550,'closed',50,1550.50),(1551,'active',51,1551.50),(1552,'active',52,1552.50),(1553,'active',53,1553.50),(1554,'active',54,1554.50),(1555,'active',55,1555.50),(1556,'active',56,1556.50),(1557,'active',57,1557.50),(1558,'active',58,1558.50),(1559,'active',59,1559.50),(1560,'closed',20,1560.50),(1561,'active',21,1561.50),(1562,'active',22,1562.50),(1563,'active',23,1563.50),(1564,'active',24,1564.50),(1565,'active',25,1565.50),(1566,'active',26,1566.50),(1567,'active',27,1567.50),(1568,'active',28,1568.50),(1569,'active',29,1569.50),(1570,'closed',30,1570.50),(1571,'active',31,1571.50),(1572,'active',32,1572.50),(1573,'active',33,1573.50),(1574,'active',34,1574.50),(1575,'active',35,1575.50),(1576,'active',36,1576.50),(1577,'active',37,1577.50),(1578,'active',38,1578.50),(1579,'active',39,1579.50),(1580,'closed',40,1580.50),(1581,'active',41,1581.50),(1582,'active',42,1582.50),(1583,'active',43,1583.50),(1584,'active',44,1584.50),(1585,'active',45,1585.50),(1586,'active',46,1586.50),(1587,'active',47,1587.50),(1588,'active',48,1588.50),(1589,'active',49,1589.50),(1590,'closed',50,1590.50),(1591,'active',51,1591.50),(1592,'active',52,1592.50),(1593,'active',53,1593.50),(1594,'active',54,1594.50),(1595,'active',55,1595.50),(1596,'active',56,1596.50),(1597,'active',57,1597.50),(1598,'active',58,1598.50),(1599,'active',59,1599.50);
+INSERT INTO `users` VALUES (1600,'closed',20,1600.50),(1601,'active',21,1601.50),(1602,'active',22,1602.50),(1603,'active',23,1603.50),(1604,'active',24,1604.50),(1605,'active',25,1605.50),(1606,'active',26,1606.50),(1607,'active',27,1607.50),(1608,'active',28,1608.50),(1609,'active',29,1609.50),(1610,'closed',30,1610.50),(1611,'active',31,1611.50),(1612,'active',32,1612.50),(1613,'active',33,1613.50),(1614,'active',34,1614.50),(1615,'active',35,1615.50),(1616,'active',36,1616.50),(1617,'active',37,1617.50),(1618,'active',38,1618.50),(1619,'active',39,1619.50),(1620,'closed',40,1620.50),(1621,'active',41,1621.50),(1622,'active',42,1622.50),(1623,'active',43,1623.50),(1624,'active',44,1624.50),(1625,'active',45,1625.50),(1626,'active',46,1626.50),(1627,'active',47,1627.50),(1628,'active',48,1628.50),(1629,'active',49,1629.50),(1630,'closed',50,1630.50),(1631,'active',51,1631.50),(1632,'active',52,1632.50),(1633,'active',53,1633.50),(1634,'active',54,1634.50),(1635,'active',55,1635.50),(1636,'active',56,1636.50),(1637,'active',57,1637.50),(1638,'active',58,1638.50),(1639,'active',59,1639.50),(1640,'closed',20,1640.50),(1641,'active',21,1641.50),(1642,'active',22,1642.50),(1643,'active',23,1643.50),(1644,'active',24,1644.50),(1645,'active',25,1645.50),(1646,'active',26,1646.50),(1647,'active',27,1647.50),(1648,'active',28,1648.50),(1649,'active',29,1649.50),(1650,'closed',30,1650.50),(1651,'active',31,1651.50),(1652,'active',32,1652.50),(1653,'active',33,1653.50),(1654,'active',34,1654.50),(1655,'active',35,1655.50),(1656,'active',36,1656.50),(1657,'active',37,1657.50),(1658,'active',38,1658.50),(1659,'active',39,1659.50),(1660,'closed',40,1660.50),(1661,'active',41,1661.50),(1662,'active',42,1662.50),(1663,'active',43,1663.50),(1664,'active',44,1664.50),(1665,'active',45,1665.50),(1666,'active',46,1666.50),(1667,'active',47,1667.50),(1668,'active',48,1668.50),(1669,'active',49,1669.50),(1670,'closed',50,1670.50),(1671,'active',51,1671.50),(1672,'active',52,1672.50),(1673,'active',53,1673.50),(1674,'active',54,1674.50),(1675,'active',55,1675.50),(1676,'active',56,1676.50),(1677,'active',57,1677.50),(1678,'active',58,1678.50),(1679,'active',59,1679.50),(1680,'closed',20,1680.50),(1681,'active',21,1681.50),(1682,'active',22,1682.50),(1683,'active',23,1683.50),(1684,'active',24,1684.50),(1685,'active',25,1685.50),(1686,'active',26,1686.50),(1687,'active',27,1687.50),(1688,'active',28,1688.50),(1689,'active',29,1689.50),(1690,'closed',30,1690.50),(1691,'active',31,1691.50),(1692,'active',32,1692.50),(1693,'active',33,1693.50),(1694,'active',34,1694.50),(1695,'active',35,1695.50),(1696,'active',36,1696.50),(1697,'active',37,1697.50),(1698,'active',38,1698.50),(1699,'active',39,1699.50);
+INSERT INTO `users` VALUES (1700,'closed',40,1700.50),(1701,'active',41,1701.50),(1702,'active',42,1702.50),(1703,'active',43,1703.50),(1704,'active',44,1704.50),(1705,'active',45,1705.50),(1706,'active',46,1706.50),(1707,'active',47,1707.50),(1708,'active',48,1708.50),(1709,'active',49,1709.50),(1710,'closed',50,1710.50),(1711,'active',51,1711.50),(1712,'active',52,1712.50),(1713,'active',53,1713.50),(1714,'active',54,1714.50),(1715,'active',55,1715.50),(1716,'active',56,1716.50),(1717,'active',57,1717.50),(1718,'active',58,1718.50),(1719,'active',59,1719.50),(1720,'closed',20,1720.50),(1721,'active',21,1721.50),(1722,'active',22,1722.50),(1723,'active',23,1723.50),(1724,'active',24,1724.50),(1725,'active',25,1725.50),(1726,'active',26,1726.50),(1727,'active',27,1727.50),(1728,'active',28,1728.50),(1729,'active',29,1729.50),(1730,'closed',30,1730.50),(1731,'active',31,1731.50),(1732,'active',32,1732.50),(1733,'active',33,1733.50),(1734,'active',34,1734.50),(1735,'active',35,1735.50),(1736,'active',36,1736.50),(1737,'active',37,1737.50),(1738,'active',38,1738.50),(1739,'active',39,1739.50),(1740,'closed',40,1740.50),(1741,'active',41,1741.50),(1742,'active',42,1742.50),(1743,'active',43,1743.50),(1744,'active',44,1744.50),(1745,'active',45,1745.50),(1746,'active',46,1746.50),(1747,'active',47,1747.50),(1748,'active',48,1748.50),(1749,'active',49,1749.50),(1750,'closed',50,1750.50),(1751,'active',51,1751.50),(1752,'active',52,1752.50),(1753,'active',53,1753.50),(1754,'active',54,1754.50),(1755,'active',55,1755.50),(1756,'active',56,1756.50),(1757,'active',57,1757.50),(1758,'active',58,1758.50),(1759,'active',59,1759.50),(1760,'closed',20,1760.50),(1761,'active',21,1761.50),(1762,'active',22,1762.50),(1763,'active',23,1763.50),(1764,'active',24,1764.50),(1765,'active',25,1765.50),(1766,'active',26,1766.50),(1767,'active',27,1767.50),(1768,'active',28,1768.50),(1769,'active',29,1769.50),(1770,'closed',30,1770.50),(1771,'active',31,1771.50),(1772,'active',32,1772.50),(1773,'active',33,1773.50),(1774,'active',34,1774.50),(1775,'active',35,1775.50),(1776,'active',36,1776.50),(1777,'active',37,1777.50),(1778,'active',38,1778.50),(1779,'active',39,1779.50),(1780,'closed',40,1780.50),(1781,'active',41,1781.50),(1782,'active',42,1782.50),(1783,'active',43,1783.50),(1784,'active',44,1784.50),(1785,'active',45,1785.50),(1786,'active',46,1786.50),(1787,'active',47,1787.50),(1788,'active',48,1788.50),(1789,'active',49,1789.50),(1790,'closed',50,1790.50),(1791,'active',51,1791.50),(1792,'active',52,1792.50),(1793,'active',53,1793.50),(1794,'active',54,1794.50),(1795,'active',55,1795.50),(1796,'active',56,1796.50),(1797,'active',57,1797.50),(1798,'active',58,1798.50),(1799,'active',59,1799.50);
+INSERT INTO `users` VALUES (1800,'closed',20,1800.50),(1801,'active',21,1801.50),(1802,'active',22,1802.50),(1803,'active',23,1803.50),(1804,'active',24,1804.50),(1805,'active',25,1805.50),(1806,'active',26,1806.50),(1807,'active',27,1807.50),(1808,'active',28,1808.50),(1809,'active',29,1809.50),(1810,'closed',30,1810.50),(1811,'active',31,1811.50),(1812,'active',32,1812.50),(1813,'active',33,1813.50),(1814,'active',34,1814.50),(1815,'active',35,1815.50),(1816,'active',36,1816.50),(1817,'active',37,1817.50),(1818,'active',38,1818.50),(1819,'active',39,1819.50),(1820,'closed',40,1820.50),(1821,'active',41,1821.50),(1822,'active',42,1822.50),(1823,'active',43,1823.50),(1824,'active',44,1824.50),(1825,'active',45,1825.50),(1826,'active',46,1826.50),(1827,'active',47,1827.50),(1828,'active',48,1828.50),(1829,'active',49,1829.50),(1830,'closed',50,1830.50),(1831,'active',51,1831.50),(1832,'active',52,1832.50),(1833,'active',53,1833.50),(1834,'active',54,1834.50),(1835,'active',55,1835.50),(1836,'active',56,1836.50),(1837,'active',57,1837.50),(1838,'active',58,1838.50),(1839,'active',59,1839.50),(1840,'closed',20,1840.50),(1841,'active',21,1841.50),(1842,'active',22,1842.50),(1843,'active',23,1843.50),(1844,'active',24,1844.50),(1845,'active',25,1845.50),(1846,'active',26,1846.50),(1847,'active',27,1847.50),(1848,'active',28,1848.50),(1849,'active',29,1849.50),(1850,'closed',30,1850.50),(1851,'active',31,1851.50),(1852,'active',32,1852.50),(1853,'active',33,1853.50),(1854,'active',34,1854.50),(1855,'active',35,1855.50),(1856,'active',36,1856.50),(1857,'active',37,1857.50),(1858,'active',38,1858.50),(1859,'active',39,1859.50),(1860,'closed',40,1860.50),(1861,'active',41,1861.50),(1862,'active',42,1862.50),(1863,'active',43,1863.50),(1864,'active',44,1864.50),(1865,'active',45,1865.50),(1866,'active',46,1866.50),(1867,'active',47,1867.50),(1868,'active',48,1868.50),(1869,'active',49,1869.50),(1870,'closed',50,1870.50),(1871,'active',51,1871.50),(1872,'active',52,1872.50),(1873,'active',53,1873.50),(1874,'active',54,1874.50),(1875,'active',55,1875.50),(1876,'active',56,1876.50),(1877,'active',57,1877.50),(1878,'active',58,1878.50),(1879,'active',59,1879.50),(1880,'closed',20,1880.50),(1881,'active',21,1881.50),(1882,'active',22,1882.50),(1883,'active',23,1883.50),(1884,'active',24,1884.50),(1885,'active',25,1885.50),(1886,'active',26,1886.50),(1887,'active',27,1887.50),(1888,'active',28,1888.50),(1889,'active',29,1889.50),(1890,'closed',30,1890.50),(1891,'active',31,1891.50),(1892,'active',32,1892.50),(1893,'active',33,1893.50),(1894,'active',34,1894.50),(1895,'active',35,1895.50),(1896,'active',36,1896.50),(1897,'active',37,1897.50),(1898,'active',38,1898.50),(1899,'active',39,1899.50);
+INSERT INTO `users` VALUES (1900,'closed',40,1900.50),(1901,'active',41,1901.50),(1902,'active',42,1902.50),(1903,'active',43,1903.50),(1904,'active',44,1904.50),(1905,'active',45,1905.50),(1906,'active',46,1906.50),(1907,'active',47,1907.50),(1908,'active',48,1908.50),(1909,'active',49,1909.50),(1910,'closed',50,1910.50),(1911,'active',51,1911.50),(1912,'active',52,1912.50),(1913,'active',53,1913.50),(1914,'active',54,1914.50),(1915,'active',55,1915.50),(1916,'active',56,1916.50),(1917,'active',57,1917.50),(1918,'active',58,1918.50),(1919,'active',59,1919.50),(1920,'closed',20,1920.50),(1921,'active',21,1921.50),(1922,'active',22,1922.50),(1923,'active',23,1923.50),(1924,'active',24,1924.50),(1925,'active',25,1925.50),(1926,'active',26,1926.50),(1927,'active',27,1927.50),(1928,'active',28,1928.50),(1929,'active',29,1929.50),(1930,'closed',30,1930.50),(1931,'active',31,1931.50),(1932,'active',32,1932.50),(1933,'active',33,1933.50),(1934,'active',34,1934.50),(1935,'active',35,1935.50),(1936,'active',36,1936.50),(1937,'active',37,1937.50),(1938,'active',38,1938.50),(1939,'active',39,1939.50),(1940,'closed',40,1940.50),(1941,'active',41,1941.50),(1942,'active',42,1942.50),(1943,'active',43,1943.50),(1944,'active',44,1944.50),(1945,'active',45,1945.50),(1946,'active',46,1946.50),(1947,'active',47,1947.50),(1948,'active',48,1948.50),(1949,'active',49,1949.50),(1950,'closed',50,1950.50),(1951,'active',51,1951.50),(1952,'active',52,1952.50),(1953,'active',53,1953.50),(1954,'active',54,1954.50),(1955,'active',55,1955.50),(1956,'active',56,1956.50),(1957,'active',57,1957.50),(1958,'active',58,1958.50),(1959,'active',59,1959.50),(1960,'closed',20,1960.50),(1961,'active',21,1961.50),(1962,'active',22,1962.50),(1963,'active',23,1963.50),(1964,'active',24,1964.50),(1965,'active',25,1965.50),(1966,'active',26,1966.50),(1967,'active',27,1967.50),(1968,'active',28,1968.50),(1969,'active',29,1969.50),(1970,'closed',30,1970.50),(1971,'active',31,1971.50),(1972,'active',32,1972.50),(1973,'active',33,1973.50),(1974,'active',34,1974.50),(1975,'active',35,1975.50),(1976,'active',36,1976.50),(1977,'active',37,1977.50),(1978,'active',38,1978.50),(1979,'active',39,1979.50),(1980,'closed',40,1980.50),(1981,'active',41,1981.50),(1982,'active',42,1982.50),(1983,'active',43,1983.50),(1984,'active',44,1984.50),(1985,'active',45,1985.50),(1986,'active',46,1986.50),(1987,'active',47,1987.50),(1988,'active',48,1988.50),(1989,'active',49,1989.50),(1990,'closed',50,1990.50),(1991,'active',51,1991.50),(1992,'active',52,1992.50),(1993,'active',53,1993.50),(1994,'active',54,1994.50),(1995,'active',55,1995.50),(1996,'active',56,1996.50),(1997,'active',57,1997.50),(1998,'active',58,1998.50),(1999,'active',59,1999.50);
+INSERT INTO `users` VALUES (2000,NULL,NULL,NULL);
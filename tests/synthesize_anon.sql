@@ -0,0 +1,27 @@
+CREATE TABLE `users` (
+  `id` int NOT NULL,
+  `status` varchar(16) DEFAULT NULL,
+  `age` int DEFAULT NULL,
+  `balance` decimal(8,2) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `users` VALUES (0,'active',36,512.60),(1,'closed',36,1233.50),(2,'closed',34,1562.99),(3,'active',32,274.66),(4,'active',21,1841.55),(5,'closed',50,1811.73),(6,'active',34,1347.43),(7,'active',32,770.08),(8,'active',34,1978.08),(9,'active',45,998.27),(10,'active',24,1415.08),(11,'active',48,723.68),(12,'active',22,438.13),(13,'active',31,1728.33),(14,'active',45,531.45),(15,'active',56,697.31),(16,'active',44,346.37),(17,'active',24,1606.23),(18,'closed',24,479.44),(19,'active',48,387.07),(20,'active',31,486.62),(21,'active',41,447.49),(22,'active',37,202.36),(23,'active',21,512.49),(24,'active',53,1507.85),(25,'closed',22,1907.64),(26,'active',50,1728.64),(27,'active',44,241.92),(28,'active',23,280.63),(29,'closed',44,1042.59),(30,'active',55,510.72),(31,'active',51,1954.96),(32,'closed',21,350.71),(33,'active',25,1101.28),(34,'active',59,1629.86),(35,'active',58,1693.74),(36,'active',38,1905.59),(37,'active',22,865.11),(38,'closed',22,1460.33),(39,'active',36,637.61),(40,'active',54,89.79),(41,'active',34,926.62),(42,'active',23,467.18),(43,'active',41,811.57),(44,'active',48,76.92),(45,'active',55,447.33),(46,'active',50,50.54),(47,'active',20,850.62),(48,'active',46,584.63),(49,'active',54,1873.64),(50,'active',26,1028.75),(51,'active',34,559.46),(52,'closed',22,1730.37),(53,'active',32,993.09),(54,'active',37,15.09),(55,'active',24,26.05),(56,'active',29,1096.92),(57,'active',37,672.41),(58,'active',49,967.17),(59,'active',50,1977.62),(60,'active',43,1323.59),(61,'active',33,366.61),(62,'active',47,1539.59),(63,'active',33,1710.93),(64,'active',41,1689.16),(65,'active',52,1425.00),(66,'active',27,1665.44),(67,'active',36,64.96),(68,'closed',30,1743.51),(69,'active',56,527.08),(70,'active',47,1784.84),(71,'active',27,550.49),(72,'active',22,477.79),(73,'active',56,529.61),(74,'active',48,1849.51),(75,'active',48,566.21),(76,'active',41,253.24),(77,'active',21,1141.13),(78,'active',48,297.28),(79,'active',59,466.48),(80,'active',32,299.24),(81,'active',45,1805.08),(82,'active',22,919.75),(83,'active',34,45.80),(84,'active',58,1462.98),(85,'active',44,1056.69),(86,'active',38,1946.67),(87,'active',48,824.78),(88,'active',53,1095.12),(89,'active',31,1835.23),(90,'active',33,857.23),(91,'active',50,1481.97),(92,'active',55,1059.57),(93,'active',45,1489.50),(94,'active',48,614.88),(95,'active',44,1909.47),(96,'active',28,1013.73),(97,'closed',43,717.14),(98,'active',23,1404.11),(99,'active',46,261.17);
+INSERT INTO `users` VALUES (100,'active',51,1345.47),(101,'active',59,1631.70),(102,'active',47,987.85),(103,'active',33,1910.48),(104,'active',41,1482.58),(105,'active',45,50.62),(106,'active',50,1695.43),(107,'active',36,593.10),(108,'active',35,1120.49),(109,'active',47,554.40),(110,'active',49,1669.32),(111,'active',32,1585.27),(112,'active',41,457.97),(113,'active',57,617.77),(114,'active',57,1246.42),(115,'active',22,1357.11),(116,'active',27,376.32),(117,'active',46,1239.90),(118,'active',31,1293.64),(119,'active',50,532.59),(120,'active',29,1649.85),(121,'active',24,78.89),(122,'active',51,1464.22),(123,'closed',41,585.50),(124,'active',24,1827.31),(125,'active',47,604.94),(126,'active',27,1819.70),(127,'active',38,1820.95),(128,'closed',54,1740.75),(129,'active',42,1542.69),(130,'active',49,1067.47),(131,'active',29,1120.04),(132,'active',38,1974.94),(133,'active',34,446.19),(134,'active',50,709.34),(135,'active',44,813.36),(136,'active',20,217.75),(137,'active',31,1600.52),(138,'active',49,1517.33),(139,'active',37,942.54),(140,'active',55,455.08),(141,'active',43,786.47),(142,'active',59,1408.42),(143,'closed',52,198.91),(144,'closed',37,1651.58),(145,'active',54,980.93),(146,'active',22,326.65),(147,'active',56,455.47),(148,'active',36,782.67),(149,'closed',26,1874.41),(150,'active',54,517.23),(151,'closed',58,516.19),(152,'active',41,119.61),(153,'active',40,1643.23),(154,'active',52,1346.82),(155,'active',22,1675.13),(156,'active',51,356.01),(157,'active',40,1131.88),(158,'active',49,70.04),(159,'active',38,1631.88),(160,'active',46,277.56),(161,'active',40,1856.72),(162,'active',29,1869.35),(163,'active',43,205.92),(164,'active',23,621.49),(165,'active',52,8.12),(166,'active',52,118.23),(167,'active',39,1693.12),(168,'active',51,1330.23),(169,'active',55,959.17),(170,'active',27,305.03),(171,'active',30,1041.47),(172,'active',38,143.26),(173,'active',51,270.72),(174,'active',25,831.59),(175,'active',44,440.29),(176,'active',54,320.81),(177,'active',48,196.05),(178,'active',31,1952.75),(179,'active',47,1861.88),(180,'active',27,1079.03),(181,'active',31,1021.43),(182,'active',56,1165.56),(183,'active',55,89.04),(184,'closed',24,1492.32),(185,'active',25,1128.16),(186,'active',26,31.97),(187,'active',31,1807.85),(188,'active',48,631.26),(189,'active',27,667.30),(190,'active',47,874.98),(191,'active',57,1435.81),(192,'active',53,1158.60),(193,'active',58,1852.04),(194,'active',31,869.20),(195,'closed',49,1566.64),(196,'active',47,1852.88),(197,'active',22,1907.14),(198,'active',57,396.87),(199,'active',42,1875.96);
+INSERT INTO `users` VALUES (200,'active',21,958.47),(201,'active',32,1764.26),(202,'active',48,790.40),(203,'active',27,900.66),(204,'closed',39,1385.15),(205,'active',43,1379.47),(206,'active',44,767.65),(207,'active',34,1159.01),(208,'active',23,950.20),(209,'active',51,1510.40),(210,'closed',30,618.43),(211,'active',27,1736.90),(212,'active',48,949.12),(213,'active',40,431.28),(214,'active',40,421.46),(215,'closed',25,276.00),(216,'active',21,859.64),(217,'active',29,773.88),(218,'active',39,1848.87),(219,'active',38,471.44),(220,'active',45,1576.20),(221,'active',23,1750.95),(222,'active',50,1199.09),(223,'active',47,1922.08),(224,'active',47,958.18),(225,'active',34,55.83),(226,'active',30,666.48),(227,'active',26,1124.19),(228,'active',40,1406.41),(229,'active',35,592.84),(230,'active',32,1969.93),(231,'active',32,941.88),(232,'active',53,568.48),(233,'active',34,941.15),(234,'active',44,772.56),(235,'active',54,1281.39),(236,'active',38,1507.12),(237,'active',40,949.24),(238,'active',49,567.20),(239,'active',27,1932.31),(240,'active',50,347.98),(241,'closed',27,713.89),(242,'active',53,241.45),(243,'active',33,615.33),(244,'active',36,1433.93),(245,'active',56,1734.31),(246,'active',33,650.03),(247,'active',55,1043.94),(248,'active',29,1848.62),(249,'active',45,1278.07),(250,'active',50,1585.43),(251,'active',40,891.07),(252,'active',53,1264.57),(253,'closed',21,1868.33),(254,'closed',50,931.60),(255,'active',54,464.97),(256,'active',56,858.23),(257,'active',37,1668.46),(258,'active',33,167.25),(259,'closed',46,3.46),(260,'active',53,1021.29),(261,'active',27,1973.76),(262,'active',46,1353.33),(263,'active',58,651.10),(264,'active',41,199.41),(265,'active',42,34.18),(266,'active',28,204.60),(267,'active',46,151.34),(268,'closed',35,1938.79),(269,'closed',21,1478.02),(270,'active',21,1267.24),(271,'active',39,438.30),(272,'active',41,30.10),(273,'closed',44,904.33),(274,'closed',26,1073.54),(275,'active',24,1618.33),(276,'active',57,513.15),(277,'active',22,1311.27),(278,'closed',26,689.00),(279,'active',48,1778.08),(280,'active',52,1849.01),(281,'closed',50,1867.02),(282,'active',32,30.81),(283,'active',24,710.68),(284,'active',50,1752.21),(285,'active',34,66.46),(286,'active',45,52.85),(287,'active',28,589.07),(288,'active',43,1397.49),(289,'active',32,237.52),(290,'closed',40,961.74),(291,'active',36,733.43),(292,'active',35,5.16),(293,'active',48,1552.33),(294,'closed',35,1038.55),(295,'active',53,1755.83),(296,'active',40,603.30),(297,'active',49,440.89),(298,'active',45,1597.82),(299,'active',21,565.35);
+INSERT INTO `users` VALUES (300,'active',30,751.51),(301,'active',31,178.95),(302,'active',37,895.31),(303,'active',31,1842.17),(304,'active',27,643.20),(305,'closed',55,1932.15),(306,'active',57,614.82),(307,'active',48,1336.10),(308,'active',33,1294.71),(309,'active',23,1571.35),(310,'closed',57,777.47),(311,'active',29,879.26),(312,'active',30,1497.60),(313,'active',43,626.73),(314,'active',40,1100.36),(315,'active',51,585.12),(316,'active',28,1255.90),(317,'closed',53,449.46),(318,'closed',43,1432.00),(319,'active',56,20.65),(320,'active',39,1354.26),(321,'active',41,4.44),(322,'closed',47,615.78),(323,'active',29,1448.10),(324,'active',57,1728.26),(325,'closed',30,410.26),(326,'active',46,1751.09),(327,'active',52,588.25),(328,'active',44,1.23),(329,'active',34,447.65),(330,'active',29,273.78),(331,'active',29,1042.61),(332,'active',53,1678.73),(333,'closed',49,1270.92),(334,'active',23,1531.18),(335,'active',52,820.24),(336,'active',29,937.08),(337,'active',38,653.69),(338,'active',33,1588.82),(339,'closed',44,1224.72),(340,'active',20,1812.84),(341,'active',58,538.71),(342,'active',44,1502.51),(343,'active',30,734.82),(344,'active',22,395.29),(345,'active',41,1982.81),(346,'active',33,1965.39),(347,'active',48,351.81),(348,'active',26,118.42),(349,'active',30,728.80),(350,'active',28,1251.42),(351,'active',56,215.04),(352,'active',31,1278.84),(353,'closed',48,12.81),(354,'active',26,1564.34),(355,'active',22,1089.20),(356,'active',53,1632.02),(357,'active',23,1336.13),(358,'active',47,1766.11),(359,'active',36,976.07),(360,'active',59,732.03),(361,'closed',54,603.52),(362,'active',54,338.69),(363,'active',45,1975.95),(364,'active',32,1535.57),(365,'active',51,285.89),(366,'active',21,1184.95),(367,'active',41,1415.99),(368,'active',27,416.56),(369,'active',41,206.79),(370,'active',42,601.08),(371,'active',23,1881.20),(372,'active',56,1186.13),(373,'active',33,123.61),(374,'active',50,1548.06),(375,'closed',35,1339.82),(376,'active',20,1934.74),(377,'active',57,573.75),(378,'active',26,1624.83),(379,'active',41,440.36),(380,'active',37,1421.30),(381,'active',33,716.55),(382,'active',52,948.27),(383,'active',40,1636.69),(384,'closed',57,1890.00),(385,'active',38,1323.29),(386,'active',39,881.33),(387,'active',55,412.66),(388,'active',50,1457.04),(389,'active',25,205.25),(390,'active',33,1098.32),(391,'active',38,1935.35),(392,'active',43,1190.44),(393,'active',52,1254.72),(394,'active',35,705.56),(395,'closed',39,1381.09),(396,'active',30,106.47),(397,'active',57,1809.13),(398,'active',45,1532.15),(399,'active',33,1863.34);
+INSERT INTO `users` VALUES (400,'active',32,1498.11),(401,'active',27,1414.47),(402,'active',31,1611.22),(403,'active',25,1343.80),(404,'active',49,1819.38),(405,'active',25,818.53),(406,'active',20,71.62),(407,'active',53,1317.96),(408,'active',39,1507.98),(409,'active',42,1237.48),(410,'active',28,1484.58),(411,'active',50,1813.99),(412,'active',24,888.73),(413,'active',24,261.46),(414,'active',28,1242.64),(415,'closed',27,1404.08),(416,'active',39,1115.23),(417,'active',54,139.68),(418,'active',56,148.56),(419,'closed',28,1294.88),(420,'active',36,1315.15),(421,'active',41,1832.97),(422,'active',51,596.05),(423,'active',22,244.81),(424,'closed',54,405.49),(425,'active',53,912.05),(426,'active',48,275.76),(427,'active',47,1595.86),(428,'active',41,716.32),(429,'active',26,1206.75),(430,'active',23,1629.45),(431,'active',58,898.06),(432,'active',28,425.68),(433,'active',55,205.95),(434,'active',33,1240.93),(435,'active',54,1206.35),(436,'closed',28,1822.92),(437,'active',23,633.89),(438,'closed',58,1259.28),(439,'active',44,1494.25),(440,'active',38,81.15),(441,'closed',24,865.36),(442,'active',45,626.41),(443,'active',56,755.16),(444,'active',43,159.98),(445,'active',23,716.83),(446,'active',38,1875.68),(447,'active',57,400.78),(448,'closed',57,1422.84),(449,'active',35,1660.14),(450,'active',44,1434.72),(451,'active',33,1880.42),(452,'active',38,320.18),(453,'active',40,1558.66),(454,'active',35,833.12),(455,'active',44,648.12),(456,'active',39,1206.07),(457,'active',39,1091.74),(458,'active',37,1795.50),(459,'active',58,1460.32),(460,'active',20,1496.84),(461,'active',47,1391.03),(462,'active',38,1038.77),(463,'closed',26,849.51),(464,'active',34,158.44),(465,'active',43,352.29),(466,'active',41,193.75),(467,'active',54,1032.95),(468,'active',23,1292.88),(469,'active',35,1762.53),(470,'active',53,1556.45),(471,'active',23,124.25),(472,'active',52,1599.87),(473,'active',38,893.88),(474,'active',55,1661.58),(475,'active',33,384.20),(476,'active',32,1234.00),(477,'active',47,1939.26),(478,'closed',34,146.83),(479,'active',46,1982.47),(480,'active',39,1787.23),(481,'active',45,1729.31),(482,'active',30,1145.18),(483,'active',22,330.32),(484,'active',20,723.51),(485,'active',53,1910.69),(486,'closed',31,612.48),(487,'active',52,1798.31),(488,'active',21,755.98),(489,'active',31,83.93),(490,'active',53,1847.98),(491,'active',38,739.24),(492,'active',56,755.24),(493,'active',24,922.56),(494,'active',58,1515.58),(495,'active',34,1993.89),(496,'active',39,1889.55),(497,'closed',27,1630.86),(498,'active',23,1697.46),(499,'active',33,780.25);
+INSERT INTO `users` VALUES (500,'closed',27,925.83),(501,'active',55,1143.44),(502,'active',48,1658.78),(503,'active',38,1126.38),(504,'active',22,394.69),(505,'active',40,1994.50),(506,'active',41,1365.05),(507,'active',51,1852.98),(508,'active',21,1871.21),(509,'active',37,582.57),(510,'active',20,1899.58),(511,'active',45,1237.89),(512,'active',27,1926.64),(513,'active',35,966.63),(514,'active',36,988.78),(515,'active',30,1184.75),(516,'closed',22,1652.35),(517,'active',27,105.08),(518,'closed',31,400.20),(519,'active',26,119.20),(520,'active',40,1182.56),(521,'active',53,1640.27),(522,'active',47,1021.95),(523,'active',26,1263.07),(524,'active',40,806.56),(525,'active',39,889.43),(526,'active',46,323.29),(527,'closed',59,1000.40),(528,'active',33,111.95),(529,'active',38,828.18),(530,'closed',52,211.66),(531,'active',56,724.08),(532,'active',34,1067.53),(533,'active',31,595.68),(534,'active',51,1854.32),(535,'active',57,1586.19),(536,'active',37,512.22),(537,'active',52,1330.03),(538,'active',41,753.92),(539,'active',28,340.72),(540,'active',33,1130.04),(541,'active',23,508.18),(542,'active',53,1650.23),(543,'active',53,1653.09),(544,'active',25,1282.26),(545,'active',36,322.48),(546,'active',46,2.43),(547,'active',34,1627.26),(548,'active',47,123.58),(549,'active',51,1703.85),(550,'active',42,195.86),(551,'active',38,485.84),(552,'active',34,125.59),(553,'active',31,1865.45),(554,'active',41,418.69),(555,'active',50,1824.56),(556,'active',54,1139.39),(557,'active',57,541.92),(558,'active',51,625.59),(559,'active',50,21.36),(560,'active',24,975.55),(561,'active',34,474.73),(562,'active',45,1949.39),(563,'active',54,21.45),(564,'active',56,1679.90),(565,'active',25,1280.31),(566,'active',33,925.94),(567,'active',35,338.68),(568,'active',22,1068.54),(569,'active',44,894.67),(570,'active',22,932.25),(571,'active',27,1307.76),(572,'active',42,293.09),(573,'active',21,31.81),(574,'active',27,1742.91),(575,'active',59,243.59),(576,'active',52,783.77),(577,'active',50,1655.18),(578,'active',55,1842.00),(579,'active',47,506.98),(580,'active',39,286.26),(581,'active',34,1876.15),(582,'active',46,411.97),(583,'active',35,996.78),(584,'active',32,132.73),(585,'active',24,1265.20),(586,'active',26,1113.45),(587,'active',43,710.75),(588,'active',24,678.17),(589,'active',44,298.66),(590,'closed',22,1799.28),(591,'active',22,1618.13),(592,'closed',54,752.21),(593,'closed',21,1363.92),(594,'active',49,241.24),(595,'active',26,943.33),(596,'active',57,730.26),(597,'closed',37,1274.06),(598,'active',57,1314.14),(599,'active',27,1709.32);
+INSERT INTO `users` VALUES (600,'active',28,1718.15),(601,'active',46,528.08),(602,'active',35,1110.47),(603,'active',31,1720.27),(604,'active',20,763.65),(605,'active',22,215.23),(606,'active',50,1912.15),(607,'active',49,783.89),(608,'active',47,315.00),(609,'active',46,78.20),(610,'active',51,1558.01),(611,'active',22,373.16),(612,'active',22,1606.57),(613,'active',58,1445.58),(614,'active',54,578.41),(615,'active',38,422.37),(616,'active',34,1879.96),(617,'closed',37,133.30),(618,'active',59,563.55),(619,'active',49,686.63),(620,'active',46,672.47),(621,'active',32,1679.67),(622,'active',47,375.91),(623,'active',23,790.86),(624,'active',39,1274.07),(625,'active',35,334.32),(626,'active',23,1986.06),(627,'active',28,259.70),(628,'active',57,942.91),(629,'active',28,239.58),(630,'active',57,1494.79),(631,'closed',33,1277.33),(632,'active',36,559.06),(633,'active',50,1457.77),(634,'active',47,71.55),(635,'active',37,1866.84),(636,'active',43,888.66),(637,'active',44,1604.96),(638,'closed',23,1407.76),(639,'active',41,1276.78),(640,'closed',44,50.64),(641,'closed',54,1076.71),(642,'active',49,926.06),(643,'closed',32,1013.30),(644,'active',58,1225.05),(645,'active',58,488.09),(646,'active',21,896.86),(647,'active',32,689.38),(648,'closed',56,1288.22),(649,'active',25,1267.83),(650,'active',56,975.56),(651,'active',20,444.39),(652,'active',41,502.92),(653,'active',30,154.90),(654,'active',23,1607.92),(655,'active',31,159.44),(656,'active',53,1314.46),(657,'active',49,1011.81),(658,'active',22,1057.04),(659,'active',46,1337.61),(660,'active',43,1754.84),(661,'active',43,1904.84),(662,'active',38,1400.82),(663,'active',21,1431.27),(664,'active',27,1547.18),(665,'active',54,1387.43),(666,'active',46,1158.65),(667,'active',27,1396.19),(668,'active',21,248.11),(669,'active',50,800.50),(670,'active',24,881.63),(671,'active',35,1833.92),(672,'active',30,992.53),(673,'active',28,403.45),(674,'closed',49,1037.48),(675,'active',49,1511.27),(676,'active',29,164.24),(677,'active',47,1511.18),(678,'active',45,1311.83),(679,'active',20,1169.39),(680,'active',28,213.89),(681,'active',21,703.82),(682,'active',54,415.48),(683,'active',27,1292.87),(684,'active',21,1782.18),(685,'active',22,1898.17),(686,'active',25,1962.01),(687,'closed',37,736.12),(688,'closed',26,873.76),(689,'active',21,943.90),(690,'active',22,1220.37),(691,'active',41,497.38),(692,'active',49,470.69),(693,'active',33,1598.03),(694,'active',44,1453.41),(695,'active',41,175.62),(696,'active',45,1115.33),(697,'active',25,477.95),(698,'closed',44,1218.91),(699,'active',33,1521.69);
+INSERT INTO `users` VALUES (700,'active',51,1532.53),(701,'active',36,1044.23),(702,'active',54,1371.40),(703,'active',56,803.76),(704,'active',28,1287.47),(705,'active',38,1212.06),(706,'active',44,467.32),(707,'active',51,1486.65),(708,'closed',45,535.78),(709,'active',38,1819.74),(710,'active',30,910.67),(711,'active',59,1831.69),(712,'active',22,904.65),(713,'active',29,1839.65),(714,'active',34,1346.45),(715,'active',39,771.64),(716,'active',36,1478.47),(717,'active',53,537.98),(718,'active',31,718.64),(719,'active',37,108.70),(720,'active',26,312.52),(721,'active',56,1936.19),(722,'active',40,507.80),(723,'closed',34,46.21),(724,'active',44,1984.77),(725,'active',45,1926.39),(726,'closed',33,1530.74),(727,'closed',50,978.38),(728,'closed',47,1211.45),(729,'active',46,1387.11),(730,'active',58,377.15),(731,'active',55,1809.17),(732,'active',48,1117.73),(733,'active',32,467.61),(734,'active',42,1061.13),(735,'active',45,1461.61),(736,'closed',25,3.60),(737,'active',31,1473.65),(738,'active',46,1859.18),(739,'active',23,546.62),(740,'active',27,1993.80),(741,'active',22,954.52),(742,'active',55,1199.03),(743,'active',25,420.46),(744,'active',47,1857.64),(745,'active',41,451.90),(746,'active',20,1308.75),(747,'active',28,1539.98),(748,'active',25,401.45),(749,'active',58,191.00),(750,'active',48,741.90),(751,'active',28,1816.02),(752,'closed',44,174.68),(753,'active',56,1751.11),(754,'active',43,882.08),(755,'active',59,1480.49),(756,'active',49,76.68),(757,'active',34,1932.44),(758,'active',55,1309.72),(759,'active',54,1907.01),(760,'active',42,36.49),(761,'active',23,267.62),(762,'active',58,1224.20),(763,'active',34,581.91),(764,'active',50,1081.18),(765,'active',42,1053.60),(766,'active',21,429.16),(767,'active',37,1224.25),(768,'active',54,1887.31),(769,'active',23,1555.82),(770,'active',35,862.83),(771,'active',45,1972.66),(772,'active',46,1558.33),(773,'closed',57,161.18),(774,'active',36,171.33),(775,'active',25,1220.04),(776,'active',21,983.09),(777,'active',37,1957.67),(778,'active',21,1765.22),(779,'closed',37,1205.27),(780,'closed',43,1739.97),(781,'active',46,629.12),(782,'active',52,735.21),(783,'active',39,1930.10),(784,'active',46,1353.53),(785,'active',25,195.09),(786,'active',34,1108.17),(787,'active',30,748.04),(788,'active',25,685.60),(789,'active',35,1223.82),(790,'active',30,718.90),(791,'active',27,543.74),(792,'active',21,1998.56),(793,'active',46,1249.88),(794,'closed',54,407.82),(795,'active',24,1349.88),(796,'active',38,175.93),(797,'active',33,848.87),(798,'active',23,1459.39),(799,'active',52,1181.94);
+INSERT INTO `users` VALUES (800,'active',37,25.01),(801,'active',38,680.48),(802,'active',42,1817.79),(803,'closed',27,710.51),(804,'closed',46,651.67),(805,'closed',38,971.27),(806,'active',54,945.43),(807,'active',43,126.58),(808,'active',54,63.64),(809,'active',29,279.94),(810,'active',36,216.48),(811,'active',54,1982.70),(812,'active',54,812.67),(813,'active',27,233.56),(814,'active',43,1997.42),(815,'closed',23,1551.28),(816,'active',37,591.08),(817,'active',50,694.25),(818,'active',58,1213.32),(819,'active',40,300.21),(820,'active',31,953.93),(821,'active',27,674.01),(822,'active',28,461.72),(823,'active',32,211.35),(824,'closed',50,395.51),(825,'active',50,788.68),(826,'active',24,1390.96),(827,'active',26,1510.57),(828,'active',54,250.49),(829,'active',58,760.93),(830,'closed',48,815.67),(831,'active',28,6.04),(832,'active',46,658.99),(833,'closed',26,1353.60),(834,'active',24,283.00),(835,'active',55,676.26),(836,'active',52,985.35),(837,'active',35,969.17),(838,'active',34,448.65),(839,'active',38,876.92),(840,'active',34,816.56),(841,'active',31,857.44),(842,'active',42,417.67),(843,'active',43,1492.99),(844,'active',41,1168.70),(845,'active',23,532.11),(846,'active',44,1527.55),(847,'active',48,318.41),(848,'active',26,124.78),(849,'active',34,434.09),(850,'active',22,558.29),(851,'active',59,1119.72),(852,'active',53,1275.97),(853,'active',31,1805.39),(854,'active',52,587.55),(855,'active',37,1721.56),(856,'active',21,1177.05),(857,'active',57,1850.00),(858,'active',45,747.50),(859,'active',52,50.64),(860,'active',42,700.23),(861,'active',47,858.23),(862,'active',46,553.78),(863,'active',44,1730.71),(864,'active',25,1532.36),(865,'active',49,120.10),(866,'active',38,601.17),(867,'active',44,1142.19),(868,'active',48,1840.14),(869,'closed',39,1327.66),(870,'active',42,506.73),(871,'active',32,151.20),(872,'active',46,1659.40),(873,'active',33,1097.75),(874,'active',59,699.27),(875,'active',38,569.08),(876,'active',24,1038.31),(877,'active',39,356.46),(878,'active',38,212.56),(879,'active',43,1039.74),(880,'active',42,1119.63),(881,'active',24,678.40),(882,'active',38,1130.07),(883,'active',41,1648.83),(884,'active',24,294.97),(885,'active',47,481.11),(886,'active',50,1009.36),(887,'active',32,317.59),(888,'active',38,416.45),(889,'active',52,200.93),(890,'active',30,248.01),(891,'active',33,951.25),(892,'active',42,1864.24),(893,'active',53,609.62),(894,'active',55,1786.93),(895,'active',30,411.88),(896,'active',49,1838.43),(897,'active',54,690.23),(898,'closed',24,685.24),(899,'active',43,1644.48);
+INSERT INTO `users` VALUES (900,'active',54,1761.03),(901,'active',47,1364.91),(902,'active',54,354.60),(903,'closed',29,946.62),(904,'active',53,186.31),(905,'active',49,1212.02),(906,'active',22,1750.73),(907,'active',34,1849.29),(908,'active',41,111.23),(909,'active',58,1630.03),(910,'active',28,1184.09),(911,'active',29,120.97),(912,'active',51,1871.62),(913,'active',27,719.58),(914,'active',57,1854.87),(915,'active',42,1057.00),(916,'active',58,1725.89),(917,'active',53,446.50),(918,'active',38,593.88),(919,'active',25,1154.36),(920,'active',45,1027.00),(921,'active',39,1385.53),(922,'active',22,799.61),(923,'active',43,1760.29),(924,'active',31,810.05),(925,'active',52,1644.87),(926,'active',45,1291.96),(927,'active',35,349.78),(928,'active',45,441.23),(929,'closed',50,107.13),(930,'active',40,321.89),(931,'active',58,1919.04),(932,'closed',33,1181.34),(933,'active',42,1300.52),(934,'active',22,1152.19),(935,'active',49,882.74),(936,'active',22,612.67),(937,'active',48,1250.18),(938,'active',33,733.24),(939,'active',31,1636.46),(940,'active',43,1991.25),(941,'active',28,525.54),(942,'active',36,905.90),(943,'active',32,1608.14),(944,'active',57,163.29),(945,'closed',59,417.70),(946,'active',35,935.33),(947,'active',38,1740.58),(948,'active',30,1374.65),(949,'active',52,1892.15),(950,'active',48,267.91),(951,'active',41,347.34),(952,'active',48,1404.81),(953,'active',32,1127.49),(954,'active',23,1146.43),(955,'active',42,473.34),(956,'active',37,595.35),(957,'active',50,361.31),(958,'active',56,968.61),(959,'active',26,895.87),(960,'active',48,1338.71),(961,'active',52,816.68),(962,'closed',38,1998.79),(963,'active',31,309.45),(964,'active',29,551.52),(965,'active',41,228.32),(966,'active',58,1395.20),(967,'active',53,1605.01),(968,'active',33,1847.13),(969,'active',35,1323.16),(970,'closed',25,1604.68),(971,'active',31,48.85),(972,'active',46,1631.93),(973,'active',40,1115.44),(974,'active',51,148.15),(975,'active',56,55.32),(976,'active',58,1356.55),(977,'active',56,366.56),(978,'active',22,1432.15),(979,'active',38,881.60),(980,'active',21,1849.36),(981,'active',23,1482.85),(982,'active',46,9.61),(983,'active',40,42.28),(984,'active',49,795.30),(985,'active',38,411.42),(986,'active',39,1248.14),(987,'closed',54,1952.56),(988,'active',51,1418.38),(989,'active',57,999.13),(990,'active',33,766.26),(991,'active',33,467.62),(992,'active',48,311.99),(993,'active',57,521.53),(994,'closed',28,191.49),(995,'active',51,1113.34),(996,'active',32,831.22),(997,'active',43,619.02),(998,'active',24,1406.61),(999,'active',31,1265.02);
+INSERT INTO `users` VALUES (1000,'active',26,599.34),(1001,'active',33,609.02),(1002,'active',33,531.28),(1003,'active',33,756.16),(1004,'active',24,916.33),(1005,'active',24,637.81),(1006,'active',32,710.67),(1007,'active',56,186.89),(1008,'active',57,330.19),(1009,'active',31,102.56),(1010,'active',32,196.40),(1011,'active',36,1277.67),(1012,'closed',41,258.56),(1013,'active',50,611.90),(1014,'active',41,630.06),(1015,'active',44,1151.92),(1016,'active',42,735.80),(1017,'active',25,1388.54),(1018,'active',41,93.35),(1019,'active',43,1298.23),(1020,'active',33,140.63),(1021,'closed',32,105.07),(1022,'active',25,1789.36),(1023,'active',50,376.14),(1024,'active',24,1019.41),(1025,'active',54,1949.07),(1026,'active',25,262.54),(1027,'active',58,543.69),(1028,'active',41,178.15),(1029,'active',44,171.30),(1030,'active',43,1884.62),(1031,'active',35,623.05),(1032,'active',40,898.27),(1033,'active',37,1403.15),(1034,'active',42,130.46),(1035,'closed',59,1364.17),(1036,'active',24,1026.41),(1037,'active',48,1249.52),(1038,'active',22,1674.00),(1039,'active',51,1091.01),(1040,'active',52,510.09),(1041,'active',42,332.07),(1042,'closed',31,1477.24),(1043,'active',29,89.46),(1044,'closed',34,836.62),(1045,'active',48,414.51),(1046,'active',27,284.06),(1047,'active',54,680.16),(1048,'active',47,1452.51),(1049,'active',42,1860.40),(1050,'active',51,1770.94),(1051,'closed',41,625.80),(1052,'active',25,1205.18),(1053,'active',25,1989.24),(1054,'active',38,1790.86),(1055,'active',53,1273.14),(1056,'active',26,38.17),(1057,'active',57,737.49),(1058,'closed',27,929.73),(1059,'active',32,1843.50),(1060,'active',42,1213.52),(1061,'closed',34,575.63),(1062,'active',29,290.61),(1063,'closed',59,292.00),(1064,'active',51,693.42),(1065,'active',39,531.37),(1066,'active',23,31.71),(1067,'active',38,1984.32),(1068,'active',55,951.27),(1069,'active',40,819.88),(1070,'active',57,763.69),(1071,'active',35,151.03),(1072,'active',53,1346.07),(1073,'active',32,262.53),(1074,'active',55,1912.53),(1075,'active',43,1884.62),(1076,'active',42,1656.80),(1077,'active',34,669.05),(1078,'active',37,949.82),(1079,'active',37,568.91),(1080,'active',20,450.00),(1081,'active',24,1046.43),(1082,'active',22,16.55),(1083,'active',23,1345.02),(1084,'active',33,1559.10),(1085,'active',39,1373.26),(1086,'active',31,678.65),(1087,'active',41,335.25),(1088,'active',49,80.16),(1089,'active',49,681.82),(1090,'active',36,1126.88),(1091,'active',34,59.09),(1092,'active',20,1073.59),(1093,'closed',37,848.91),(1094,'active',33,445.85),(1095,'active',57,1718.96),(1096,'active',36,1694.14),(1097,'active',32,141.80),(1098,'active',44,1207.28),(1099,'active',55,1154.38);
+INSERT INTO `users` VALUES (1100,'active',55,1256.15),(1101,'active',36,4.56),(1102,'active',21,813.09),(1103,'active',23,1825.86),(1104,'active',27,584.15),(1105,'active',39,1209.41),(1106,'active',37,683.25),(1107,'active',44,665.87),(1108,'active',49,1054.29),(1109,'active',27,277.50),(1110,'active',44,543.52),(1111,'active',28,520.41),(1112,'active',51,1331.38),(1113,'active',32,768.96),(1114,'active',59,582.03),(1115,'active',33,961.11),(1116,'active',25,576.11),(1117,'active',25,795.29),(1118,'active',26,1748.85),(1119,'closed',26,1233.78),(1120,'active',45,927.88),(1121,'active',49,1152.54),(1122,'active',37,185.27),(1123,'closed',57,1234.96),(1124,'active',47,331.42),(1125,'closed',38,646.63),(1126,'active',38,1242.08),(1127,'closed',21,1484.17),(1128,'active',44,235.31),(1129,'active',41,1426.93),(1130,'active',26,722.79),(1131,'active',22,384.67),(1132,'active',57,1959.09),(1133,'active',28,1107.83),(1134,'active',47,268.43),(1135,'active',48,865.17),(1136,'active',42,255.84),(1137,'active',34,1468.76),(1138,'active',23,1133.08),(1139,'active',30,1743.20),(1140,'active',34,49.57),(1141,'active',34,691.98),(1142,'active',49,1498.67),(1143,'active',24,897.52),(1144,'active',36,945.19),(1145,'active',29,1915.52),(1146,'active',57,926.73),(1147,'active',35,841.03),(1148,'closed',56,525.85),(1149,'active',49,1391.65),(1150,'active',36,112.73),(1151,'active',37,115.86),(1152,'active',26,1162.94),(1153,'active',30,423.87),(1154,'active',26,371.16),(1155,'active',33,1530.30),(1156,'closed',35,1124.20),(1157,'active',36,1749.75),(1158,'active',22,369.37),(1159,'active',55,1881.94),(1160,'closed',38,643.00),(1161,'active',44,1061.62),(1162,'closed',44,468.40),(1163,'active',42,791.80),(1164,'active',41,311.48),(1165,'active',44,1260.43),(1166,'active',45,304.25),(1167,'active',22,1483.61),(1168,'active',58,1725.52),(1169,'active',47,1452.70),(1170,'closed',50,1141.86),(1171,'active',42,917.14),(1172,'closed',21,1471.94),(1173,'closed',46,1642.28),(1174,'active',58,820.23),(1175,'active',49,162.74),(1176,'active',27,631.27),(1177,'active',29,295.52),(1178,'active',55,684.52),(1179,'active',44,373.61),(1180,'active',25,1735.26),(1181,'active',40,522.42),(1182,'active',49,1175.76),(1183,'closed',55,1274.88),(1184,'active',30,1022.81),(1185,'active',36,313.94),(1186,'active',32,1091.67),(1187,'active',32,1182.16),(1188,'active',33,1927.07),(1189,'active',38,436.93),(1190,'active',26,1909.49),(1191,'active',30,972.24),(1192,'active',35,1889.26),(1193,'active',59,938.39),(1194,'active',54,1414.12),(1195,'active',58,845.03),(1196,'active',47,250.18),(1197,'active',48,1679.37),(1198,'active',43,994.54),(1199,'active',56,692.12);
+INSERT INTO `users` VALUES (1200,'active',49,464.77),(1201,'active',24,529.90),(1202,'active',36,805.90),(1203,'active',36,1496.20),(1204,'active',58,1715.25),(1205,'active',25,1416.93),(1206,'active',52,477.13),(1207,'active',32,696.46),(1208,'active',58,1459.30),(1209,'active',48,1000.99),(1210,'active',44,397.14),(1211,'active',23,1403.34),(1212,'closed',25,1899.93),(1213,'closed',35,708.99),(1214,'active',52,1976.69),(1215,'active',29,1066.99),(1216,'active',23,1438.00),(1217,'closed',52,423.41),(1218,'active',57,1841.11),(1219,'active',51,711.51),(1220,'closed',29,638.13),(1221,'active',29,306.18),(1222,'active',55,1639.69),(1223,'active',58,244.45),(1224,'active',51,863.49),(1225,'closed',22,115.83),(1226,'active',45,1714.09),(1227,'active',30,1634.14),(1228,'active',30,943.14),(1229,'active',58,1332.72),(1230,'active',29,345.71),(1231,'active',54,641.53),(1232,'closed',48,1382.52),(1233,'active',20,1949.99),(1234,'active',52,1856.35),(1235,'active',53,745.44),(1236,'active',21,348.33),(1237,'active',23,858.74),(1238,'active',55,762.61),(1239,'active',57,1403.42),(1240,'active',31,1076.91),(1241,'active',42,802.30),(1242,'active',47,536.06),(1243,'active',46,1580.97),(1244,'active',44,589.02),(1245,'active',41,586.44),(1246,'active',50,42.41),(1247,'active',27,446.99),(1248,'active',56,1395.06),(1249,'active',31,197.49),(1250,'active',58,69.59),(1251,'active',45,368.72),(1252,'closed',57,1903.17),(1253,'active',32,866.60),(1254,'closed',25,1379.66),(1255,'active',48,1551.60),(1256,'active',39,476.65),(1257,'active',22,166.44),(1258,'active',33,1226.36),(1259,'active',28,1825.26),(1260,'active',57,1272.35),(1261,'active',34,1121.20),(1262,'active',50,436.40),(1263,'active',36,551.49),(1264,'active',49,1495.72),(1265,'active',34,40.15),(1266,'active',27,534.89),(1267,'active',33,1863.79),(1268,'active',49,399.75),(1269,'active',36,147.28),(1270,'active',22,1726.50),(1271,'active',22,1684.40),(1272,'active',29,1743.71),(1273,'active',43,347.06),(1274,'active',46,341.68),(1275,'active',45,1872.79),(1276,'active',53,1377.57),(1277,'active',37,462.29),(1278,'active',36,1967.84),(1279,'active',30,221.80),(1280,'active',52,628.48),(1281,'active',32,1578.35),(1282,'active',37,1027.36),(1283,'active',35,992.40),(1284,'active',57,1378.42),(1285,'closed',29,598.72),(1286,'active',39,1210.66),(1287,'active',35,163.24),(1288,'active',34,1026.90),(1289,'active',45,786.61),(1290,'active',44,1417.73),(1291,'active',43,289.13),(1292,'active',39,1258.22),(1293,'active',50,243.71),(1294,'active',59,589.97),(1295,'active',38,1221.33),(1296,'active',42,269.79),(1297,'active',33,142.99),(1298,'active',49,817.23),(1299,'active',50,1884.17);
+INSERT INTO `users` VALUES (1300,'active',38,337.19),(1301,'active',33,410.43),(1302,'active',26,618.00),(1303,'active',46,1531.73),(1304,'active',32,1019.44),(1305,'closed',36,1474.33),(1306,'active',35,360.23),(1307,'active',47,1657.14),(1308,'active',28,162.85),(1309,'active',55,990.51),(1310,'closed',44,1900.33),(1311,'active',48,137.62),(1312,'active',49,120.72),(1313,'active',54,1506.55),(1314,'closed',57,496.17),(1315,'active',51,1240.60),(1316,'active',36,258.40),(1317,'active',59,1953.25),(1318,'active',45,1767.18),(1319,'active',53,459.83),(1320,'active',43,1148.02),(1321,'active',54,877.44),(1322,'active',24,780.55),(1323,'active',55,848.58),(1324,'active',21,1584.48),(1325,'active',56,1400.84),(1326,'active',21,221.96),(1327,'active',22,846.09),(1328,'active',33,505.19),(1329,'active',48,781.78),(1330,'active',41,213.23),(1331,'active',39,410.35),(1332,'active',55,1085.47),(1333,'active',24,574.39),(1334,'active',29,1339.92),(1335,'closed',50,1943.71),(1336,'closed',41,1001.83),(1337,'active',26,1925.20),(1338,'active',32,482.54),(1339,'active',20,542.04),(1340,'active',37,1630.36),(1341,'active',56,1820.10),(1342,'active',57,1269.78),(1343,'active',57,373.60),(1344,'active',29,488.60),(1345,'active',52,1388.81),(1346,'closed',31,1745.74),(1347,'closed',35,1144.43),(1348,'active',47,423.87),(1349,'active',54,643.49),(1350,'active',29,554.14),(1351,'active',58,539.05),(1352,'closed',31,1559.64),(1353,'active',36,651.04),(1354,'active',29,1037.57),(1355,'active',53,1381.29),(1356,'active',27,268.19),(1357,'active',42,537.14),(1358,'active',43,255.51),(1359,'active',54,989.23),(1360,'active',38,1620.62),(1361,'active',56,386.50),(1362,'active',44,68.59),(1363,'active',43,911.09),(1364,'active',54,372.66),(1365,'closed',57,178.77),(1366,'active',26,231.54),(1367,'active',36,172.87),(1368,'active',22,815.88),(1369,'active',50,1088.66),(1370,'closed',33,663.94),(1371,'active',23,215.95),(1372,'active',29,47.95),(1373,'active',56,419.97),(1374,'active',37,1360.19),(1375,'active',49,433.64),(1376,'active',50,1180.88),(1377,'closed',57,1904.14),(1378,'active',38,1426.51),(1379,'active',46,1553.72),(1380,'active',25,640.81),(1381,'closed',58,358.27),(1382,'active',22,1383.64),(1383,'closed',54,282.03),(1384,'active',47,1151.59),(1385,'active',47,1674.67),(1386,'active',40,706.18),(1387,'active',44,1987.21),(1388,'active',34,448.91),(1389,'closed',31,903.09),(1390,'active',45,1509.34),(1391,'active',53,369.90),(1392,'active',30,1276.36),(1393,'active',25,1832.38),(1394,'active',51,1536.51),(1395,'active',38,637.61),(1396,'active',53,158.32),(1397,'active',41,439.81),(1398,'active',45,1965.66),(1399,'active',48,688.52);
+INSERT INTO `users` VALUES (1400,'active',23,321.99),(1401,'closed',45,1955.19),(1402,'active',44,1946.49),(1403,'active',58,209.16),(1404,'active',40,1851.91),(1405,'active',49,995.12),(1406,'active',48,1408.08),(1407,'active',41,1554.68),(1408,'active',30,795.91),(1409,'active',39,146.33),(1410,'closed',58,1581.43),(1411,'active',55,965.10),(1412,'active',36,83.87),(1413,'active',30,1008.57),(1414,'active',42,1496.96),(1415,'closed',46,1416.18),(1416,'active',57,4.39),(1417,'active',51,1077.40),(1418,'active',31,189.29),(1419,'closed',28,1615.81),(1420,'active',51,1534.92),(1421,'active',49,1064.96),(1422,'active',54,1564.21),(1423,'active',26,1976.30),(1424,'active',51,1266.47),(1425,'active',23,576.72),(1426,'active',56,235.07),(1427,'active',27,1701.67),(1428,'closed',29,98.57),(1429,'active',46,1564.87),(1430,'active',32,415.96),(1431,'active',51,317.29),(1432,'active',30,1571.22),(1433,'active',56,978.59),(1434,'active',26,1049.28),(1435,'active',45,1106.85),(1436,'closed',32,1195.37),(1437,'active',33,1345.88),(1438,'active',27,1302.83),(1439,'active',26,1185.91),(1440,'active',31,1311.68),(1441,'active',33,623.08),(1442,'active',46,1207.92),(1443,'active',50,221.34),(1444,'active',43,576.59),(1445,'closed',37,923.09),(1446,'active',53,1275.88),(1447,'active',56,1203.18),(1448,'active',33,500.32),(1449,'active',45,1443.80),(1450,'active',38,672.86),(1451,'active',20,971.72),(1452,'active',23,1752.99),(1453,'active',48,1738.45),(1454,'closed',41,305.51),(1455,'active',54,46.37),(1456,'active',47,1228.79),(1457,'active',55,1046.76),(1458,'active',41,912.69),(1459,'active',24,575.06),(1460,'active',45,1901.19),(1461,'active',26,1724.42),(1462,'active',45,604.27),(1463,'active',53,1302.47),(1464,'active',21,190.40),(1465,'active',50,1805.98),(1466,'active',42,1460.40),(1467,'active',35,232.24),(1468,'active',32,917.29),(1469,'active',55,537.04),(1470,'active',53,1865.56),(1471,'active',43,752.34),(1472,'active',37,1398.21),(1473,'active',48,1080.80),(1474,'active',23,1283.03),(1475,'active',31,167.11),(1476,'active',46,432.71),(1477,'active',58,812.20),(1478,'active',28,122.92),(1479,'active',30,942.11),(1480,'active',42,394.98),(1481,'active',33,172.79),(1482,'active',53,455.25),(1483,'active',29,1488.29),(1484,'active',31,8.84),(1485,'active',36,1751.17),(1486,'active',33,991.49),(1487,'active',31,236.62),(1488,'active',22,1180.67),(1489,'active',28,1998.02),(1490,'active',38,341.02),(1491,'active',52,298.15),(1492,'active',24,200.46),(1493,'closed',23,342.64),(1494,'active',43,204.99),(1495,'active',52,568.21),(1496,'active',55,1826.21),(1497,'active',53,692.56),(1498,'active',45,373.88),(1499,'active',26,281.04);
+INSERT INTO `users` VALUES (1500,'active',41,1252.62),(1501,'active',58,453.73),(1502,'active',39,1058.65),(1503,'active',56,129.83),(1504,'active',36,385.16),(1505,'active',44,878.31),(1506,'active',52,625.33),(1507,'active',28,1669.21),(1508,'active',22,294.74),(1509,'active',56,1538.69),(1510,'active',56,1158.59),(1511,'active',31,1117.75),(1512,'active',30,1085.72),(1513,'active',36,488.80),(1514,'active',48,142.67),(1515,'active',45,1251.86),(1516,'active',38,1714.55),(1517,'active',32,1844.72),(1518,'active',41,158.05),(1519,'closed',42,232.18),(1520,'active',23,337.76),(1521,'active',33,610.92),(1522,'active',58,685.93),(1523,'active',30,1109.40),(1524,'active',36,123.79),(1525,'active',51,790.52),(1526,'active',41,1841.91),(1527,'active',47,1648.89),(1528,'active',53,1411.92),(1529,'active',53,390.50),(1530,'active',43,1369.41),(1531,'active',29,927.82),(1532,'closed',53,1209.69),(1533,'active',28,25.91),(1534,'active',36,596.21),(1535,'closed',36,1703.19),(1536,'active',46,811.56),(1537,'closed',47,242.43),(1538,'active',42,340.29),(1539,'closed',35,1606.02),(1540,'active',50,611.98),(1541,'active',35,1889.09),(1542,'active',58,58.93),(1543,'active',53,1557.02),(1544,'active',47,193.53),(1545,'active',56,1165.17),(1546,'active',50,1523.60),(1547,'active',40,656.83),(1548,'active',27,460.08),(1549,'active',47,1310.58),(1550,'active',33,731.55),(1551,'closed',50,1513.78),(1552,'active',28,770.62),(1553,'active',53,931.99),(1554,'active',26,1.83),(1555,'active',40,897.06),(1556,'active',35,1220.08),(1557,'active',50,507.07),(1558,'closed',51,202.93),(1559,'active',49,507.69),(1560,'active',25,764.51),(1561,'active',31,244.76),(1562,'active',50,299.01),(1563,'active',49,605.68),(1564,'active',35,1218.10),(1565,'active',58,674.33),(1566,'active',49,1555.16),(1567,'active',45,1178.22),(1568,'active',22,1297.03),(1569,'active',30,1802.46),(1570,'active',59,1392.74),(1571,'active',53,124.31),(1572,'active',25,1714.84),(1573,'active',51,1260.13),(1574,'active',40,1499.23),(1575,'active',27,1594.43),(1576,'active',53,426.30),(1577,'active',37,519.74),(1578,'active',54,1157.06),(1579,'active',30,265.74),(1580,'closed',37,38.89),(1581,'active',35,22.19),(1582,'active',32,1293.69),(1583,'active',25,1629.84),(1584,'active',59,1899.12),(1585,'active',44,292.33),(1586,'active',33,1282.04),(1587,'active',34,411.25),(1588,'closed',25,1205.32),(1589,'active',27,747.85),(1590,'active',22,20.60),(1591,'active',55,12.76),(1592,'active',48,820.74),(1593,'active',31,1259.28),(1594,'active',49,1577.42),(1595,'active',34,495.39),(1596,'active',26,1107.05),(1597,'closed',41,1341.28),(1598,'active',25,629.93),(1599,'active',54,1536.32);
+INSERT INTO `users` VALUES (1600,'active',27,504.12),(1601,'active',22,893.93),(1602,'closed',32,584.81),(1603,'closed',35,111.20),(1604,'active',58,1035.02),(1605,'active',36,501.81),(1606,'active',32,1782.22),(1607,'active',45,1142.90),(1608,'active',54,459.04),(1609,'active',26,1064.57),(1610,'closed',22,630.71),(1611,'active',37,7.19),(1612,'active',21,1912.68),(1613,'active',21,391.13),(1614,'active',40,1924.23),(1615,'active',27,1288.89),(1616,'active',54,1883.33),(1617,'active',55,225.88),(1618,'active',39,801.20),(1619,'closed',21,1364.77),(1620,'active',20,450.78),(1621,'closed',28,258.95),(1622,'active',51,1463.89),(1623,'active',51,224.28),(1624,'active',54,1956.74),(1625,'active',57,494.52),(1626,'active',28,1397.66),(1627,'active',33,1571.55),(1628,'active',33,340.75),(1629,'active',54,1331.65),(1630,'active',43,150.54),(1631,'active',42,294.17),(1632,'active',46,370.66),(1633,'active',57,5.93),(1634,'active',51,585.92),(1635,'active',45,1580.18),(1636,'active',37,1776.44),(1637,'active',56,846.80),(1638,'active',46,1290.85),(1639,'active',40,1165.50),(1640,'active',32,287.58),(1641,'active',38,1315.62),(1642,'active',21,233.34),(1643,'active',51,1923.90),(1644,'active',55,1902.88),(1645,'active',40,1265.74),(1646,'active',49,1334.87),(1647,'active',56,1523.03),(1648,'active',26,555.39),(1649,'active',26,1232.45),(1650,'active',26,933.34),(1651,'active',28,1759.72),(1652,'active',45,754.01),(1653,'active',33,1797.06),(1654,'active',38,1716.73),(1655,'active',55,730.44),(1656,'active',44,353.87),(1657,'active',41,135.84),(1658,'active',22,893.83),(1659,'active',35,1092.48),(1660,'active',24,65.84),(1661,'active',40,1828.28),(1662,'active',39,649.07),(1663,'active',25,1279.62),(1664,'active',33,68.79),(1665,'active',44,1352.19),(1666,'closed',53,1420.93),(1667,'active',52,1477.97),(1668,'active',42,1151.03),(1669,'active',56,1733.38),(1670,'active',59,1488.54),(1671,'active',22,194.28),(1672,'active',22,411.57),(1673,'active',58,603.24),(1674,'active',31,285.72),(1675,'active',35,579.01),(1676,'active',35,264.89),(1677,'active',31,808.18),(1678,'closed',34,1742.31),(1679,'active',30,672.64),(1680,'active',41,316.01),(1681,'active',38,1717.21),(1682,'closed',47,513.98),(1683,'active',56,1163.62),(1684,'active',33,357.95),(1685,'active',42,1974.81),(1686,'closed',46,211.29),(1687,'active',22,142.00),(1688,'active',48,1757.74),(1689,'active',20,204.24),(1690,'active',52,46.12),(1691,'active',30,354.04),(1692,'active',58,1954.57),(1693,'closed',28,1819.87),(1694,'active',38,784.20),(1695,'active',53,1178.90),(1696,'active',48,855.28),(1697,'active',38,274.75),(1698,'active',25,1971.70),(1699,'active',56,1658.24);
+INSERT INTO `users` VALUES (1700,'closed',38,492.64),(1701,'active',32,323.03),(1702,'active',56,725.39),(1703,'active',32,490.84),(1704,'active',54,1040.45),(1705,'active',57,1715.16),(1706,'active',58,687.69),(1707,'active',29,633.94),(1708,'closed',32,1699.40),(1709,'active',38,191.60),(1710,'active',28,909.21),(1711,'active',43,589.16),(1712,'active',32,621.49),(1713,'active',28,1800.37),(1714,'active',36,1720.82),(1715,'active',58,115.36),(1716,'active',34,1409.62),(1717,'active',38,822.57),(1718,'active',48,1801.62),(1719,'active',27,148.76),(1720,'closed',29,594.57),(1721,'active',55,1289.28),(1722,'closed',35,698.73),(1723,'active',28,1789.25),(1724,'active',59,1583.88),(1725,'active',36,805.62),(1726,'active',49,1122.60),(1727,'active',22,1107.92),(1728,'active',57,149.59),(1729,'active',25,339.22),(1730,'active',20,1209.54),(1731,'active',54,1312.53),(1732,'active',29,551.19),(1733,'active',43,245.59),(1734,'active',29,1840.39),(1735,'active',45,1563.15),(1736,'active',22,1969.45),(1737,'active',39,1866.96),(1738,'active',39,186.10),(1739,'active',47,1443.39),(1740,'active',32,1091.38),(1741,'active',33,1651.06),(1742,'active',53,909.08),(1743,'closed',51,1261.44),(1744,'active',23,650.13),(1745,'active',34,1525.01),(1746,'active',55,722.10),(1747,'active',31,633.53),(1748,'active',28,695.80),(1749,'active',42,548.52),(1750,'active',29,637.85),(1751,'active',29,705.54),(1752,'active',33,1837.90),(1753,'active',29,746.30),(1754,'active',43,1780.76),(1755,'active',22,16.31),(1756,'active',57,861.85),(1757,'active',40,1496.86),(1758,'active',39,1181.73),(1759,'active',40,689.43),(1760,'active',57,563.79),(1761,'active',28,1937.56),(1762,'active',58,1495.94),(1763,'closed',32,1452.13),(1764,'active',56,1054.85),(1765,'active',43,1923.80),(1766,'active',40,361.46),(1767,'active',37,1391.48),(1768,'active',48,480.27),(1769,'closed',35,1593.51),(1770,'active',30,568.99),(1771,'active',53,1382.87),(1772,'active',38,530.51),(1773,'active',30,333.14),(1774,'active',46,590.90),(1775,'active',53,206.50),(1776,'active',39,1583.44),(1777,'closed',30,454.15),(1778,'active',42,1988.36),(1779,'active',26,270.98),(1780,'active',20,712.95),(1781,'active',52,392.20),(1782,'active',34,1424.19),(1783,'closed',52,1233.82),(1784,'active',40,1609.63),(1785,'active',46,431.27),(1786,'closed',35,1851.53),(1787,'active',46,1859.38),(1788,'active',34,1903.08),(1789,'active',32,1484.64),(1790,'active',47,1477.19),(1791,'active',42,742.19),(1792,'active',30,1151.15),(1793,'active',20,1430.72),(1794,'active',38,1249.15),(1795,'active',37,1725.76),(1796,'active',22,1380.37),(1797,'active',26,258.82),(1798,'active',41,719.57),(1799,'active',41,1456.54);
+INSERT INTO `users` VALUES (1800,'active',39,299.13),(1801,'closed',39,355.38),(1802,'active',28,1920.89),(1803,'active',21,31.53),(1804,'active',23,852.80),(1805,'active',25,481.67),(1806,'active',51,1855.91),(1807,'active',22,194.40),(1808,'active',49,961.41),(1809,'active',27,366.01),(1810,'active',49,91.41),(1811,'active',55,1282.16),(1812,'active',42,1601.38),(1813,'active',30,1054.91),(1814,'active',52,957.98),(1815,'active',47,1124.29),(1816,'active',38,390.81),(1817,'closed',27,901.36),(1818,'active',29,109.76),(1819,'closed',33,959.55),(1820,'active',33,1009.44),(1821,'active',42,1494.95),(1822,'active',25,1288.68),(1823,'active',33,1949.85),(1824,'active',44,724.52),(1825,'active',55,1655.78),(1826,'active',40,856.66),(1827,'active',45,139.04),(1828,'active',41,411.44),(1829,'active',43,1842.87),(1830,'active',32,331.40),(1831,'active',43,475.77),(1832,'active',55,1534.27),(1833,'active',28,263.05),(1834,'active',28,278.94),(1835,'active',32,597.96),(1836,'active',47,658.04),(1837,'active',22,1442.08),(1838,'active',22,29.50),(1839,'active',28,860.92),(1840,'active',51,986.59),(1841,'active',58,1368.95),(1842,'active',46,1155.76),(1843,'active',39,1150.87),(1844,'closed',51,1960.60),(1845,'active',20,667.39),(1846,'active',36,1102.27),(1847,'active',28,1429.02),(1848,'active',30,561.56),(1849,'active',30,1902.33),(1850,'active',37,751.84),(1851,'active',56,1756.35),(1852,'closed',30,63.78),(1853,'active',34,9.84),(1854,'active',55,1238.75),(1855,'closed',22,1475.91),(1856,'active',27,1871.39),(1857,'active',53,1564.59),(1858,'active',29,805.30),(1859,'active',26,869.50),(1860,'active',49,922.06),(1861,'active',50,453.92),(1862,'active',37,18.34),(1863,'active',43,1323.66),(1864,'active',37,129.31),(1865,'active',56,1316.66),(1866,'active',42,347.19),(1867,'active',26,1779.67),(1868,'active',20,1318.20),(1869,'active',24,297.45),(1870,'active',22,1198.87),(1871,'active',56,271.71),(1872,'closed',35,1023.28),(1873,'active',51,572.35),(1874,'active',46,642.14),(1875,'active',40,179.24),(1876,'active',58,1045.83),(1877,'active',44,62.43),(1878,'active',23,548.60),(1879,'active',30,708.00),(1880,'active',40,501.81),(1881,'closed',38,1396.59),(1882,'active',43,1004.14),(1883,'active',34,1157.69),(1884,'active',54,466.55),(1885,'active',52,678.32),(1886,'active',25,127.93),(1887,'active',51,791.13),(1888,'active',27,790.28),(1889,'active',37,1453.06),(1890,'active',41,940.70),(1891,'active',24,469.09),(1892,'active',29,620.91),(1893,'closed',44,1119.83),(1894,'active',54,1393.56),(1895,'active',43,1957.81),(1896,'active',51,391.30),(1897,'active',31,1674.02),(1898,'active',53,914.66),(1899,'closed',53,774.53);
+INSERT INTO `users` VALUES (1900,'active',55,1937.81),(1901,'active',28,400.59),(1902,'active',28,1254.62),(1903,'closed',49,1478.02),(1904,'active',26,429.55),(1905,'active',40,1316.61),(1906,'active',50,1155.38),(1907,'active',55,856.34),(1908,'active',22,917.91),(1909,'active',22,381.51),(1910,'active',50,405.76),(1911,'active',32,1439.77),(1912,'active',22,1325.76),(1913,'active',52,221.68),(1914,'active',59,520.07),(1915,'active',56,434.48),(1916,'active',27,1274.37),(1917,'active',33,1965.93),(1918,'closed',45,937.13),(1919,'active',24,972.00),(1920,'active',43,564.23),(1921,'active',21,98.01),(1922,'closed',34,529.42),(1923,'active',37,1078.53),(1924,'active',30,896.41),(1925,'active',39,1074.22),(1926,'active',37,1923.95),(1927,'active',40,1872.27),(1928,'active',24,1265.57),(1929,'active',21,1904.28),(1930,'closed',57,1735.06),(1931,'closed',45,1697.41),(1932,'active',31,123.53),(1933,'active',42,208.89),(1934,'active',51,986.94),(1935,'active',44,1461.56),(1936,'active',26,1673.88),(1937,'active',22,1331.79),(1938,'active',47,387.33),(1939,'active',39,27.08),(1940,'active',37,801.86),(1941,'active',56,394.32),(1942,'active',41,1559.28),(1943,'active',47,1127.60),(1944,'active',44,1687.76),(1945,'active',42,56.52),(1946,'active',23,926.32),(1947,'closed',43,920.37),(1948,'closed',47,1445.46),(1949,'active',21,940.77),(1950,'active',47,1274.97),(1951,'active',48,496.74),(1952,'active',31,605.45),(1953,'active',38,1943.82),(1954,'active',28,857.68),(1955,'active',42,1972.63),(1956,'active',30,1044.48),(1957,'active',21,1917.48),(1958,'active',48,279.97),(1959,'active',22,1507.91),(1960,'active',28,313.45),(1961,'closed',35,1168.79),(1962,'active',35,1322.55),(1963,'active',44,746.16),(1964,'active',25,368.41),(1965,'active',49,516.34),(1966,'active',23,1034.54),(1967,'active',23,305.17),(1968,'active',20,217.52),(1969,'active',41,53.94),(1970,'active',55,1712.86),(1971,'active',37,373.03),(1972,'active',22,179.38),(1973,'active',44,784.19),(1974,'closed',53,1261.01),(1975,'active',29,967.73),(1976,'active',28,1143.20),(1977,'active',25,54.13),(1978,'active',58,1200.35),(1979,'active',39,605.83),(1980,'active',42,819.01),(1981,'active',32,18.90),(1982,'active',53,1065.01),(1983,'active',46,736.52),(1984,'active',21,1281.58),(1985,'active',55,1940.21),(1986,'active',50,1109.05),(1987,'active',21,1887.94),(1988,'active',50,424.25),(1989,'active',47,797.51),(1990,'active',22,514.28),(1991,'active',48,902.50),(1992,'active',48,566.35),(1993,'active',23,1999.46),(1994,'active',58,284.87),(1995,'active',34,1059.15),(1996,'active',41,454.70),(1997,'active',45,932.02),(1998,'active',30,897.60),(1999,'active',41,1609.79);
+INSERT INTO `users` VALUES (2000,NULL,NULL,NULL);
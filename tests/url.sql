@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'https://bob:pw@app.io/u/42?email=jo%40x.com&token=abc&page=2'),(4,'/a#b'),(2,'not a url'),(3,NULL);
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'https://example.com/u/7?email=anon%20user@example.com&page=2'),(4,'/a#to%22p%5Cx'),(2,'invalid'),(3,NULL);
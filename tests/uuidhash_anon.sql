@@ -0,0 +1,7 @@
+CREATE TABLE `sessions` (
+  `id` char(36) NOT NULL,
+  `bin` binary(16) DEFAULT NULL,
+  `hex` binary(16) DEFAULT NULL,
+  `plain` varchar(32) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `sessions` VALUES ('c719d233-0acd-4a22-9244-211027f84261',_binary '3\nJ\"D!\'Ba',0xC719D2330ACD4A229244211027F84261,'C719D2330ACD4A229244211027F84261'),('n/a',NULL,NULL,NULL);
@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'<?xml version=\"1.0\"?>\n<user id=\"7\">\n  <email>x@example.com</email>\n  <phones><phone>000</phone><phone>000</phone></phones>\n</user>'),(2,'<broken>');
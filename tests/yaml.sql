@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'---\n:author_name: x\nauthor_name: John\nip_address: 10.0.0.1\ncustom_message: It\'s me\ntarget_details: \"root\"\n'),(2,'a: [');
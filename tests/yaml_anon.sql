@@ -0,0 +1,5 @@
+CREATE TABLE `people` (
+  `id` int NOT NULL,
+  `name` varchar(255) DEFAULT NULL
+) ENGINE=InnoDB;
+INSERT INTO `people` VALUES (1,'---\n:author_name: Jane Doe\nauthor_name: Jane Doe\nip_address: null\ntarget_details: \"root\"\n'),(2,'a: [');